@@ -1,22 +1,23 @@
 use clap::Parser;
 use console::style;
-use sqlx_cli::Opt;
 use sqlx_exasol::any::DRIVER;
+use sqlx_exasol_cli::ExaOpt;
 
 /// Cargo invokes this binary as `cargo-sqlx-exasol sqlx-exasol <args>`
 #[derive(Parser, Debug)]
 #[clap(bin_name = "cargo")]
 enum Cli {
-    SqlxExasol(Opt),
+    SqlxExasol(ExaOpt),
 }
 
 #[tokio::main]
 async fn main() {
     sqlx_cli::maybe_apply_dotenv();
     sqlx_exasol::any::install_drivers(&[DRIVER]).expect("driver installation failed");
+
     let Cli::SqlxExasol(opt) = Cli::parse();
 
-    if let Err(error) = sqlx_cli::run(opt).await {
+    if let Err(error) = opt.run().await {
         println!("{} {}", style("error:").bold().red(), error);
         std::process::exit(1);
     }
@@ -0,0 +1,540 @@
+use std::{collections::HashSet, fmt::Write as _, path::PathBuf};
+
+use clap::{Args, ValueEnum};
+use sqlx_exasol::{
+    ExaTypeInfo,
+    _unstable::config::macros::{DateTimeCrate, NumericCrate, PreferredCrates},
+};
+
+use crate::{CliResult, ConnectOpts};
+
+/// Rust keywords that cannot be used as field names without being escaped as raw identifiers.
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where",
+    "while", "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try",
+    "typeof", "unsized", "virtual", "yield",
+];
+
+/// Keywords that cannot be raw identifiers either, so they get an underscore appended instead.
+const PATH_KEYWORDS: &[&str] = &["crate", "self", "super"];
+
+/// Generate `FromRow` structs from the tables of a schema.
+#[derive(Args, Debug)]
+pub struct CodegenCommand {
+    #[clap(flatten)]
+    connect_opts: ConnectOpts,
+
+    /// The schema whose tables to generate structs for, as stored in the catalog, so unquoted
+    /// names are uppercase.
+    #[clap(long)]
+    schema: String,
+
+    /// Only generate the struct for this table.
+    #[clap(long)]
+    table: Option<String>,
+
+    /// The crate used for date and time types. Required if the tables have such columns, since
+    /// the crate enabled in the project using the structs cannot be inferred.
+    #[clap(long, value_enum)]
+    date_time: Option<DateTime>,
+
+    /// The crate used for decimals that do not fit in an integer. Required if the tables have
+    /// such columns, since the crate enabled in the project using the structs cannot be inferred.
+    #[clap(long, value_enum)]
+    numeric: Option<Numeric>,
+
+    /// Map `HASHTYPE(16 BYTE)` columns to `uuid::Uuid`, like the `query!` macros do when the
+    /// `uuid` feature is enabled. Otherwise they are mapped to `HashType`.
+    #[clap(long)]
+    uuid: bool,
+
+    /// Map `GEOMETRY` columns to `geo_types::Geometry`, like the `query!` macros do when the
+    /// `geo-types` feature is enabled. Otherwise they have no mapping.
+    #[clap(long)]
+    geo_types: bool,
+
+    /// Write the structs to a file instead of stdout.
+    #[clap(long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum DateTime {
+    Chrono,
+    Time,
+    Jiff,
+}
+
+impl DateTime {
+    /// Returns the `jiff` type matching a `chrono` one.
+    ///
+    /// `sqlx` has no `jiff` date-time types slot, so the types are resolved through `chrono` and
+    /// then translated, in line with the `jiff` types the `query!` macros use.
+    fn jiff_type(chrono_type: &'static str) -> &'static str {
+        match chrono_type {
+            "sqlx_exasol::types::chrono::TimeDelta" => "sqlx_exasol::types::jiff::SignedDuration",
+            "sqlx_exasol::types::chrono::NaiveDate" => "sqlx_exasol::types::jiff::civil::Date",
+            "sqlx_exasol::types::chrono::NaiveDateTime" => {
+                "sqlx_exasol::types::jiff::civil::DateTime"
+            }
+            "sqlx_exasol::types::chrono::DateTime<sqlx_exasol::types::chrono::Utc>" => {
+                "sqlx_exasol::types::jiff::Timestamp"
+            }
+            _ => chrono_type,
+        }
+    }
+}
+
+impl From<DateTime> for DateTimeCrate {
+    fn from(value: DateTime) -> Self {
+        match value {
+            DateTime::Chrono | DateTime::Jiff => Self::Chrono,
+            DateTime::Time => Self::Time,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum Numeric {
+    Bigdecimal,
+    RustDecimal,
+}
+
+impl From<Numeric> for NumericCrate {
+    fn from(value: Numeric) -> Self {
+        match value {
+            Numeric::Bigdecimal => Self::BigDecimal,
+            Numeric::RustDecimal => Self::RustDecimal,
+        }
+    }
+}
+
+/// A column of a table, as listed in `EXA_ALL_COLUMNS`.
+#[derive(Debug)]
+struct Column {
+    name: String,
+    rust_type: &'static str,
+    nullable: bool,
+}
+
+impl CodegenCommand {
+    /// Reads the table columns and prints a struct for each table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if querying the columns fails, if no tables are found or if a column data
+    /// type has no Rust type mapping.
+    pub async fn run(self) -> CliResult<()> {
+        let mut conn = self.connect_opts.connect().await?;
+
+        let query = "
+            SELECT column_table, column_name, column_type, column_is_nullable
+            FROM exa_all_columns
+            WHERE column_schema = ? AND column_object_type = 'TABLE'
+            ORDER BY column_table, column_ordinal_position;";
+
+        let table_query = "
+            SELECT column_table, column_name, column_type, column_is_nullable
+            FROM exa_all_columns
+            WHERE column_schema = ? AND column_object_type = 'TABLE' AND column_table = ?
+            ORDER BY column_ordinal_position;";
+
+        let columns: Vec<(String, String, String, bool)> = match &self.table {
+            Some(table) => {
+                sqlx_exasol::query_as(table_query)
+                    .bind(&self.schema)
+                    .bind(table)
+                    .fetch_all(&mut conn)
+                    .await?
+            }
+            None => {
+                sqlx_exasol::query_as(query)
+                    .bind(&self.schema)
+                    .fetch_all(&mut conn)
+                    .await?
+            }
+        };
+
+        if columns.is_empty() {
+            return Err(format!("no matching tables found in schema {}", self.schema).into());
+        }
+
+        let mut tables = Vec::<(String, Vec<Column>)>::new();
+
+        for (table, name, column_type, nullable) in columns {
+            let rust_type = column_type
+                .parse::<ExaTypeInfo>()
+                .map_err(|e| e.to_string())
+                .and_then(|t| self.rust_type(&t))
+                .and_then(|t| self.feature_gated(t))
+                .map_err(|e| format!("{table}.{name} of type {column_type}: {e}"))?;
+
+            let column = Column {
+                name,
+                rust_type,
+                nullable,
+            };
+
+            match tables.last_mut() {
+                Some((t, columns)) if *t == table => columns.push(column),
+                _ => tables.push((table, vec![column])),
+            }
+        }
+
+        let mut output = format!(
+            "// Generated by `sqlx-exasol codegen` from schema `{}`.\n",
+            self.schema
+        );
+
+        let mut structs = HashSet::new();
+
+        for (table, columns) in &tables {
+            let name = unique_name(&mut structs, struct_name(table), "");
+            output.push('\n');
+            output.push_str(&table_struct(&name, columns));
+        }
+
+        match &self.out {
+            Some(path) => tokio::fs::write(path, output).await?,
+            None => print!("{output}"),
+        }
+
+        Ok(())
+    }
+
+    /// Returns the Rust type of a column using the crates chosen through the flags.
+    ///
+    /// The CLI is built with all the date-time and numeric features, so the type the `query!`
+    /// macros infer in the project using the structs cannot be known. Choosing a crate is thus
+    /// required for the data types that map to a different type depending on it.
+    fn rust_type(&self, type_info: &ExaTypeInfo) -> Result<&'static str, String> {
+        let rust_type = |date_time: DateTime, numeric: Numeric| {
+            let preferred_crates = PreferredCrates {
+                date_time: date_time.into(),
+                numeric: numeric.into(),
+            };
+
+            type_info
+                .rust_type(&preferred_crates)
+                .map_err(|e| e.to_string())
+        };
+
+        let date_time = match self.date_time {
+            Some(date_time) => date_time,
+            None if rust_type(DateTime::Chrono, Numeric::Bigdecimal)?
+                != rust_type(DateTime::Time, Numeric::Bigdecimal)? =>
+            {
+                return Err("choose the date and time crate with `--date-time`".to_owned());
+            }
+            None => DateTime::Chrono,
+        };
+
+        let numeric = match self.numeric {
+            Some(numeric) => numeric,
+            None if rust_type(date_time, Numeric::Bigdecimal)?
+                != rust_type(date_time, Numeric::RustDecimal)? =>
+            {
+                return Err("choose the decimal crate with `--numeric`".to_owned());
+            }
+            None => Numeric::Bigdecimal,
+        };
+
+        let rust_type = rust_type(date_time, numeric)?;
+
+        match date_time {
+            DateTime::Jiff => Ok(DateTime::jiff_type(rust_type)),
+            DateTime::Chrono | DateTime::Time => Ok(rust_type),
+        }
+    }
+
+    /// Adjusts the Rust type to the features enabled by the flags, since the CLI itself is built
+    /// with all of them.
+    fn feature_gated(&self, rust_type: &'static str) -> Result<&'static str, String> {
+        match rust_type {
+            "sqlx_exasol::types::Uuid" if !self.uuid => Ok("sqlx_exasol::types::HashType"),
+            "sqlx_exasol::types::geo_types::Geometry" if !self.geo_types => {
+                Err("no built-in mapping found without `--geo-types`".to_owned())
+            }
+            _ => Ok(rust_type),
+        }
+    }
+}
+
+/// Renders the struct of a table.
+///
+/// Fields whose sanitized names collide get a numeric suffix, e.g. `a_b_2`, and are renamed to
+/// their column.
+fn table_struct(name: &str, columns: &[Column]) -> String {
+    let mut output = String::new();
+    let mut fields = HashSet::new();
+
+    // Writing to a `String` is infallible.
+    let _ = writeln!(output, "#[derive(Debug, sqlx_exasol::FromRow)]");
+    let _ = writeln!(output, "#[sqlx(rename_all = \"UPPERCASE\")]");
+    let _ = writeln!(output, "pub struct {name} {{");
+
+    for column in columns {
+        let field = unique_name(&mut fields, field_name(&column.name), "_");
+
+        if field.trim_start_matches("r#").to_uppercase() != column.name {
+            let _ = writeln!(output, "    #[sqlx(rename = {:?})]", column.name);
+        }
+
+        let ty = if column.nullable {
+            format!("Option<{}>", column.rust_type)
+        } else {
+            column.rust_type.to_owned()
+        };
+
+        let _ = writeln!(output, "    pub {field}: {ty},");
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+/// Appends the lowest numeric suffix starting from 2 that makes the name unique, if it is taken.
+fn unique_name(names: &mut HashSet<String>, name: String, separator: &str) -> String {
+    if names.insert(name.clone()) {
+        return name;
+    }
+
+    let mut suffix = 2;
+
+    while !names.insert(format!("{name}{separator}{suffix}")) {
+        suffix += 1;
+    }
+
+    format!("{name}{separator}{suffix}")
+}
+
+/// Converts a table name to a `PascalCase` struct name, e.g. `USER_ACCOUNTS` to `UserAccounts`.
+fn struct_name(table: &str) -> String {
+    let mut name = table
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let w = w.to_lowercase();
+            let mut chars = w.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect::<String>();
+
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name.insert(0, 'T');
+    }
+
+    name
+}
+
+/// Converts a column name to a `snake_case` field name, e.g. `USER_ID` to `user_id`.
+fn field_name(column: &str) -> String {
+    let mut name = column
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    if KEYWORDS.contains(&name.as_str()) {
+        name.insert_str(0, "r#");
+    } else if PATH_KEYWORDS.contains(&name.as_str()) {
+        name.push('_');
+    }
+
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(uuid: bool, geo_types: bool) -> CodegenCommand {
+        CodegenCommand {
+            connect_opts: ConnectOpts {
+                database_url: String::new(),
+            },
+            schema: String::new(),
+            table: None,
+            date_time: None,
+            numeric: None,
+            uuid,
+            geo_types,
+            out: None,
+        }
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(struct_name("USER_ACCOUNTS"), "UserAccounts");
+        assert_eq!(struct_name("2023_sales"), "T2023Sales");
+        assert_eq!(field_name("USER_ID"), "user_id");
+        assert_eq!(field_name("TYPE"), "r#type");
+        assert_eq!(field_name("CRATE"), "crate_");
+        assert_eq!(field_name("SELF"), "self_");
+        assert_eq!(field_name("Self"), "self_");
+        assert_eq!(field_name("SUPER"), "super_");
+        assert_eq!(field_name("1st place"), "_1st_place");
+    }
+
+    #[test]
+    fn test_table_struct() {
+        let columns = [
+            Column {
+                name: "ID".to_owned(),
+                rust_type: "i64",
+                nullable: false,
+            },
+            Column {
+                name: "Display Name".to_owned(),
+                rust_type: "String",
+                nullable: true,
+            },
+        ];
+
+        let expected = "\
+#[derive(Debug, sqlx_exasol::FromRow)]
+#[sqlx(rename_all = \"UPPERCASE\")]
+pub struct Users {
+    pub id: i64,
+    #[sqlx(rename = \"Display Name\")]
+    pub display_name: Option<String>,
+}
+";
+
+        assert_eq!(table_struct("Users", &columns), expected);
+    }
+
+    #[test]
+    fn test_table_struct_collisions() {
+        let column = |name: &str| Column {
+            name: name.to_owned(),
+            rust_type: "i64",
+            nullable: false,
+        };
+
+        let columns = [
+            column("A B"),
+            column("A-B"),
+            column("A_B_2"),
+            column("CRATE"),
+        ];
+
+        let expected = "\
+#[derive(Debug, sqlx_exasol::FromRow)]
+#[sqlx(rename_all = \"UPPERCASE\")]
+pub struct T {
+    #[sqlx(rename = \"A B\")]
+    pub a_b: i64,
+    #[sqlx(rename = \"A-B\")]
+    pub a_b_2: i64,
+    #[sqlx(rename = \"A_B_2\")]
+    pub a_b_2_2: i64,
+    #[sqlx(rename = \"CRATE\")]
+    pub crate_: i64,
+}
+";
+
+        assert_eq!(table_struct("T", &columns), expected);
+    }
+
+    #[test]
+    fn test_unique_struct_names() {
+        let mut structs = HashSet::new();
+        let mut name = |table: &str| unique_name(&mut structs, struct_name(table), "");
+
+        assert_eq!(name("USER_ACCOUNTS"), "UserAccounts");
+        assert_eq!(name("USER-ACCOUNTS"), "UserAccounts2");
+        assert_eq!(name("user accounts"), "UserAccounts3");
+        assert_eq!(name("USERS"), "Users");
+    }
+
+    #[test]
+    fn test_rust_type() {
+        let rust_type = |date_time, numeric, data_type: &str| {
+            let command = CodegenCommand {
+                date_time,
+                numeric,
+                ..command(false, false)
+            };
+
+            command.rust_type(&data_type.parse().unwrap())
+        };
+
+        // Types that do not depend on a crate need no flags.
+        assert_eq!(rust_type(None, None, "DECIMAL(18,0)").unwrap(), "i64");
+        assert_eq!(rust_type(None, None, "VARCHAR(10) UTF8").unwrap(), "String");
+
+        assert!(rust_type(None, None, "TIMESTAMP").is_err());
+        assert!(rust_type(None, Some(Numeric::Bigdecimal), "TIMESTAMP").is_err());
+        assert_eq!(
+            rust_type(Some(DateTime::Chrono), None, "TIMESTAMP").unwrap(),
+            "sqlx_exasol::types::chrono::NaiveDateTime"
+        );
+        assert_eq!(
+            rust_type(Some(DateTime::Time), None, "TIMESTAMP").unwrap(),
+            "sqlx_exasol::types::time::OffsetDateTime"
+        );
+        assert_eq!(
+            rust_type(Some(DateTime::Jiff), None, "TIMESTAMP").unwrap(),
+            "sqlx_exasol::types::jiff::civil::DateTime"
+        );
+        assert_eq!(
+            rust_type(Some(DateTime::Jiff), None, "DATE").unwrap(),
+            "sqlx_exasol::types::jiff::civil::Date"
+        );
+        assert_eq!(
+            rust_type(Some(DateTime::Jiff), None, "TIMESTAMP WITH LOCAL TIME ZONE").unwrap(),
+            "sqlx_exasol::types::jiff::Timestamp"
+        );
+        assert_eq!(
+            rust_type(Some(DateTime::Jiff), None, "INTERVAL DAY(2) TO SECOND(3)").unwrap(),
+            "sqlx_exasol::types::jiff::SignedDuration"
+        );
+        assert_eq!(
+            rust_type(Some(DateTime::Jiff), None, "DECIMAL(18,0)").unwrap(),
+            "i64"
+        );
+
+        assert!(rust_type(None, None, "DECIMAL(10,2)").is_err());
+        assert_eq!(
+            rust_type(None, Some(Numeric::Bigdecimal), "DECIMAL(10,2)").unwrap(),
+            "sqlx_exasol::types::BigDecimal"
+        );
+        assert_eq!(
+            rust_type(None, Some(Numeric::RustDecimal), "DECIMAL(10,2)").unwrap(),
+            "sqlx_exasol::types::Decimal"
+        );
+    }
+
+    #[test]
+    fn test_feature_gated() {
+        let uuid = "sqlx_exasol::types::Uuid";
+        let geometry = "sqlx_exasol::types::geo_types::Geometry";
+
+        assert_eq!(
+            command(false, false).feature_gated(uuid).unwrap(),
+            "sqlx_exasol::types::HashType"
+        );
+        assert!(command(false, false).feature_gated(geometry).is_err());
+        assert_eq!(command(true, true).feature_gated(uuid).unwrap(), uuid);
+        assert_eq!(
+            command(true, true).feature_gated(geometry).unwrap(),
+            geometry
+        );
+        assert_eq!(command(false, false).feature_gated("i64").unwrap(), "i64");
+    }
+}
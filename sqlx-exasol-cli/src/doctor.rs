@@ -0,0 +1,305 @@
+use std::fmt::{Display, Write as _};
+
+use clap::Args;
+use console::style;
+use sqlx_exasol::{ExaHostDiagnostics, ExaLoginDiagnostics, ExaNodeDiagnostics, ExaTlsDiagnostics};
+
+use crate::{CliResult, ConnectOpts};
+
+/// Check the connectivity to every Exasol node and report what works and what does not.
+#[derive(Args, Debug)]
+pub struct DoctorCommand {
+    #[clap(flatten)]
+    connect_opts: ConnectOpts,
+
+    /// Also check whether an ETL socket handshake to each node succeeds.
+    #[clap(long)]
+    etl: bool,
+}
+
+impl DoctorCommand {
+    /// Probes every node resolved from the connection string and prints a report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection string is invalid or if no node could be logged into.
+    pub async fn run(self) -> CliResult<()> {
+        let opts = self.connect_opts.options()?;
+        let hosts = opts.diagnose(self.etl).await;
+
+        let mut report = Report::default();
+        for host in &hosts {
+            report.host(host);
+        }
+
+        print!("{}", report.0);
+        ensure_logged_in(&hosts)
+    }
+}
+
+/// Fails unless any node could be logged into.
+fn ensure_logged_in(hosts: &[ExaHostDiagnostics]) -> CliResult<()> {
+    let logged_in = hosts
+        .iter()
+        .filter_map(|h| h.nodes.as_ref().ok())
+        .flatten()
+        .any(|n| n.login.as_ref().is_some_and(Result::is_ok));
+
+    if logged_in {
+        Ok(())
+    } else {
+        Err("could not log into any Exasol node".into())
+    }
+}
+
+/// The rendered diagnostics.
+#[derive(Default)]
+struct Report(String);
+
+impl Report {
+    fn host(&mut self, host: &ExaHostDiagnostics) {
+        self.line(style(format!("{}:{}", host.host, host.port)).bold());
+
+        let nodes = match &host.nodes {
+            Ok(nodes) => nodes,
+            Err(e) => return self.failure("DNS", e),
+        };
+
+        if nodes.is_empty() {
+            return self.failure("DNS", "no addresses resolved");
+        }
+
+        for node in nodes {
+            self.node(node);
+        }
+    }
+
+    fn node(&mut self, node: &ExaNodeDiagnostics) {
+        self.line(format_args!("  {}", style(node.addr).cyan()));
+
+        match &node.tcp {
+            Ok(elapsed) => self.success("TCP", format_args!("reachable in {elapsed:?}")),
+            Err(e) => self.failure("TCP", e),
+        }
+
+        match &node.tls {
+            None => {}
+            Some(Ok(tls)) => self.tls(tls),
+            Some(Err(e)) => self.failure("TLS", e),
+        }
+
+        match &node.login {
+            None => {}
+            Some(Ok(login)) => self.login(login),
+            Some(Err(e)) => self.failure("Login", e),
+        }
+
+        match &node.etl {
+            None => {}
+            Some(Ok(addr)) => self.success(
+                "ETL",
+                format_args!("handshake succeeded, internal address {addr}"),
+            ),
+            Some(Err(e)) => self.failure("ETL", e),
+        }
+    }
+
+    fn tls(&mut self, tls: &ExaTlsDiagnostics) {
+        self.success("TLS", "certificate retrieved");
+        self.detail("subject", &tls.subject);
+        self.detail("issuer", &tls.issuer);
+        self.detail("not before", &tls.not_before);
+        self.detail("not after", &tls.not_after);
+        self.detail("fingerprint (SHA-256)", &tls.fingerprint);
+        self.detail("chain length", tls.chain_len);
+        self.detail("protocol", &tls.protocol_version);
+    }
+
+    fn login(&mut self, login: &ExaLoginDiagnostics) {
+        let info = &login.session_info;
+        let attributes = &login.attributes;
+
+        self.success("Login", "succeeded");
+        self.detail("protocol version", u8::from(info.protocol_version()));
+        self.detail("session id", info.session_id());
+        self.detail("release version", info.release_version());
+        self.detail("database name", info.database_name());
+        self.detail("product name", info.product_name());
+        self.detail("max data message size", info.max_data_message_size());
+        self.detail("max identifier length", info.max_identifier_length());
+        self.detail("max varchar length", info.max_varchar_length());
+        self.detail("identifier quote", info.identifier_quote_string());
+        self.detail("timezone", info.timezone());
+        self.detail("timezone behavior", info.time_zone_behavior());
+        self.detail("tls", login.with_tls);
+        self.detail("compression", attributes.compression_enabled());
+        self.detail("autocommit", attributes.autocommit());
+        self.detail(
+            "current schema",
+            attributes.current_schema().unwrap_or("<none>"),
+        );
+        self.detail("query timeout", attributes.query_timeout());
+        self.detail(
+            "snapshot transactions",
+            attributes.snapshot_transactions_enabled(),
+        );
+        self.detail("timestamp utc", attributes.timestamp_utc_enabled());
+        self.detail("date format", attributes.date_format());
+        self.detail("datetime format", attributes.datetime_format());
+        self.detail("numeric characters", attributes.numeric_characters());
+        self.detail("fetch size", attributes.fetch_size());
+    }
+
+    fn success(&mut self, step: &str, message: impl Display) {
+        self.line(format_args!("    {} {step}: {message}", style("✓").green()));
+    }
+
+    fn failure(&mut self, step: &str, message: impl Display) {
+        self.line(format_args!(
+            "    {} {step}: {}",
+            style("✗").red(),
+            style(message).red()
+        ));
+    }
+
+    fn detail(&mut self, name: &str, value: impl Display) {
+        self.line(format_args!("        {}: {value}", style(name).dim()));
+    }
+
+    fn line(&mut self, line: impl Display) {
+        // Writing to a `String` is infallible.
+        let _ = writeln!(self.0, "{line}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use sqlx_exasol::Error;
+
+    use super::*;
+
+    fn tls() -> ExaTlsDiagnostics {
+        ExaTlsDiagnostics {
+            fingerprint: "AB12".to_owned(),
+            subject: "CN=exasol.example.com".to_owned(),
+            issuer: "CN=Example CA".to_owned(),
+            not_before: "2024-01-02 00:00:00 UTC".to_owned(),
+            not_after: "2051-03-04 00:00:00 UTC".to_owned(),
+            chain_len: 2,
+            protocol_version: "TLSv1_3".to_owned(),
+        }
+    }
+
+    fn render(hosts: &[ExaHostDiagnostics]) -> String {
+        console::set_colors_enabled(false);
+
+        let mut report = Report::default();
+        for host in hosts {
+            report.host(host);
+        }
+
+        report.0
+    }
+
+    #[test]
+    fn test_report() {
+        let hosts = [
+            ExaHostDiagnostics {
+                host: "missing.example.com".into(),
+                port: 8563,
+                nodes: Err(Error::Protocol("no such host".to_owned())),
+            },
+            ExaHostDiagnostics {
+                host: "exasol.example.com".into(),
+                port: 8563,
+                nodes: Ok(vec![
+                    ExaNodeDiagnostics {
+                        addr: "10.0.0.1:8563".parse().unwrap(),
+                        tcp: Ok(Duration::from_millis(5)),
+                        tls: Some(Ok(tls())),
+                        login: Some(Err(Error::Protocol("login failed".to_owned()))),
+                        etl: Some(Ok("10.0.0.11:20000".parse().unwrap())),
+                    },
+                    ExaNodeDiagnostics {
+                        addr: "10.0.0.2:8563".parse().unwrap(),
+                        tcp: Err(Error::Protocol("connection refused".to_owned())),
+                        tls: None,
+                        login: None,
+                        etl: None,
+                    },
+                ]),
+            },
+        ];
+
+        let expected = "\
+missing.example.com:8563
+    ✗ DNS: encountered unexpected or invalid data: no such host
+exasol.example.com:8563
+  10.0.0.1:8563
+    ✓ TCP: reachable in 5ms
+    ✓ TLS: certificate retrieved
+        subject: CN=exasol.example.com
+        issuer: CN=Example CA
+        not before: 2024-01-02 00:00:00 UTC
+        not after: 2051-03-04 00:00:00 UTC
+        fingerprint (SHA-256): AB12
+        chain length: 2
+        protocol: TLSv1_3
+    ✗ Login: encountered unexpected or invalid data: login failed
+    ✓ ETL: handshake succeeded, internal address 10.0.0.11:20000
+  10.0.0.2:8563
+    ✗ TCP: encountered unexpected or invalid data: connection refused
+";
+
+        assert_eq!(render(&hosts), expected);
+        assert!(ensure_logged_in(&hosts).is_err());
+    }
+
+    #[test]
+    fn test_report_no_addresses() {
+        let hosts = [ExaHostDiagnostics {
+            host: "exasol.example.com".into(),
+            port: 8563,
+            nodes: Ok(Vec::new()),
+        }];
+
+        assert_eq!(
+            render(&hosts),
+            "exasol.example.com:8563\n    ✗ DNS: no addresses resolved\n"
+        );
+        assert!(ensure_logged_in(&hosts).is_err());
+    }
+
+    #[test]
+    fn test_ensure_logged_in() {
+        let hosts = [ExaHostDiagnostics {
+            host: "exasol.example.com".into(),
+            port: 8563,
+            nodes: Ok(vec![ExaNodeDiagnostics {
+                addr: "10.0.0.1:8563".parse().unwrap(),
+                tcp: Ok(Duration::from_millis(5)),
+                tls: None,
+                login: Some(Err(Error::Protocol("login failed".to_owned()))),
+                etl: None,
+            }]),
+        }];
+
+        let err = ensure_logged_in(&hosts).unwrap_err();
+        assert_eq!(err.to_string(), "could not log into any Exasol node");
+        assert!(ensure_logged_in(&[]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_invalid_url() {
+        let command = DoctorCommand {
+            connect_opts: ConnectOpts {
+                database_url: "postgres://localhost:5432".to_owned(),
+            },
+            etl: false,
+        };
+
+        assert!(command.run().await.is_err());
+    }
+}
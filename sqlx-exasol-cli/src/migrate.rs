@@ -0,0 +1,91 @@
+use std::path::PathBuf;
+
+use clap::{Args, Subcommand};
+use console::style;
+use sqlx_exasol::migrate::Migrator;
+
+use crate::{CliResult, ConnectOpts};
+
+#[derive(Subcommand, Debug)]
+pub enum MigrateCommand {
+    /// Mark all migrations up to and including the given version as applied, without running
+    /// them. Useful for adopting migrations on an existing schema.
+    Baseline {
+        #[clap(flatten)]
+        opts: MigrateOpts,
+
+        /// The version to baseline the schema at.
+        version: i64,
+    },
+
+    /// Rewrite the stored checksums of applied migrations whose files were changed after being
+    /// applied. Only use this after reviewing the changes.
+    Repair {
+        #[clap(flatten)]
+        opts: MigrateOpts,
+
+        /// Only repair the migration with this version.
+        #[clap(long)]
+        version: Option<i64>,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct MigrateOpts {
+    #[clap(flatten)]
+    connect_opts: ConnectOpts,
+
+    /// Path to folder containing migrations.
+    #[clap(long, default_value = "migrations")]
+    source: PathBuf,
+
+    /// Do not acquire the migration lock.
+    #[clap(long)]
+    no_lock: bool,
+}
+
+impl MigrateOpts {
+    async fn migrator(&self) -> CliResult<Migrator> {
+        let mut migrator = Migrator::new(self.source.as_path()).await?;
+        migrator.set_locking(!self.no_lock);
+        Ok(migrator)
+    }
+}
+
+impl MigrateCommand {
+    /// Runs the migration subcommand.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if resolving the migrations or running the subcommand fails.
+    pub async fn run(self) -> CliResult<()> {
+        match self {
+            Self::Baseline { opts, version } => {
+                let migrator = opts.migrator().await?;
+                let mut conn = opts.connect_opts.connect().await?;
+
+                conn.baseline_migrations(&migrator, version).await?;
+                println!(
+                    "Baselined migrations up to version {}",
+                    style(version).cyan()
+                );
+            }
+            Self::Repair { opts, version } => {
+                let migrator = opts.migrator().await?;
+                let mut conn = opts.connect_opts.connect().await?;
+
+                let repaired = conn.repair_migrations(&migrator, version).await?;
+
+                if repaired.is_empty() {
+                    println!("No checksums to repair");
+                }
+
+                for version in repaired {
+                    println!("Repaired checksum of migration {}", style(version).cyan());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
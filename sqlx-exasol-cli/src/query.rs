@@ -0,0 +1,428 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
+use console::style;
+use futures_util::TryStreamExt;
+use serde_json::Value as JsonValue;
+use sqlx_exasol::{AssertSqlSafe, Column, Either, ExaRow, Executor, Row, SqlSafeStr};
+
+use crate::{CliResult, ConnectOpts};
+
+/// Run SQL statements and print their results.
+#[derive(Args, Debug)]
+pub struct QueryCommand {
+    #[clap(flatten)]
+    connect_opts: ConnectOpts,
+
+    /// The SQL to run. Multiple statements must be separated by `;`.
+    #[clap(conflicts_with = "file", required_unless_present = "file")]
+    sql: Option<String>,
+
+    /// Read the SQL to run from a file.
+    #[clap(long)]
+    file: Option<PathBuf>,
+
+    /// How to print the returned rows.
+    #[clap(long, value_enum, default_value_t = OutputFormat::Table)]
+    format: OutputFormat,
+
+    /// Send the SQL as a single prepared statement instead of splitting it on `;`, e.g. for
+    /// `CREATE SCRIPT` or `CREATE FUNCTION` statements.
+    #[clap(long)]
+    no_split: bool,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum OutputFormat {
+    /// An aligned table.
+    Table,
+    /// CSV with a header row.
+    Csv,
+    /// A JSON object per row.
+    Json,
+}
+
+impl QueryCommand {
+    /// Runs the statements, printing the rows they return and the row counts.
+    ///
+    /// The statements are split by the driver, as for [`Executor::fetch_many`], unless
+    /// `--no-split` is given. Rows are printed as they are streamed and row counts as statements
+    /// complete, in both cases. The driver returns nothing for empty result sets, so their
+    /// header is only printed for a single statement, which gets described for it, and they are
+    /// left out among multiple statements.
+    ///
+    /// For the `csv` and `json` formats the row counts are printed to stderr, so that the output
+    /// only contains the rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the file or running a statement fails.
+    pub async fn run(self) -> CliResult<()> {
+        let sql = match (self.sql, &self.file) {
+            (Some(sql), _) => sql,
+            (None, Some(file)) => tokio::fs::read_to_string(file).await?,
+            (None, None) => return Err("either the SQL or --file must be provided".into()),
+        };
+
+        let sql = sql.trim().to_owned();
+        let mut conn = self.connect_opts.connect().await?;
+        let mut printer = Printer::new(self.format);
+
+        {
+            let mut steps = if self.no_split {
+                // Prepared statements are not split, unlike plain SQL.
+                let query = sqlx_exasol::query(AssertSqlSafe(sql.clone())).persistent(false);
+                conn.fetch_many(query)
+            } else {
+                conn.fetch_many(AssertSqlSafe(sql.clone()))
+            };
+
+            while let Some(step) = steps.try_next().await? {
+                match step {
+                    Either::Left(res) => printer.end_statement(res.rows_affected()),
+                    Either::Right(row) => printer.row(row)?,
+                }
+            }
+        }
+
+        // Nothing is returned for an empty result set, so describe the statement to get its
+        // columns. This fails if there are multiple statements.
+        if printer.is_empty() {
+            if let Ok(describe) = conn.describe(AssertSqlSafe(sql).into_sql_str()).await {
+                let header = describe.columns().iter().map(Column::name);
+                let header = header.map(ToOwned::to_owned).collect::<Vec<_>>();
+
+                if !header.is_empty() {
+                    printer.empty_result_set(header);
+                }
+            }
+        }
+
+        printer.finish();
+        Ok(())
+    }
+}
+
+/// Number of rows the columns of the `table` format get aligned over, so that large result sets
+/// are not buffered entirely.
+const TABLE_BATCH_SIZE: usize = 1000;
+
+/// Prints the result sets and row counts of the statements as they get streamed.
+///
+/// The rows of a result set share their columns, which tells apart the result sets of consecutive
+/// statements.
+struct Printer {
+    format: OutputFormat,
+    /// Whether the output of a statement was printed already.
+    printed: bool,
+    /// The row count of the last statement, printed once the next one starts, so that the
+    /// columns of an empty result set can still be printed instead.
+    count: Option<u64>,
+    result_set: Option<ResultSet>,
+}
+
+/// The result set being printed.
+struct ResultSet {
+    header: Vec<String>,
+    /// Column widths of the `table` format.
+    widths: Vec<usize>,
+    /// Rows of the `table` format waiting for their columns to be aligned.
+    pending: Vec<Vec<String>>,
+    rows: usize,
+    /// The first row of the result set, if any. Keeping it alive ensures the columns of the next
+    /// result set cannot end up at the same address.
+    first: Option<ExaRow>,
+}
+
+impl Printer {
+    fn new(format: OutputFormat) -> Self {
+        Self {
+            format,
+            printed: false,
+            count: None,
+            result_set: None,
+        }
+    }
+
+    /// Whether nothing but an empty result set or a row count of zero was returned.
+    fn is_empty(&self) -> bool {
+        !self.printed && self.result_set.is_none() && self.count.is_none_or(|c| c == 0)
+    }
+
+    fn row(&mut self, row: ExaRow) -> CliResult<()> {
+        if let Some(result_set) = self.result_set.take_if(|rs| !rs.contains(&row)) {
+            self.end_result_set(result_set);
+        }
+
+        if self.result_set.is_none() {
+            self.start_result_set(row.columns().iter().map(|c| c.name().to_owned()).collect());
+        }
+
+        let values = values(&row)?;
+        let format = self.format;
+        let Some(result_set) = &mut self.result_set else {
+            unreachable!("the result set was just started");
+        };
+
+        result_set.rows += 1;
+
+        match format {
+            OutputFormat::Table => {
+                result_set
+                    .pending
+                    .push(values.into_iter().map(table_cell).collect());
+
+                if result_set.pending.len() == TABLE_BATCH_SIZE {
+                    print!("{}", result_set.flush_table());
+                }
+            }
+            OutputFormat::Csv => println!("{}", csv_line(values.into_iter().map(csv_value))),
+            OutputFormat::Json => println!("{}", json_line(&result_set.header, &values)),
+        }
+
+        result_set.first.get_or_insert(row);
+        Ok(())
+    }
+
+    /// Prints the header of an empty result set in place of the row count of its statement.
+    fn empty_result_set(&mut self, header: Vec<String>) {
+        self.count = None;
+        self.start_result_set(header);
+    }
+
+    fn start_result_set(&mut self, header: Vec<String>) {
+        self.flush_count();
+        self.separate();
+
+        if let OutputFormat::Csv = self.format {
+            println!("{}", csv_line(header.iter().map(|h| csv_field(h))));
+        }
+
+        let widths = header.iter().map(|h| h.chars().count()).collect();
+
+        self.result_set = Some(ResultSet {
+            header,
+            widths,
+            pending: Vec::new(),
+            rows: 0,
+            first: None,
+        });
+    }
+
+    /// Ends the result set, if one is being printed, or records the row count of the statement.
+    fn end_statement(&mut self, count: u64) {
+        if let Some(result_set) = self.result_set.take() {
+            self.end_result_set(result_set);
+        } else {
+            self.flush_count();
+            self.count = Some(count);
+        }
+    }
+
+    fn end_result_set(&mut self, mut result_set: ResultSet) {
+        if let OutputFormat::Table = self.format {
+            print!("{}", result_set.flush_table());
+        }
+
+        self.status(&format!("{} rows returned", style(result_set.rows).cyan()));
+    }
+
+    fn finish(mut self) {
+        match self.result_set.take() {
+            Some(result_set) => self.end_result_set(result_set),
+            None => self.flush_count(),
+        }
+    }
+
+    fn flush_count(&mut self) {
+        if let Some(count) = self.count.take() {
+            self.separate();
+            self.status(&format!("{} rows affected", style(count).cyan()));
+        }
+    }
+
+    /// Separates the outputs of the statements by an empty line.
+    fn separate(&mut self) {
+        if self.printed {
+            println!();
+        }
+
+        self.printed = true;
+    }
+
+    fn status(&self, status: &str) {
+        match self.format {
+            OutputFormat::Table => println!("{status}"),
+            OutputFormat::Csv | OutputFormat::Json => eprintln!("{status}"),
+        }
+    }
+}
+
+impl ResultSet {
+    /// Whether the row belongs to this result set.
+    fn contains(&self, row: &ExaRow) -> bool {
+        self.first
+            .as_ref()
+            .is_none_or(|first| std::ptr::eq(first.columns(), row.columns()))
+    }
+
+    /// Renders the pending rows of the `table` format, preceded by the header if nothing was
+    /// rendered yet. Columns only widen, so rows of later batches might not line up with earlier
+    /// ones.
+    fn flush_table(&mut self) -> String {
+        let mut output = String::new();
+        let first = self.rows == self.pending.len();
+
+        for row in &self.pending {
+            for (width, cell) in self.widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        if first {
+            output.push_str(&table_line(&self.header, &self.widths));
+
+            let separator = self
+                .widths
+                .iter()
+                .map(|w| "-".repeat(*w))
+                .collect::<Vec<_>>();
+            output.push_str(&table_line(&separator, &self.widths));
+        }
+
+        for row in self.pending.drain(..) {
+            output.push_str(&table_line(&row, &self.widths));
+        }
+
+        output
+    }
+}
+
+fn values(row: &ExaRow) -> CliResult<Vec<&JsonValue>> {
+    let values = (0..row.len())
+        .map(|i| row.try_get_raw(i).map(|v| v.as_json()))
+        .collect::<Result<_, _>>()?;
+
+    Ok(values)
+}
+
+/// Renders a value as text, without quotes for strings.
+fn text(value: &JsonValue) -> String {
+    match value {
+        JsonValue::String(s) => s.clone(),
+        value => value.to_string(),
+    }
+}
+
+fn table_cell(value: &JsonValue) -> String {
+    if value.is_null() {
+        "NULL".to_owned()
+    } else {
+        text(value)
+    }
+}
+
+fn table_line(cells: &[String], widths: &[usize]) -> String {
+    let line = cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{cell:width$}"))
+        .collect::<Vec<_>>()
+        .join(" | ");
+
+    format!("{}\n", line.trim_end())
+}
+
+fn csv_value(value: &JsonValue) -> String {
+    if value.is_null() {
+        String::new()
+    } else {
+        csv_field(&text(value))
+    }
+}
+
+fn csv_line(fields: impl Iterator<Item = String>) -> String {
+    fields.collect::<Vec<_>>().join(",")
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn json_line(header: &[String], values: &[&JsonValue]) -> String {
+    let fields = header
+        .iter()
+        .zip(values)
+        .map(|(name, value)| format!("{}:{value}", JsonValue::from(name.as_str())))
+        .collect::<Vec<_>>();
+
+    format!("{{{}}}", fields.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_csv_line() {
+        let values = [json!(1), json!("a,b"), JsonValue::Null];
+        assert_eq!(csv_line(values.iter().map(csv_value)), "1,\"a,b\",");
+    }
+
+    #[test]
+    fn test_json_line() {
+        let header = ["ID".to_owned(), "NAME".to_owned()];
+        let values = [json!(1), JsonValue::Null];
+        let values = values.iter().collect::<Vec<_>>();
+
+        assert_eq!(json_line(&header, &values), r#"{"ID":1,"NAME":null}"#);
+    }
+
+    #[test]
+    fn test_flush_table() {
+        let mut result_set = ResultSet {
+            header: vec!["ID".to_owned(), "NAME".to_owned()],
+            widths: vec![2, 4],
+            pending: Vec::new(),
+            rows: 0,
+            first: None,
+        };
+
+        assert_eq!(result_set.flush_table(), "ID | NAME\n-- | ----\n");
+
+        result_set
+            .pending
+            .push(vec!["1".to_owned(), "alice".to_owned()]);
+        result_set
+            .pending
+            .push(vec!["200".to_owned(), "NULL".to_owned()]);
+        result_set.rows = 2;
+
+        assert_eq!(
+            result_set.flush_table(),
+            "ID  | NAME\n--- | -----\n1   | alice\n200 | NULL\n"
+        );
+
+        // Later batches only get their rows rendered.
+        result_set
+            .pending
+            .push(vec!["3".to_owned(), "bob".to_owned()]);
+        result_set.rows = 3;
+
+        assert_eq!(result_set.flush_table(), "3   | bob\n");
+        assert!(result_set.pending.is_empty());
+    }
+}
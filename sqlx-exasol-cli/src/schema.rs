@@ -0,0 +1,580 @@
+use std::{fmt::Write as _, path::PathBuf};
+
+use clap::{Args, Subcommand};
+use console::style;
+use sqlx_exasol::{ExaConnection, ExaTypeInfo};
+
+use crate::{CliResult, ConnectOpts};
+
+#[derive(Subcommand, Debug)]
+pub enum SchemaCommand {
+    /// Write the DDL of schemas, including their tables, constraints, views, functions, scripts
+    /// and comments.
+    Dump(DumpCommand),
+}
+
+#[derive(Args, Debug)]
+pub struct DumpCommand {
+    #[clap(flatten)]
+    connect_opts: ConnectOpts,
+
+    /// The schemas to dump, as stored in the catalog, so unquoted names are uppercase. Can be
+    /// given multiple times.
+    #[clap(long, required = true)]
+    schema: Vec<String>,
+
+    /// Write the DDL to a file instead of stdout.
+    #[clap(long)]
+    out: Option<PathBuf>,
+}
+
+/// A table column, as listed in `EXA_ALL_COLUMNS`.
+#[derive(Debug)]
+struct Column {
+    name: String,
+    data_type: String,
+    nullable: bool,
+    default: Option<String>,
+    identity: bool,
+    distribution_key: bool,
+    /// The position of the column in the partition key, if it is part of it.
+    partition_key: Option<u32>,
+}
+
+type ColumnRow = (
+    String,
+    String,
+    String,
+    bool,
+    Option<String>,
+    bool,
+    Option<String>,
+    bool,
+    Option<u32>,
+);
+
+type ViewRow = (String, String, Option<String>);
+
+type ConstraintRow = (
+    String,
+    String,
+    String,
+    String,
+    bool,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+);
+
+/// A primary or foreign key constraint.
+#[derive(Debug)]
+struct Constraint {
+    table: String,
+    name: String,
+    kind: String,
+    enabled: bool,
+    columns: Vec<String>,
+    ref_schema: Option<String>,
+    ref_table: Option<String>,
+    ref_columns: Vec<String>,
+}
+
+impl SchemaCommand {
+    /// Runs the schema subcommand.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if querying the system views or writing the output fails.
+    pub async fn run(self) -> CliResult<()> {
+        match self {
+            Self::Dump(command) => command.run().await,
+        }
+    }
+}
+
+impl DumpCommand {
+    async fn run(self) -> CliResult<()> {
+        let mut conn = self.connect_opts.connect().await?;
+
+        let mut output = String::new();
+
+        for (i, schema) in self.schema.iter().enumerate() {
+            if i > 0 {
+                output.push('\n');
+            }
+
+            output.push_str(&dump_schema(&mut conn, schema).await?);
+        }
+
+        match &self.out {
+            Some(path) => {
+                tokio::fs::write(path, output).await?;
+                eprintln!("Dumped schemas to {}", style(path.display()).cyan());
+            }
+            None => print!("{output}"),
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the DDL of a schema.
+///
+/// Objects are ordered by name so that dumps of identical schemas are identical, except for views
+/// which come after the views they reference. Functions and scripts come before the views, which
+/// can call them. They are terminated by a `/` line, since their bodies contain `;`.
+async fn dump_schema(conn: &mut ExaConnection, schema: &str) -> CliResult<String> {
+    let comment: Option<(Option<String>,)> =
+        sqlx_exasol::query_as("SELECT schema_comment FROM exa_schemas WHERE schema_name = ?;")
+            .bind(schema)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+    let Some((comment,)) = comment else {
+        return Err(format!("schema {schema} not found").into());
+    };
+
+    let mut output = format!("-- Schema {schema}\n");
+    let _ = writeln!(output, "CREATE SCHEMA {};", quote_identifier(schema));
+    push_comment(
+        &mut output,
+        "SCHEMA",
+        &quote_identifier(schema),
+        comment.as_deref(),
+    );
+
+    dump_tables(conn, schema, &mut output).await?;
+
+    let constraints = constraints(conn, schema).await?;
+
+    if !constraints.is_empty() {
+        output.push('\n');
+    }
+
+    for constraint in constraints {
+        output.push_str(&add_constraint(schema, &constraint));
+    }
+
+    for object_type in ["FUNCTION", "SCRIPT"] {
+        let view = object_type.to_lowercase();
+
+        let query = format!(
+            "
+            SELECT {view}_name, {view}_text, {view}_comment
+            FROM exa_all_{view}s
+            WHERE {view}_schema = ?
+            ORDER BY {view}_name;"
+        );
+
+        let objects: Vec<(String, String, Option<String>)> =
+            sqlx_exasol::query_as(sqlx_exasol::AssertSqlSafe(query))
+                .bind(schema)
+                .fetch_all(&mut *conn)
+                .await?;
+
+        for (object, text, comment) in objects {
+            let name = format!("{}.{}", quote_identifier(schema), quote_identifier(&object));
+
+            let _ = write!(output, "\n{}\n/\n", text.trim());
+            push_comment(&mut output, object_type, &name, comment.as_deref());
+        }
+    }
+
+    let views: Vec<ViewRow> = sqlx_exasol::query_as(
+        "
+        SELECT view_name, view_text, view_comment
+        FROM exa_all_views
+        WHERE view_schema = ?
+        ORDER BY view_name;",
+    )
+    .bind(schema)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let dependencies: Vec<(String, String)> = sqlx_exasol::query_as(
+        "
+        SELECT object_name, referenced_object_name
+        FROM exa_all_dependencies
+        WHERE object_schema = ?
+            AND object_type = 'VIEW'
+            AND referenced_object_schema = ?
+            AND referenced_object_type = 'VIEW';",
+    )
+    .bind(schema)
+    .bind(schema)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for (view, text, comment) in order_views(views, &dependencies) {
+        let text = text.trim().trim_end_matches(';');
+        let name = format!("{}.{}", quote_identifier(schema), quote_identifier(&view));
+
+        let _ = write!(output, "\n{text};\n");
+        push_comment(&mut output, "VIEW", &name, comment.as_deref());
+    }
+
+    Ok(output)
+}
+
+/// Appends the DDL of the tables in the schema, along with their comments, to the output.
+async fn dump_tables(conn: &mut ExaConnection, schema: &str, output: &mut String) -> CliResult<()> {
+    let tables: Vec<(String, Option<String>)> = sqlx_exasol::query_as(
+        "
+        SELECT table_name, table_comment
+        FROM exa_all_tables
+        WHERE table_schema = ?
+        ORDER BY table_name;",
+    )
+    .bind(schema)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let columns: Vec<ColumnRow> = sqlx_exasol::query_as(
+        "
+        SELECT
+            column_table,
+            column_name,
+            column_type,
+            column_is_nullable,
+            column_default,
+            column_identity IS NOT NULL,
+            column_comment,
+            column_is_distribution_key,
+            column_partition_key_ordinal_position
+        FROM exa_all_columns
+        WHERE column_schema = ? AND column_object_type = 'TABLE'
+        ORDER BY column_table, column_ordinal_position;",
+    )
+    .bind(schema)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for (table, table_comment) in tables {
+        let name = format!("{}.{}", quote_identifier(schema), quote_identifier(&table));
+        let mut table_columns = Vec::new();
+        let mut column_comments = Vec::new();
+
+        for row in columns.iter().filter(|c| c.0 == table) {
+            let (
+                _,
+                column,
+                data_type,
+                nullable,
+                default,
+                identity,
+                comment,
+                distribution_key,
+                partition_key,
+            ) = row.clone();
+
+            column_comments.push((column.clone(), comment));
+            table_columns.push(Column {
+                name: column,
+                data_type,
+                nullable,
+                default,
+                identity,
+                distribution_key,
+                partition_key,
+            });
+        }
+
+        output.push('\n');
+        output.push_str(&create_table(&name, &table_columns));
+        push_comment(output, "TABLE", &name, table_comment.as_deref());
+
+        for (column, comment) in column_comments {
+            let column = format!("{name}.{}", quote_identifier(&column));
+            push_comment(output, "COLUMN", &column, comment.as_deref());
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the primary and foreign key constraints of the tables in the schema, primary keys
+/// first so that foreign keys can reference them.
+async fn constraints(conn: &mut ExaConnection, schema: &str) -> CliResult<Vec<Constraint>> {
+    let rows: Vec<ConstraintRow> = sqlx_exasol::query_as(
+        "
+        SELECT
+            cc.constraint_table,
+            cc.constraint_name,
+            cc.constraint_type,
+            cc.column_name,
+            c.constraint_enabled,
+            cc.referenced_schema,
+            cc.referenced_table,
+            cc.referenced_column
+        FROM exa_all_constraint_columns cc
+        JOIN exa_all_constraints c
+            ON c.constraint_schema = cc.constraint_schema
+            AND c.constraint_table = cc.constraint_table
+            AND c.constraint_name = cc.constraint_name
+        WHERE cc.constraint_schema = ?
+            AND cc.constraint_type IN ('PRIMARY KEY', 'FOREIGN KEY')
+        ORDER BY
+            cc.constraint_type DESC,
+            cc.constraint_table,
+            cc.constraint_name,
+            cc.ordinal_position;",
+    )
+    .bind(schema)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut constraints = Vec::<Constraint>::new();
+
+    for (table, name, kind, column, enabled, ref_schema, ref_table, ref_column) in rows {
+        let column = quote_identifier(&column);
+        let ref_column = ref_column.as_deref().map(quote_identifier);
+
+        match constraints.last_mut() {
+            Some(c) if c.table == table && c.name == name => {
+                c.columns.push(column);
+                c.ref_columns.extend(ref_column);
+            }
+            _ => constraints.push(Constraint {
+                table,
+                name,
+                kind,
+                enabled,
+                columns: vec![column],
+                ref_schema,
+                ref_table,
+                ref_columns: ref_column.into_iter().collect(),
+            }),
+        }
+    }
+
+    Ok(constraints)
+}
+
+fn create_table(name: &str, columns: &[Column]) -> String {
+    let mut definitions = columns
+        .iter()
+        .map(|c| {
+            // Reuse the driver formatting of data types, falling back to the catalog one.
+            let data_type = c
+                .data_type
+                .parse::<ExaTypeInfo>()
+                .map_or_else(|_| c.data_type.clone(), |t| t.to_string());
+
+            let mut definition = format!("{} {data_type}", quote_identifier(&c.name));
+
+            if let Some(default) = &c.default {
+                let _ = write!(definition, " DEFAULT {default}");
+            }
+
+            if c.identity {
+                definition.push_str(" IDENTITY");
+            }
+
+            if !c.nullable {
+                definition.push_str(" NOT NULL");
+            }
+
+            definition
+        })
+        .collect::<Vec<_>>();
+
+    let distribution_keys = columns
+        .iter()
+        .filter(|c| c.distribution_key)
+        .map(|c| quote_identifier(&c.name))
+        .collect::<Vec<_>>();
+
+    if !distribution_keys.is_empty() {
+        definitions.push(format!("DISTRIBUTE BY {}", distribution_keys.join(", ")));
+    }
+
+    let mut partition_keys = columns
+        .iter()
+        .filter_map(|c| Some((c.partition_key?, quote_identifier(&c.name))))
+        .collect::<Vec<_>>();
+
+    partition_keys.sort_unstable();
+
+    if !partition_keys.is_empty() {
+        let partition_keys = partition_keys
+            .into_iter()
+            .map(|(_, name)| name)
+            .collect::<Vec<_>>();
+
+        definitions.push(format!("PARTITION BY {}", partition_keys.join(", ")));
+    }
+
+    format!(
+        "CREATE TABLE {name} (\n    {}\n);\n",
+        definitions.join(",\n    ")
+    )
+}
+
+/// Orders the views so that each one comes after the views it references, given as `(view,
+/// referenced view)` pairs, keeping the name order otherwise.
+fn order_views(mut views: Vec<ViewRow>, dependencies: &[(String, String)]) -> Vec<ViewRow> {
+    let mut ordered = Vec::with_capacity(views.len());
+
+    while !views.is_empty() {
+        let is_pending = |name: &String| views.iter().any(|(view, ..)| view == name);
+
+        // Views cannot reference each other cyclically, but fall back to the name order anyway.
+        let next = views
+            .iter()
+            .position(|(view, ..)| {
+                !dependencies
+                    .iter()
+                    .any(|(dependent, referenced)| dependent == view && is_pending(referenced))
+            })
+            .unwrap_or_default();
+
+        ordered.push(views.remove(next));
+    }
+
+    ordered
+}
+
+fn add_constraint(schema: &str, c: &Constraint) -> String {
+    // System generated names are reserved.
+    let name = if c.name.starts_with("SYS_") {
+        String::new()
+    } else {
+        format!("CONSTRAINT {} ", quote_identifier(&c.name))
+    };
+
+    let mut statement = format!(
+        "ALTER TABLE {}.{} ADD {name}{} ({})",
+        quote_identifier(schema),
+        quote_identifier(&c.table),
+        c.kind,
+        c.columns.join(", ")
+    );
+
+    if let Some(ref_table) = &c.ref_table {
+        let ref_schema = c.ref_schema.as_deref().unwrap_or(schema);
+
+        let _ = write!(
+            statement,
+            " REFERENCES {}.{} ({})",
+            quote_identifier(ref_schema),
+            quote_identifier(ref_table),
+            c.ref_columns.join(", ")
+        );
+    }
+
+    statement.push_str(if c.enabled {
+        " ENABLE;\n"
+    } else {
+        " DISABLE;\n"
+    });
+    statement
+}
+
+fn push_comment(output: &mut String, object_type: &str, name: &str, comment: Option<&str>) {
+    if let Some(comment) = comment {
+        let _ = writeln!(
+            output,
+            "COMMENT ON {object_type} {name} IS {};",
+            quote_string(comment)
+        );
+    }
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn quote_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_table() {
+        let columns = [
+            Column {
+                name: "ID".to_owned(),
+                data_type: "DECIMAL(18,0)".to_owned(),
+                nullable: false,
+                default: None,
+                identity: true,
+                distribution_key: true,
+                partition_key: None,
+            },
+            Column {
+                name: "NAME".to_owned(),
+                data_type: "VARCHAR(100) UTF8".to_owned(),
+                nullable: true,
+                default: Some("'anonymous'".to_owned()),
+                identity: false,
+                distribution_key: false,
+                partition_key: Some(2),
+            },
+            Column {
+                name: "CREATED".to_owned(),
+                data_type: "DATE".to_owned(),
+                nullable: false,
+                default: None,
+                identity: false,
+                distribution_key: false,
+                partition_key: Some(1),
+            },
+        ];
+
+        let expected = r#"CREATE TABLE "S"."USERS" (
+    "ID" DECIMAL(18, 0) IDENTITY NOT NULL,
+    "NAME" VARCHAR(100) UTF8 DEFAULT 'anonymous',
+    "CREATED" DATE NOT NULL,
+    DISTRIBUTE BY "ID",
+    PARTITION BY "CREATED", "NAME"
+);
+"#;
+
+        assert_eq!(create_table(r#""S"."USERS""#, &columns), expected);
+    }
+
+    #[test]
+    fn test_order_views() {
+        let view = |name: &str| (name.to_owned(), String::new(), None);
+        let dependency = |view: &str, referenced: &str| (view.to_owned(), referenced.to_owned());
+
+        let views = vec![view("A"), view("B"), view("C"), view("D")];
+        let dependencies = [
+            dependency("A", "C"),
+            dependency("C", "D"),
+            dependency("B", "D"),
+            // Views of other schemas are already created.
+            dependency("D", "OTHER"),
+        ];
+
+        let names = order_views(views, &dependencies)
+            .into_iter()
+            .map(|(name, ..)| name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, ["D", "B", "C", "A"]);
+    }
+
+    #[test]
+    fn test_add_constraint() {
+        let constraint = Constraint {
+            table: "POSTS".to_owned(),
+            name: "FK_AUTHOR".to_owned(),
+            kind: "FOREIGN KEY".to_owned(),
+            enabled: true,
+            columns: vec![r#""AUTHOR_ID""#.to_owned()],
+            ref_schema: Some("S".to_owned()),
+            ref_table: Some("USERS".to_owned()),
+            ref_columns: vec![r#""ID""#.to_owned()],
+        };
+
+        assert_eq!(
+            add_constraint("S", &constraint),
+            "ALTER TABLE \"S\".\"POSTS\" ADD CONSTRAINT \"FK_AUTHOR\" FOREIGN KEY (\"AUTHOR_ID\") \
+             REFERENCES \"S\".\"USERS\" (\"ID\") ENABLE;\n"
+        );
+    }
+}
@@ -3,11 +3,16 @@ mod geo_types;
 #[cfg(feature = "json")]
 mod json;
 
+use std::ops::Range;
+
 use serde::Serialize;
 use serde_json::Error as SerdeError;
 use sqlx_core::{arguments::Arguments, encode::Encode, error::BoxDynError, types::Type};
 
-use crate::{database::Exasol, error::ExaProtocolError, type_info::ExaTypeInfo};
+use crate::{
+    database::Exasol, error::ExaProtocolError, type_info::ExaTypeInfo,
+    types::time_zone::ParamTimeZone,
+};
 
 /// Implementor of [`Arguments`].
 #[derive(Debug, Default)]
@@ -29,12 +34,21 @@ impl Arguments for ExaArguments {
         T: Encode<'t, Self::Database> + Type<Self::Database>,
     {
         let ty = value.produces().unwrap_or_else(T::type_info);
+        let start = self.buf.buffer.len();
 
         self.buf.start_seq();
         let _ = value.encode(&mut self.buf)?;
         self.buf.end_seq();
-        self.buf.add_separator();
 
+        if let Some(time_zone) = self.buf.col_utc_timestamps.take() {
+            self.buf.utc_timestamp_params.push(UtcTimestampParam {
+                index: self.types.len(),
+                range: start..self.buf.buffer.len(),
+                time_zone,
+            });
+        }
+
+        self.buf.add_separator();
         self.buf.check_param_count()?;
 
         self.types.push(ty);
@@ -65,6 +79,25 @@ pub struct ExaBuffer {
     ///
     /// All subsequent columns are expected to have the same amount of rows.
     pub(crate) first_col_params_num: Option<usize>,
+    /// Whether the parameter being serialized consists of UTC timestamps, which must be
+    /// converted to the session time zone if bound to a `TIMESTAMP WITH LOCAL TIME ZONE`, along
+    /// with the time zone to send them in otherwise.
+    pub(crate) col_utc_timestamps: Option<ParamTimeZone>,
+    /// The parameters consisting of UTC timestamps.
+    pub(crate) utc_timestamp_params: Vec<UtcTimestampParam>,
+}
+
+/// A parameter consisting of UTC timestamps, which might have to be converted to another time zone
+/// once the data types of the prepared statement parameters are known.
+#[derive(Clone, Debug)]
+pub(crate) struct UtcTimestampParam {
+    /// The index of the parameter.
+    pub(crate) index: usize,
+    /// The position of the parameter values in the buffer.
+    pub(crate) range: Range<usize>,
+    /// The time zone the timestamps are sent in unless bound to a
+    /// `TIMESTAMP WITH LOCAL TIME ZONE`.
+    pub(crate) time_zone: ParamTimeZone,
 }
 
 impl ExaBuffer {
@@ -98,6 +131,16 @@ impl ExaBuffer {
         Ok(())
     }
 
+    /// Marks the parameter being serialized as consisting of UTC timestamps, which are sent in
+    /// the given time zone unless bound to a `TIMESTAMP WITH LOCAL TIME ZONE`.
+    #[cfg_attr(
+        not(any(feature = "chrono", feature = "jiff", feature = "time")),
+        allow(dead_code)
+    )]
+    pub(crate) fn mark_utc_timestamps(&mut self, time_zone: ParamTimeZone) {
+        self.col_utc_timestamps = Some(time_zone);
+    }
+
     /// Outputs the numbers of parameter sets in the buffer.
     pub(crate) fn num_param_sets(&self) -> usize {
         self.first_col_params_num.unwrap_or_default()
@@ -154,6 +197,8 @@ impl Default for ExaBuffer {
             buffer: String::with_capacity(1),
             col_params_counter: 0,
             first_col_params_num: None,
+            col_utc_timestamps: None,
+            utc_timestamp_params: Vec::new(),
         };
 
         buffer.start_seq();
@@ -0,0 +1,614 @@
+use std::{
+    net::{SocketAddr, SocketAddrV4, ToSocketAddrs},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use futures_util::SinkExt;
+use sqlx_core::net::{Socket, WithSocket};
+
+use crate::{
+    connection::websocket::{
+        future::{Disconnect, WebSocketFuture},
+        socket::WithExaSocket,
+        ExaWebSocket, WithMaybeTlsExaSocket,
+    },
+    responses::{ExaAttributes, SessionInfo},
+    ExaConnectOptions, SqlxError, SqlxResult,
+};
+
+/// Diagnostics of a host from the connection options, as returned by
+/// [`ExaConnectOptions::diagnose`].
+#[derive(Debug)]
+pub struct ExaHostDiagnostics {
+    pub host: Arc<str>,
+    pub port: u16,
+    /// The diagnostics of every node the host resolved to, or the DNS resolution error.
+    pub nodes: SqlxResult<Vec<ExaNodeDiagnostics>>,
+}
+
+/// Diagnostics of a single resolved Exasol node.
+///
+/// The remaining steps are only attempted if the TCP connection succeeds, so they are `None` when
+/// it failed.
+#[derive(Debug)]
+pub struct ExaNodeDiagnostics {
+    pub addr: SocketAddr,
+    /// The time it took to open a TCP connection to the node.
+    pub tcp: SqlxResult<Duration>,
+    /// The TLS certificate of the node. `None` if TLS is disabled. Retrieving it requires the
+    /// `tls-diagnostics` feature.
+    pub tls: Option<SqlxResult<ExaTlsDiagnostics>>,
+    /// The result of connecting and logging in with the connection options.
+    pub login: Option<SqlxResult<ExaLoginDiagnostics>>,
+    /// The internal address returned by the ETL tunneling handshake. Only probed if requested.
+    pub etl: Option<SqlxResult<SocketAddrV4>>,
+}
+
+/// Details of the TLS certificate presented by a node.
+///
+/// The certificate is retrieved through a separate handshake that does not validate it, so that
+/// it can be inspected even if validation is what makes connecting fail.
+#[derive(Clone, Debug)]
+pub struct ExaTlsDiagnostics {
+    /// The SHA-256 fingerprint of the node certificate, as uppercase hex.
+    pub fingerprint: String,
+    /// The subject of the node certificate, e.g. `CN=exasol.example.com, O=Example`.
+    pub subject: String,
+    /// The issuer of the node certificate, in the same format as the subject.
+    pub issuer: String,
+    /// The start of the certificate validity period, as `YYYY-MM-DD HH:MM:SS UTC`.
+    pub not_before: String,
+    /// The end of the certificate validity period, as `YYYY-MM-DD HH:MM:SS UTC`.
+    pub not_after: String,
+    /// The number of certificates in the chain presented by the node.
+    pub chain_len: usize,
+    /// The negotiated TLS protocol version.
+    pub protocol_version: String,
+}
+
+/// Details of a successful login.
+#[derive(Debug)]
+pub struct ExaLoginDiagnostics {
+    pub session_info: SessionInfo,
+    pub attributes: ExaAttributes,
+    /// Whether the connection uses TLS.
+    pub with_tls: bool,
+}
+
+impl ExaConnectOptions {
+    /// Probes every node the configured hosts resolve to, step by step, to find out why
+    /// connecting fails.
+    ///
+    /// Unlike connecting, which only returns the last error encountered, this reports the outcome
+    /// of the TCP connection, TLS handshake, login and, if `with_etl` is set, the ETL tunneling
+    /// handshake for each node.
+    pub async fn diagnose(&self, with_etl: bool) -> Vec<ExaHostDiagnostics> {
+        let mut hosts = Vec::with_capacity(self.hosts.len());
+
+        for (host, port) in &self.hosts {
+            let (h, p) = (host.clone(), *port);
+            let resolved =
+                sqlx_core::rt::spawn_blocking(move || (h.as_ref(), p).to_socket_addrs()).await;
+
+            let nodes = match resolved {
+                Ok(addrs) => {
+                    let mut nodes = Vec::new();
+                    for addr in addrs {
+                        nodes.push(self.diagnose_node(host, addr, with_etl).await);
+                    }
+                    Ok(nodes)
+                }
+                Err(e) => Err(e.into()),
+            };
+
+            hosts.push(ExaHostDiagnostics {
+                host: host.clone(),
+                port: *port,
+                nodes,
+            });
+        }
+
+        hosts
+    }
+
+    async fn diagnose_node(
+        &self,
+        host: &str,
+        addr: SocketAddr,
+        with_etl: bool,
+    ) -> ExaNodeDiagnostics {
+        let ip = addr.ip().to_string();
+
+        let start = Instant::now();
+        let tcp = sqlx_core::net::connect_tcp(&ip, addr.port(), Discard)
+            .await
+            .map(|()| start.elapsed());
+
+        let mut diagnostics = ExaNodeDiagnostics {
+            addr,
+            tcp,
+            tls: None,
+            login: None,
+            etl: None,
+        };
+
+        if diagnostics.tcp.is_err() {
+            return diagnostics;
+        }
+
+        if self.ssl_mode != crate::ExaSslMode::Disabled {
+            diagnostics.tls = Some(tls_certificate(host, addr).await);
+        }
+
+        diagnostics.login = Some(self.diagnose_login(host, addr).await);
+
+        if with_etl {
+            diagnostics.etl = Some(etl_handshake(&ip, addr.port()).await);
+        }
+
+        diagnostics
+    }
+
+    async fn diagnose_login(
+        &self,
+        host: &str,
+        addr: SocketAddr,
+    ) -> SqlxResult<ExaLoginDiagnostics> {
+        let with_socket = WithMaybeTlsExaSocket::new(WithExaSocket(addr), host, self.into());
+        let (mut socket, with_tls) =
+            sqlx_core::net::connect_tcp(&addr.ip().to_string(), addr.port(), with_socket).await??;
+
+        socket.set_io_timeout(self.io_timeout());
+
+        let (mut ws, session_info) =
+            ExaWebSocket::new(host, addr.port(), socket, self.try_into()?, with_tls).await?;
+
+        let attributes = ws.attributes.clone();
+
+        Disconnect::default().future(&mut ws).await?;
+        ws.close().await?;
+
+        Ok(ExaLoginDiagnostics {
+            session_info,
+            attributes,
+            with_tls,
+        })
+    }
+}
+
+/// Implementor of [`WithSocket`] that only checks that the socket got connected.
+struct Discard;
+
+impl WithSocket for Discard {
+    type Output = ();
+
+    async fn with_socket<S: Socket>(self, _socket: S) -> Self::Output {}
+}
+
+#[cfg(feature = "etl")]
+async fn etl_handshake(ip: &str, port: u16) -> SqlxResult<SocketAddrV4> {
+    struct WithTunnelAddress;
+
+    impl WithSocket for WithTunnelAddress {
+        type Output = SqlxResult<SocketAddrV4>;
+
+        async fn with_socket<S: Socket>(self, mut socket: S) -> Self::Output {
+            Ok(crate::etl::tunnel_address(&mut socket).await?)
+        }
+    }
+
+    sqlx_core::net::connect_tcp(ip, port, WithTunnelAddress).await?
+}
+
+#[cfg(not(feature = "etl"))]
+#[allow(clippy::unused_async, reason = "conditionally compiled")]
+async fn etl_handshake(_ip: &str, _port: u16) -> SqlxResult<SocketAddrV4> {
+    Err(SqlxError::Configuration(
+        "ETL diagnostics require the `etl` feature".into(),
+    ))
+}
+
+#[cfg(all(
+    feature = "tls-diagnostics",
+    not(any(feature = "native-tls", feature = "rustls"))
+))]
+compile_error!("the `tls-diagnostics` feature requires a TLS feature");
+
+/// Timeout for the blocking TLS handshake used for retrieving the node certificate.
+#[cfg(feature = "tls-diagnostics")]
+const TLS_PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[cfg(feature = "tls-diagnostics")]
+async fn tls_certificate(host: &str, addr: SocketAddr) -> SqlxResult<ExaTlsDiagnostics> {
+    let host = host.to_owned();
+    sqlx_core::rt::spawn_blocking(move || tls_certificate_blocking(&host, addr)).await
+}
+
+#[cfg(not(feature = "tls-diagnostics"))]
+#[allow(clippy::unused_async, reason = "conditionally compiled")]
+async fn tls_certificate(_host: &str, _addr: SocketAddr) -> SqlxResult<ExaTlsDiagnostics> {
+    Err(SqlxError::Configuration(
+        "TLS certificate diagnostics require the `tls-diagnostics` feature".into(),
+    ))
+}
+
+#[cfg(feature = "tls-diagnostics")]
+fn fingerprint(der: &[u8]) -> String {
+    use std::fmt::Write;
+
+    use sha2::{Digest, Sha256};
+
+    Sha256::digest(der)
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, b| {
+            // Writing to a `String` is infallible.
+            let _ = write!(hex, "{b:02X}");
+            hex
+        })
+}
+
+#[cfg(feature = "tls-diagnostics")]
+fn tcp_stream(addr: SocketAddr) -> SqlxResult<std::net::TcpStream> {
+    let stream = std::net::TcpStream::connect_timeout(&addr, TLS_PROBE_TIMEOUT)?;
+    stream.set_read_timeout(Some(TLS_PROBE_TIMEOUT))?;
+    stream.set_write_timeout(Some(TLS_PROBE_TIMEOUT))?;
+    Ok(stream)
+}
+
+/// Retrieves the node certificate through rustls regardless of the TLS backend, since native-tls
+/// exposes neither the certificate chain nor the negotiated protocol.
+#[cfg(feature = "tls-diagnostics")]
+fn tls_certificate_blocking(host: &str, addr: SocketAddr) -> SqlxResult<ExaTlsDiagnostics> {
+    use rustls::{
+        client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+        crypto::CryptoProvider,
+        pki_types::{CertificateDer, ServerName, UnixTime},
+        ClientConfig, ClientConnection, DigitallySignedStruct, SignatureScheme,
+    };
+
+    /// Accepts any certificate, since the point is inspecting it.
+    #[derive(Debug)]
+    struct AcceptAnyCert(Arc<CryptoProvider>);
+
+    impl ServerCertVerifier for AcceptAnyCert {
+        fn verify_server_cert(
+            &self,
+            _end_entity: &CertificateDer<'_>,
+            _intermediates: &[CertificateDer<'_>],
+            _server_name: &ServerName<'_>,
+            _ocsp_response: &[u8],
+            _now: UnixTime,
+        ) -> Result<ServerCertVerified, rustls::Error> {
+            Ok(ServerCertVerified::assertion())
+        }
+
+        fn verify_tls12_signature(
+            &self,
+            _message: &[u8],
+            _cert: &CertificateDer<'_>,
+            _dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            Ok(HandshakeSignatureValid::assertion())
+        }
+
+        fn verify_tls13_signature(
+            &self,
+            _message: &[u8],
+            _cert: &CertificateDer<'_>,
+            _dss: &DigitallySignedStruct,
+        ) -> Result<HandshakeSignatureValid, rustls::Error> {
+            Ok(HandshakeSignatureValid::assertion())
+        }
+
+        fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+            self.0.signature_verification_algorithms.supported_schemes()
+        }
+    }
+
+    let tls_err = |e: rustls::Error| SqlxError::Tls(e.into());
+
+    // The provider is always picked explicitly, as the process-wide default one is only set if
+    // exactly one provider is enabled across the dependency tree.
+    let builder = ClientConfig::builder_with_provider(crypto_provider())
+        .with_safe_default_protocol_versions()
+        .map_err(tls_err)?;
+    let verifier = AcceptAnyCert(builder.crypto_provider().clone());
+    let config = builder
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(verifier))
+        .with_no_client_auth();
+
+    let server_name =
+        ServerName::try_from(host.to_owned()).map_err(|e| SqlxError::Tls(e.into()))?;
+
+    let mut conn = ClientConnection::new(Arc::new(config), server_name).map_err(tls_err)?;
+    let mut stream = tcp_stream(addr)?;
+
+    while conn.is_handshaking() {
+        conn.complete_io(&mut stream)?;
+    }
+
+    let chain = conn
+        .peer_certificates()
+        .ok_or_else(|| SqlxError::Tls("the node presented no certificate".into()))?;
+
+    let leaf = chain
+        .first()
+        .ok_or_else(|| SqlxError::Tls("the node presented no certificate".into()))?;
+
+    let certificate = x509::Certificate::parse(leaf)
+        .map_err(|e| SqlxError::Tls(format!("could not parse the node certificate: {e}").into()))?;
+
+    let protocol_version = conn
+        .protocol_version()
+        .map_or_else(|| "unknown".to_owned(), |v| format!("{v:?}"));
+
+    Ok(ExaTlsDiagnostics {
+        fingerprint: fingerprint(leaf),
+        subject: certificate.subject,
+        issuer: certificate.issuer,
+        not_before: certificate.not_before,
+        not_after: certificate.not_after,
+        chain_len: chain.len(),
+        protocol_version,
+    })
+}
+
+/// The rustls crypto provider used for retrieving the node certificate.
+///
+/// This is the provider already enabled by the TLS backend, so the diagnostics do not pull in
+/// another one: aws-lc-rs for native-tls and `rustls-aws-lc-rs`, ring for `rustls-ring`.
+#[cfg(feature = "tls-diagnostics")]
+fn crypto_provider() -> Arc<rustls::crypto::CryptoProvider> {
+    #[cfg(any(feature = "native-tls", feature = "rustls-aws-lc-rs"))]
+    let provider = rustls::crypto::aws_lc_rs::default_provider();
+    #[cfg(not(any(feature = "native-tls", feature = "rustls-aws-lc-rs")))]
+    let provider = rustls::crypto::ring::default_provider();
+
+    Arc::new(provider)
+}
+
+/// X.509 parsing, only extracting the certificate details shown by the diagnostics.
+#[cfg(feature = "tls-diagnostics")]
+mod x509 {
+    use yasna::{
+        models::ObjectIdentifier,
+        tags::{TAG_BMPSTRING, TAG_GENERALIZEDTIME, TAG_UTCTIME},
+        ASN1Result, BERReader, Tag,
+    };
+
+    /// The details of a certificate, formatted for display.
+    pub struct Certificate {
+        pub subject: String,
+        pub issuer: String,
+        pub not_before: String,
+        pub not_after: String,
+    }
+
+    impl Certificate {
+        /// Parses the DER encoded certificate.
+        pub fn parse(der: &[u8]) -> ASN1Result<Self> {
+            yasna::parse_der(der, |r| {
+                r.read_sequence(|r| {
+                    let certificate = r.next().read_sequence(|r| {
+                        // The version is optional and defaults to v1.
+                        r.read_optional(|r| r.read_tagged(Tag::context(0), skip))?;
+                        skip(r.next())?; // serial number
+                        skip(r.next())?; // signature algorithm
+                        let issuer = name(r.next())?;
+                        let (not_before, not_after) = r
+                            .next()
+                            .read_sequence(|r| Ok((time(r.next())?, time(r.next())?)))?;
+                        let subject = name(r.next())?;
+
+                        // The public key, unique identifiers and extensions.
+                        while r.read_optional(skip)?.is_some() {}
+
+                        Ok(Self {
+                            subject,
+                            issuer,
+                            not_before,
+                            not_after,
+                        })
+                    })?;
+
+                    skip(r.next())?; // signature algorithm
+                    skip(r.next())?; // signature
+                    Ok(certificate)
+                })
+            })
+        }
+    }
+
+    /// Skips a value whose contents are not needed.
+    fn skip(reader: BERReader<'_, '_>) -> ASN1Result<()> {
+        reader.read_der().map(drop)
+    }
+
+    /// Formats a distinguished name as comma separated `TYPE=value` pairs.
+    fn name(reader: BERReader<'_, '_>) -> ASN1Result<String> {
+        let mut attributes = Vec::new();
+
+        reader.read_sequence_of(|r| {
+            r.read_set_of(|r| {
+                let attribute = r.read_sequence(|r| {
+                    let oid = r.next().read_oid()?;
+                    let value = directory_string(r.next())?;
+                    Ok(format!("{}={value}", attribute_type(&oid)))
+                })?;
+
+                attributes.push(attribute);
+                Ok(())
+            })
+        })?;
+
+        Ok(attributes.join(", "))
+    }
+
+    /// Reads the value of a name attribute. Besides `BMPString`, the string types used in
+    /// certificates are ASCII compatible.
+    fn directory_string(reader: BERReader<'_, '_>) -> ASN1Result<String> {
+        if reader.lookahead_tag()? == TAG_BMPSTRING {
+            return reader.read_bmp_string();
+        }
+
+        let value = reader.read_tagged_der()?;
+        Ok(String::from_utf8_lossy(value.value()).into_owned())
+    }
+
+    /// Returns the short name of common attribute types or the dotted OID otherwise.
+    fn attribute_type(oid: &ObjectIdentifier) -> String {
+        let short_name = match oid.components().as_slice() {
+            [2, 5, 4, 3] => "CN",
+            [2, 5, 4, 6] => "C",
+            [2, 5, 4, 7] => "L",
+            [2, 5, 4, 8] => "ST",
+            [2, 5, 4, 10] => "O",
+            [2, 5, 4, 11] => "OU",
+            [1, 2, 840, 113_549, 1, 9, 1] => "emailAddress",
+            _ => return oid.to_string(),
+        };
+
+        short_name.to_owned()
+    }
+
+    /// Formats a `UTCTime` or `GeneralizedTime` as `YYYY-MM-DD HH:MM:SS UTC`.
+    fn time(reader: BERReader<'_, '_>) -> ASN1Result<String> {
+        let datetime = match reader.lookahead_tag()? {
+            TAG_UTCTIME => *reader.read_utctime()?.datetime(),
+            TAG_GENERALIZEDTIME => *reader.read_generalized_time()?.datetime(),
+            // Let the reader report the unexpected tag.
+            _ => *reader.read_utctime()?.datetime(),
+        };
+
+        Ok(format!(
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+            datetime.year(),
+            u8::from(datetime.month()),
+            datetime.day(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second()
+        ))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use rcgen::{date_time_ymd, CertificateParams, DistinguishedName, DnType, KeyPair};
+
+        use super::*;
+
+        #[test]
+        fn test_parse_certificate() {
+            let mut params = CertificateParams::default();
+            params.distinguished_name = DistinguishedName::new();
+            params
+                .distinguished_name
+                .push(DnType::CommonName, "exasol.example.com");
+            params
+                .distinguished_name
+                .push(DnType::OrganizationName, "Example");
+            params.not_before = date_time_ymd(2024, 1, 2);
+            params.not_after = date_time_ymd(2051, 3, 4);
+            params.subject_alt_names = vec![rcgen::SanType::DnsName(
+                "exasol.example.com".try_into().unwrap(),
+            )];
+
+            let key_pair = KeyPair::generate().unwrap();
+            let cert = params.self_signed(&key_pair).unwrap();
+            let certificate = Certificate::parse(cert.der()).unwrap();
+
+            assert_eq!(certificate.subject, "CN=exasol.example.com, O=Example");
+            assert_eq!(certificate.issuer, certificate.subject);
+            // Dates before 2050 are encoded as `UTCTime`, later ones as `GeneralizedTime`.
+            assert_eq!(certificate.not_before, "2024-01-02 00:00:00 UTC");
+            assert_eq!(certificate.not_after, "2051-03-04 00:00:00 UTC");
+        }
+
+        /// The root certificate of Let's Encrypt.
+        const ISRG_ROOT_X1: &str = "
+-----BEGIN CERTIFICATE-----
+MIIFazCCA1OgAwIBAgIRAIIQz7DSQONZRGPgu2OCiwAwDQYJKoZIhvcNAQELBQAw
+TzELMAkGA1UEBhMCVVMxKTAnBgNVBAoTIEludGVybmV0IFNlY3VyaXR5IFJlc2Vh
+cmNoIEdyb3VwMRUwEwYDVQQDEwxJU1JHIFJvb3QgWDEwHhcNMTUwNjA0MTEwNDM4
+WhcNMzUwNjA0MTEwNDM4WjBPMQswCQYDVQQGEwJVUzEpMCcGA1UEChMgSW50ZXJu
+ZXQgU2VjdXJpdHkgUmVzZWFyY2ggR3JvdXAxFTATBgNVBAMTDElTUkcgUm9vdCBY
+MTCCAiIwDQYJKoZIhvcNAQEBBQADggIPADCCAgoCggIBAK3oJHP0FDfzm54rVygc
+h77ct984kIxuPOZXoHj3dcKi/vVqbvYATyjb3miGbESTtrFj/RQSa78f0uoxmyF+
+0TM8ukj13Xnfs7j/EvEhmkvBioZxaUpmZmyPfjxwv60pIgbz5MDmgK7iS4+3mX6U
+A5/TR5d8mUgjU+g4rk8Kb4Mu0UlXjIB0ttov0DiNewNwIRt18jA8+o+u3dpjq+sW
+T8KOEUt+zwvo/7V3LvSye0rgTBIlDHCNAymg4VMk7BPZ7hm/ELNKjD+Jo2FR3qyH
+B5T0Y3HsLuJvW5iB4YlcNHlsdu87kGJ55tukmi8mxdAQ4Q7e2RCOFvu396j3x+UC
+B5iPNgiV5+I3lg02dZ77DnKxHZu8A/lJBdiB3QW0KtZB6awBdpUKD9jf1b0SHzUv
+KBds0pjBqAlkd25HN7rOrFleaJ1/ctaJxQZBKT5ZPt0m9STJEadao0xAH0ahmbWn
+OlFuhjuefXKnEgV4We0+UXgVCwOPjdAvBbI+e0ocS3MFEvzG6uBQE3xDk3SzynTn
+jh8BCNAw1FtxNrQHusEwMFxIt4I7mKZ9YIqioymCzLq9gwQbooMDQaHWBfEbwrbw
+qHyGO0aoSCqI3Haadr8faqU9GY/rOPNk3sgrDQoo//fb4hVC1CLQJ13hef4Y53CI
+rU7m2Ys6xt0nUW7/vGT1M0NPAgMBAAGjQjBAMA4GA1UdDwEB/wQEAwIBBjAPBgNV
+HRMBAf8EBTADAQH/MB0GA1UdDgQWBBR5tFnme7bl5AFzgAiIyBpY9umbbjANBgkq
+hkiG9w0BAQsFAAOCAgEAVR9YqbyyqFDQDLHYGmkgJykIrGF1XIpu+ILlaS/V9lZL
+ubhzEFnTIZd+50xx+7LSYK05qAvqFyFWhfFQDlnrzuBZ6brJFe+GnY+EgPbk6ZGQ
+3BebYhtF8GaV0nxvwuo77x/Py9auJ/GpsMiu/X1+mvoiBOv/2X/qkSsisRcOj/KK
+NFtY2PwByVS5uCbMiogziUwthDyC3+6WVwW6LLv3xLfHTjuCvjHIInNzktHCgKQ5
+ORAzI4JMPJ+GslWYHb4phowim57iaztXOoJwTdwJx4nLCgdNbOhdjsnvzqvHu7Ur
+TkXWStAmzOVyyghqpZXjFaH3pO3JLF+l+/+sKAIuvtd7u+Nxe5AW0wdeRlN8NwdC
+jNPElpzVmbUq4JUagEiuTDkHzsxHpFKVK7q4+63SM1N95R1NbdWhscdCb+ZAJzVc
+oyi3B43njTOQ5yOf+1CceWxG1bQVs5ZufpsMljq4Ui0/1lvh+wjChP4kqKOJ2qxq
+4RgqsahDYVvTH9w7jXbyLeiNdd8XM2w9U/t7y0Ff/9yi0GE44Za4rF2LN9d11TPA
+mRGunUHBcnWEvgJBQl9nJEiU0Zsnvgc/ubhPgXRR4Xq37Z0j4r7g1SgEEzwxA57d
+emyPxgcYxn/eR44/KJ4EBs+lVDR3veyJm+kXQ99b21/+jh5Xos1AnX5iItreGCc=
+-----END CERTIFICATE-----
+";
+
+        #[test]
+        fn test_parse_real_certificate() {
+            use base64::{engine::general_purpose::STANDARD, Engine};
+
+            let base64 = ISRG_ROOT_X1
+                .lines()
+                .filter(|l| !l.is_empty() && !l.starts_with("-----"))
+                .collect::<String>();
+            let der = STANDARD.decode(base64).unwrap();
+            let certificate = Certificate::parse(&der).unwrap();
+
+            assert_eq!(
+                certificate.subject,
+                "C=US, O=Internet Security Research Group, CN=ISRG Root X1"
+            );
+            assert_eq!(certificate.issuer, certificate.subject);
+            assert_eq!(certificate.not_before, "2015-06-04 11:04:38 UTC");
+            assert_eq!(certificate.not_after, "2035-06-04 11:04:38 UTC");
+        }
+
+        #[test]
+        fn test_parse_invalid_certificate() {
+            assert!(Certificate::parse(&[]).is_err());
+            assert!(Certificate::parse(&[0x30, 0x82, 0xFF]).is_err());
+            assert!(Certificate::parse(&[0x30, 0x00]).is_err());
+        }
+
+        #[test]
+        fn test_attribute_type() {
+            let oid = ObjectIdentifier::from_slice(&[2, 5, 4, 3]);
+            assert_eq!(attribute_type(&oid), "CN");
+
+            let oid = ObjectIdentifier::from_slice(&[1, 2, 840, 113_549]);
+            assert_eq!(attribute_type(&oid), "1.2.840.113549");
+        }
+    }
+}
+
+#[cfg(all(test, feature = "tls-diagnostics"))]
+mod tests {
+    use super::*;
+
+    /// Building the config must not depend on a process-wide default provider, which is missing
+    /// when several providers are enabled.
+    #[test]
+    fn test_crypto_provider() {
+        let res = rustls::ClientConfig::builder_with_provider(crypto_provider())
+            .with_safe_default_protocol_versions();
+        assert!(res.is_ok());
+    }
+}
@@ -41,13 +41,15 @@ type ImportPartsReceiver = flume::Receiver<(ImportDataSender, OneShotServer<Impo
 /// explicitly committed:
 ///
 /// ```rust,no_run
+/// # use sqlx_exasol_impl as sqlx_exasol;
 /// use std::env;
 ///
-/// use sqlx_exasol::{error::*, etl::*, *};
+/// use sqlx_core::{connection::Connection, error::BoxDynError, pool::Pool};
+/// use sqlx_exasol::{etl::*, Exasol};
 ///
 /// # async {
 /// #
-/// let pool = ExaPool::connect(&env::var("DATABASE_URL").unwrap()).await?;
+/// let pool = Pool::<Exasol>::connect(&env::var("DATABASE_URL").unwrap()).await?;
 /// let mut con = pool.acquire().await?;
 /// let mut tx = con.begin().await?;
 ///
@@ -12,6 +12,7 @@ use flume::{Receiver, Sender};
 use futures_core::future::BoxFuture;
 use hyper::server::conn::http1::Connection;
 use semver::Version;
+pub use socket_addr::tunnel_address;
 use sqlx_core::{
     net::WithSocket,
     sql_str::{AssertSqlSafe, SqlSafeStr},
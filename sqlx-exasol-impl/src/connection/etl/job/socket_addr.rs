@@ -33,44 +33,54 @@ where
     type Output = SqlxResult<(SocketAddrV4, ServerBootstrap)>;
 
     async fn with_socket<S: Socket>(self, mut socket: S) -> Self::Output {
-        /// Special Exasol packet that enables tunneling.
-        /// Exasol responds with an internal address that can be used in a query.
-        const SPECIAL_PACKET: [u8; 12] = [2, 33, 33, 2, 1, 0, 0, 0, 1, 0, 0, 0];
+        let address = tunnel_address(&mut socket).await?;
+        Ok((address, self.0.with_socket(socket).await))
+    }
+}
 
-        // Write special packet
-        let mut write_start = 0;
+/// Sends the special Exasol packet that enables tunneling through the socket and returns the
+/// internal address Exasol responds with.
+pub async fn tunnel_address<S: Socket>(socket: &mut S) -> io::Result<SocketAddrV4> {
+    /// Special Exasol packet that enables tunneling.
+    /// Exasol responds with an internal address that can be used in a query.
+    const SPECIAL_PACKET: [u8; 12] = [2, 33, 33, 2, 1, 0, 0, 0, 1, 0, 0, 0];
 
-        while write_start < SPECIAL_PACKET.len() {
-            let written = socket.write(&SPECIAL_PACKET[write_start..]).await?;
-            write_start += written;
-        }
+    // Write special packet
+    let mut write_start = 0;
+
+    while write_start < SPECIAL_PACKET.len() {
+        let written = socket.write(&SPECIAL_PACKET[write_start..]).await?;
+        write_start += written;
+    }
+
+    // Read response buffer.
+    let mut buf = [0; 24];
+    let mut read_start = 0;
 
-        // Read response buffer.
-        let mut buf = [0; 24];
-        let mut read_start = 0;
+    while read_start < buf.len() {
+        let mut buf = &mut buf[read_start..];
+        let read = socket.read(&mut buf).await?;
 
-        while read_start < buf.len() {
-            let mut buf = &mut buf[read_start..];
-            let read = socket.read(&mut buf).await?;
-            read_start += read;
+        if read == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
         }
 
-        // Parse address
-        let ip_buf = buf[8..]
-            .split(|b| *b == b'\0')
-            .next()
-            .expect("at least one slice");
+        read_start += read;
+    }
 
-        let ip = std::str::from_utf8(ip_buf)
-            .map_err(ExaEtlError::from)
-            .map_err(io::Error::from)?
-            .parse::<Ipv4Addr>()
-            .map_err(ExaEtlError::from)
-            .map_err(io::Error::from)?;
+    // Parse address
+    let ip_buf = buf[8..]
+        .split(|b| *b == b'\0')
+        .next()
+        .expect("at least one slice");
 
-        let port = u16::from_le_bytes([buf[4], buf[5]]);
-        let address = SocketAddrV4::new(ip, port);
+    let ip = std::str::from_utf8(ip_buf)
+        .map_err(ExaEtlError::from)
+        .map_err(io::Error::from)?
+        .parse::<Ipv4Addr>()
+        .map_err(ExaEtlError::from)
+        .map_err(io::Error::from)?;
 
-        Ok((address, self.0.with_socket(socket).await))
-    }
+    let port = u16::from_le_bytes([buf[4], buf[5]]);
+    Ok(SocketAddrV4::new(ip, port))
 }
@@ -8,7 +8,7 @@ use sqlx_core::{
     Either,
 };
 
-use super::stream::ResultStream;
+use super::{nullability, stream::ResultStream, ExaQueryOptions};
 use crate::{
     connection::websocket::future::{
         self, ExecuteBatch, ExecutePrepared, GetOrPrepare, WebSocketFuture,
@@ -28,13 +28,7 @@ impl<'c> Executor<'c> for &'c mut ExaConnection {
         'c: 'e,
         E: 'q + Execute<'q, Exasol>,
     {
-        match self.fetch_impl(query) {
-            Ok(stream) => stream
-                .try_filter_map(|v| std::future::ready(Ok(v.left())))
-                .try_collect()
-                .boxed(),
-            Err(e) => std::future::ready(Err(e)).boxed(),
-        }
+        self.execute_with(query, None)
     }
 
     fn execute_many<'e, 'q, E>(self, query: E) -> BoxStream<'e, SqlxResult<ExaQueryResult>>
@@ -43,7 +37,7 @@ impl<'c> Executor<'c> for &'c mut ExaConnection {
         'c: 'e,
         E: 'q + Execute<'q, Exasol>,
     {
-        match self.fetch_many_impl(query) {
+        match self.fetch_many_impl(query, None) {
             Ok(stream) => stream
                 .try_filter_map(|step| std::future::ready(Ok(step.left())))
                 .boxed(),
@@ -57,12 +51,7 @@ impl<'c> Executor<'c> for &'c mut ExaConnection {
         'c: 'e,
         E: 'q + Execute<'q, Exasol>,
     {
-        match self.fetch_impl(query) {
-            Ok(stream) => stream
-                .try_filter_map(|v| std::future::ready(Ok(v.right())))
-                .boxed(),
-            Err(e) => std::future::ready(Err(e)).into_stream().boxed(),
-        }
+        self.fetch_with(query, None)
     }
 
     fn fetch_many<'e, 'q, E>(
@@ -74,10 +63,7 @@ impl<'c> Executor<'c> for &'c mut ExaConnection {
         'c: 'e,
         E: 'q + Execute<'q, Exasol>,
     {
-        match self.fetch_many_impl(query) {
-            Ok(stream) => stream.boxed(),
-            Err(e) => std::future::ready(Err(e)).into_stream().boxed(),
-        }
+        self.fetch_many_with(query, None)
     }
 
     fn fetch_all<'e, 'q: 'e, E>(self, query: E) -> BoxFuture<'e, SqlxResult<Vec<ExaRow>>>
@@ -85,7 +71,7 @@ impl<'c> Executor<'c> for &'c mut ExaConnection {
         'c: 'e,
         E: 'q + Execute<'q, Self::Database>,
     {
-        match self.fetch_impl(query) {
+        match self.fetch_impl(query, None) {
             Ok(stream) => stream
                 .try_filter_map(|v| std::future::ready(Ok(v.right())))
                 .try_collect()
@@ -99,7 +85,7 @@ impl<'c> Executor<'c> for &'c mut ExaConnection {
         'c: 'e,
         E: 'q + Execute<'q, Self::Database>,
     {
-        let stream = match self.fetch_impl(query) {
+        let stream = match self.fetch_impl(query, None) {
             Ok(stream) => stream,
             Err(e) => return std::future::ready(Err(e)).boxed(),
         };
@@ -120,17 +106,7 @@ impl<'c> Executor<'c> for &'c mut ExaConnection {
         'c: 'e,
         E: 'q + Execute<'q, Exasol>,
     {
-        let stream = match self.fetch_impl(query) {
-            Ok(stream) => stream,
-            Err(e) => return std::future::ready(Err(e)).boxed(),
-        };
-
-        Box::pin(async move {
-            stream
-                .try_filter_map(|v| std::future::ready(Ok(v.right())))
-                .try_next()
-                .await
-        })
+        self.fetch_optional_with(query, None)
     }
 
     fn prepare_with<'e>(
@@ -166,9 +142,15 @@ impl<'c> Executor<'c> for &'c mut ExaConnection {
                 columns,
                 parameters,
                 ..
-            } = future::Describe::new(sql).future(&mut self.ws).await?;
+            } = future::Describe::new(sql.clone())
+                .future(&mut self.ws)
+                .await?;
 
-            let nullable = (0..columns.len()).map(|_| None).collect();
+            let nullable = if self.infer_nullability {
+                nullability::infer(self, sql.as_str(), columns.len()).await?
+            } else {
+                vec![None; columns.len()]
+            };
 
             Ok(Describe {
                 parameters: Some(Either::Left(parameters)),
@@ -180,7 +162,91 @@ impl<'c> Executor<'c> for &'c mut ExaConnection {
 }
 
 impl ExaConnection {
-    fn fetch_impl<'c, 'e, 'q, E>(&'c mut self, mut query: E) -> SqlxResult<ResultStream<'e>>
+    /// [`Executor::execute`] with the provided [`ExaQueryOptions`] applied, if any.
+    pub(crate) fn execute_with<'c, 'e, 'q, E>(
+        &'c mut self,
+        query: E,
+        options: Option<ExaQueryOptions>,
+    ) -> BoxFuture<'e, SqlxResult<ExaQueryResult>>
+    where
+        'q: 'e,
+        'c: 'e,
+        E: 'q + Execute<'q, Exasol>,
+    {
+        match self.fetch_impl(query, options) {
+            Ok(stream) => stream
+                .try_filter_map(|v| std::future::ready(Ok(v.left())))
+                .try_collect()
+                .boxed(),
+            Err(e) => std::future::ready(Err(e)).boxed(),
+        }
+    }
+
+    /// [`Executor::fetch`] with the provided [`ExaQueryOptions`] applied, if any.
+    pub(crate) fn fetch_with<'c, 'e, 'q, E>(
+        &'c mut self,
+        query: E,
+        options: Option<ExaQueryOptions>,
+    ) -> BoxStream<'e, SqlxResult<ExaRow>>
+    where
+        'q: 'e,
+        'c: 'e,
+        E: 'q + Execute<'q, Exasol>,
+    {
+        match self.fetch_impl(query, options) {
+            Ok(stream) => stream
+                .try_filter_map(|v| std::future::ready(Ok(v.right())))
+                .boxed(),
+            Err(e) => std::future::ready(Err(e)).into_stream().boxed(),
+        }
+    }
+
+    /// [`Executor::fetch_many`] with the provided [`ExaQueryOptions`] applied, if any.
+    pub(crate) fn fetch_many_with<'c, 'e, 'q, E>(
+        &'c mut self,
+        query: E,
+        options: Option<ExaQueryOptions>,
+    ) -> BoxStream<'e, SqlxResult<Either<ExaQueryResult, ExaRow>>>
+    where
+        'q: 'e,
+        'c: 'e,
+        E: 'q + Execute<'q, Exasol>,
+    {
+        match self.fetch_many_impl(query, options) {
+            Ok(stream) => stream.boxed(),
+            Err(e) => std::future::ready(Err(e)).into_stream().boxed(),
+        }
+    }
+
+    /// [`Executor::fetch_optional`] with the provided [`ExaQueryOptions`] applied, if any.
+    pub(crate) fn fetch_optional_with<'c, 'e, 'q, E>(
+        &'c mut self,
+        query: E,
+        options: Option<ExaQueryOptions>,
+    ) -> BoxFuture<'e, SqlxResult<Option<ExaRow>>>
+    where
+        'q: 'e,
+        'c: 'e,
+        E: 'q + Execute<'q, Exasol>,
+    {
+        let stream = match self.fetch_impl(query, options) {
+            Ok(stream) => stream,
+            Err(e) => return std::future::ready(Err(e)).boxed(),
+        };
+
+        Box::pin(async move {
+            stream
+                .try_filter_map(|v| std::future::ready(Ok(v.right())))
+                .try_next()
+                .await
+        })
+    }
+
+    pub(crate) fn fetch_impl<'c, 'e, 'q, E>(
+        &'c mut self,
+        mut query: E,
+        options: Option<ExaQueryOptions>,
+    ) -> SqlxResult<ResultStream<'e>>
     where
         'q: 'e,
         'c: 'e,
@@ -190,17 +256,26 @@ impl ExaConnection {
         let arguments = query.take_arguments().map_err(SqlxError::Encode)?;
         let logger = QueryLogger::new(query.sql(), self.log_settings.clone());
         let sql = logger.sql().clone();
+        let restore = options
+            .map(|o| self.ws.attributes.apply_query_options(o))
+            .transpose()?;
 
-        if let Some(arguments) = arguments {
+        let stream = if let Some(arguments) = arguments {
             let future = ExecutePrepared::new(sql, persist, arguments);
-            Ok(ResultStream::new(&mut self.ws, logger, future))
+            ResultStream::new(&mut self.ws, logger, future)
         } else {
             let future = future::Execute::new(sql);
-            Ok(ResultStream::new(&mut self.ws, logger, future))
-        }
+            ResultStream::new(&mut self.ws, logger, future)
+        };
+
+        Ok(stream.restore_options(restore))
     }
 
-    fn fetch_many_impl<'c, 'e, 'q, E>(&'c mut self, mut query: E) -> SqlxResult<ResultStream<'e>>
+    pub(crate) fn fetch_many_impl<'c, 'e, 'q, E>(
+        &'c mut self,
+        mut query: E,
+        options: Option<ExaQueryOptions>,
+    ) -> SqlxResult<ResultStream<'e>>
     where
         'q: 'e,
         'c: 'e,
@@ -210,13 +285,18 @@ impl ExaConnection {
         let arguments = query.take_arguments().map_err(SqlxError::Encode)?;
         let logger = QueryLogger::new(query.sql(), self.log_settings.clone());
         let sql = logger.sql().clone();
+        let restore = options
+            .map(|o| self.ws.attributes.apply_query_options(o))
+            .transpose()?;
 
-        if let Some(arguments) = arguments {
+        let stream = if let Some(arguments) = arguments {
             let future = ExecutePrepared::new(sql, persist, arguments);
-            Ok(ResultStream::new(&mut self.ws, logger, future))
+            ResultStream::new(&mut self.ws, logger, future)
         } else {
             let future = ExecuteBatch::new(sql);
-            Ok(ResultStream::new(&mut self.ws, logger, future))
-        }
+            ResultStream::new(&mut self.ws, logger, future)
+        };
+
+        Ok(stream.restore_options(restore))
     }
 }
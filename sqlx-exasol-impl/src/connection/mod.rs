@@ -1,6 +1,9 @@
+mod diagnostics;
 #[cfg(feature = "etl")]
 pub mod etl;
 mod executor;
+mod nullability;
+mod query_options;
 pub mod stream;
 pub mod websocket;
 
@@ -9,7 +12,11 @@ use std::{
     net::{SocketAddr, ToSocketAddrs},
 };
 
+pub use diagnostics::{
+    ExaHostDiagnostics, ExaLoginDiagnostics, ExaNodeDiagnostics, ExaTlsDiagnostics,
+};
 use futures_util::SinkExt;
+pub use query_options::{ExaOptionsExecutor, ExaQueryOptions};
 use rand::{seq::SliceRandom, thread_rng};
 use sqlx_core::{
     connection::{Connection, LogSettings},
@@ -26,7 +33,8 @@ use crate::{
     database::Exasol,
     options::ExaConnectOptions,
     responses::{ExaAttributes, SessionInfo},
-    SqlxError, SqlxResult,
+    transaction::ExaTxOptions,
+    ExaTransaction, SqlxError, SqlxResult,
 };
 
 /// A connection to the Exasol database. Implementor of [`Connection`].
@@ -34,6 +42,9 @@ use crate::{
 pub struct ExaConnection {
     pub(crate) ws: ExaWebSocket,
     pub(crate) log_settings: LogSettings,
+    pub(crate) infer_nullability: bool,
+    #[cfg(feature = "migrate")]
+    pub(crate) migration_lock: crate::migrate::MigrationLock,
     session_info: SessionInfo,
 }
 
@@ -65,6 +76,33 @@ impl ExaConnection {
         SetAttributes::default().future(&mut self.ws).await
     }
 
+    /// Returns an [`Executor`] that runs a single query with the provided [`ExaQueryOptions`].
+    ///
+    /// Unlike changing the [`ExaAttributes`] directly, the options only apply to the query
+    /// executed through the returned executor. The previous attribute values are restored
+    /// afterwards.
+    pub fn with_options(&mut self, options: ExaQueryOptions) -> ExaOptionsExecutor<'_> {
+        ExaOptionsExecutor::new(self, options)
+    }
+
+    /// Begins a new transaction configured through the provided [`ExaTxOptions`].
+    ///
+    /// The transaction related attributes are restored once the transaction is committed or
+    /// rolled back. This is not named `begin_with` so it does not shadow
+    /// [`Connection::begin_with`], which starts a transaction with a custom statement.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if a transaction is already open.
+    pub async fn begin_with_options(
+        &mut self,
+        options: ExaTxOptions,
+    ) -> SqlxResult<ExaTransaction<'_>> {
+        let mut tx: ExaTransaction<'_> = Transaction::begin(self, None).await?;
+        tx.attributes_mut().apply_tx_options(options);
+        Ok(tx)
+    }
+
     /// Returns a reference to the [`SessionInfo`] related to this connection.
     pub fn session_info(&self) -> &SessionInfo {
         &self.session_info
@@ -107,7 +145,7 @@ impl ExaConnection {
             ip_buf.clear();
 
             // Continue if the future to connect a socket failed.
-            let (socket, with_tls) = match socket_res {
+            let (mut socket, with_tls) = match socket_res {
                 Ok(Ok((socket, with_tls))) => (socket, with_tls),
                 Ok(Err(err)) | Err(err) => {
                     error = err;
@@ -115,6 +153,8 @@ impl ExaConnection {
                 }
             };
 
+            socket.set_io_timeout(opts.io_timeout());
+
             match ExaWebSocket::new(host.as_ref(), port, socket, opts.try_into()?, with_tls).await {
                 Err(err) => error = err,
                 // Return if we successfully connect a websocket.
@@ -122,10 +162,15 @@ impl ExaConnection {
                     let mut con = Self {
                         ws,
                         log_settings: LogSettings::default(),
+                        infer_nullability: opts.infer_nullability,
+                        #[cfg(feature = "migrate")]
+                        migration_lock: crate::migrate::MigrationLock::new(
+                            opts.migration_lock_expiry(),
+                        ),
                         session_info,
                     };
 
-                    con.configure_session().await?;
+                    con.configure_session(opts).await?;
                     return Ok(con);
                 }
             }
@@ -136,11 +181,17 @@ impl ExaConnection {
     }
 
     /// Sets session parameters for the open connection.
-    async fn configure_session(&mut self) -> SqlxResult<()> {
+    async fn configure_session(&mut self, opts: &ExaConnectOptions) -> SqlxResult<()> {
         // We rely on this for consistent size output for HASHTYPE columns.
         // This allows to reliably use UUID at compile-time.
         self.execute("ALTER SESSION SET HASHTYPE_FORMAT = 'HEX';")
             .await?;
+        // The default format only has microseconds, which truncates `TIMESTAMP(p)` values with a
+        // higher precision. Nanoseconds can represent values of any precision.
+        if opts.nanosecond_timestamps {
+            self.execute("ALTER SESSION SET NLS_TIMESTAMP_FORMAT = 'YYYY-MM-DD HH24:MI:SS.FF9';")
+                .await?;
+        }
         Ok(())
     }
 }
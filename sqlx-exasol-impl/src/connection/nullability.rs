@@ -0,0 +1,667 @@
+//! Inference of result column nullability for
+//! [`Executor::describe`](sqlx_core::executor::Executor::describe).
+//!
+//! Exasol does not report where the columns of a prepared statement come from, so simple `SELECT`
+//! statements are parsed to find the source table columns. Their nullability is then looked up
+//! in the enabled `NOT NULL` and `PRIMARY KEY` constraints.
+//!
+//! Anything that cannot be proven is reported as unknown.
+
+use std::collections::HashSet;
+
+use crate::{ExaConnection, SqlxResult};
+
+/// Words that end the `FROM` clause of a `SELECT` statement.
+const CLAUSE_END: &[&str] = &[
+    "WHERE",
+    "CONNECT",
+    "START",
+    "PREFERRING",
+    "GROUP",
+    "HAVING",
+    "QUALIFY",
+    "ORDER",
+    "LIMIT",
+];
+
+/// Words that make the parsed statement too complex to reason about.
+const UNSUPPORTED: &[&str] = &[
+    "UNION",
+    "INTERSECT",
+    "MINUS",
+    "EXCEPT",
+    "ROLLUP",
+    "CUBE",
+    "GROUPING",
+    "WITH",
+];
+
+/// Expressions that are never `NULL`.
+const NON_NULL_WORDS: &[&str] = &[
+    "TRUE",
+    "FALSE",
+    "CURRENT_DATE",
+    "CURRENT_TIMESTAMP",
+    "SYSDATE",
+    "SYSTIMESTAMP",
+    "CURRENT_USER",
+    "CURRENT_SESSION",
+];
+
+/// Words that start the next table reference in the `FROM` clause.
+const TABLE_SEPARATORS: &[&str] = &["JOIN", "INNER", "LEFT", "RIGHT", "FULL", "CROSS", "NATURAL"];
+
+/// Words that can follow a table reference and therefore are not table aliases.
+const JOIN_WORDS: &[&str] = &[
+    "JOIN", "INNER", "LEFT", "RIGHT", "FULL", "OUTER", "CROSS", "NATURAL", "ON", "USING",
+];
+
+/// Returns the nullability of the result columns of a statement, [`None`] meaning unknown.
+pub(crate) async fn infer(
+    conn: &mut ExaConnection,
+    sql: &str,
+    num_columns: usize,
+) -> SqlxResult<Vec<Option<bool>>> {
+    let unknown = vec![None; num_columns];
+
+    let Some(select) = tokenize(sql).as_deref().and_then(parse_select) else {
+        return Ok(unknown);
+    };
+
+    let mut tables = Vec::with_capacity(select.tables.len());
+
+    for table_ref in select.tables {
+        let schema = match &table_ref.schema {
+            Some(schema) => schema.clone(),
+            None => match conn.attributes().current_schema() {
+                Some(schema) => schema.to_owned(),
+                None => return Ok(unknown),
+            },
+        };
+
+        let columns = table_columns(conn, &schema, &table_ref.name).await?;
+
+        // Unknown objects, such as synonyms or objects in other schemas than expected, cannot be
+        // reasoned about.
+        if columns.is_empty() {
+            return Ok(unknown);
+        }
+
+        tables.push((table_ref, columns));
+    }
+
+    let mut nullable = Vec::with_capacity(num_columns);
+
+    for item in select.items {
+        match item {
+            Item::Star { qualifier } => {
+                for (table_ref, columns) in &tables {
+                    if qualifier.as_ref().is_none_or(|q| table_ref.is_named(q)) {
+                        let outer = table_ref.outer;
+                        nullable
+                            .extend(columns.iter().map(|(_, non_null)| Some(outer || !non_null)));
+                    }
+                }
+            }
+            Item::Column { qualifier, name } => {
+                let mut matches = tables
+                    .iter()
+                    .filter(|(t, _)| qualifier.as_ref().is_none_or(|q| t.is_named(q)))
+                    .flat_map(|(t, columns)| {
+                        columns
+                            .iter()
+                            .filter(|(c, _)| *c == name)
+                            .map(move |(_, non_null)| t.outer || !non_null)
+                    });
+
+                // Ambiguous or unknown columns are left for the database to complain about.
+                match (matches.next(), matches.next()) {
+                    (Some(n), None) => nullable.push(Some(n)),
+                    _ => nullable.push(None),
+                }
+            }
+            Item::NonNull => nullable.push(Some(false)),
+            Item::Null => nullable.push(Some(true)),
+            Item::Unknown => nullable.push(None),
+        }
+    }
+
+    // The parsing went wrong somewhere if the column count differs.
+    if nullable.len() == num_columns {
+        Ok(nullable)
+    } else {
+        Ok(unknown)
+    }
+}
+
+/// Returns the columns of a table, in order, along with whether they are guaranteed non-null by
+/// an enabled constraint.
+///
+/// The lookups are not persisted, as they run once per table and would otherwise crowd user
+/// statements out of the statement cache.
+async fn table_columns(
+    conn: &mut ExaConnection,
+    schema: &str,
+    table: &str,
+) -> SqlxResult<Vec<(String, bool)>> {
+    let columns: Vec<String> = sqlx_core::query_scalar::query_scalar(
+        "
+        SELECT column_name
+        FROM exa_all_columns
+        WHERE column_schema = ? AND column_table = ?
+        ORDER BY column_ordinal_position;",
+    )
+    .bind(schema)
+    .bind(table)
+    .persistent(false)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let non_null: HashSet<String> = sqlx_core::query_scalar::query_scalar(
+        "
+        SELECT cc.column_name
+        FROM exa_all_constraint_columns cc
+        JOIN exa_all_constraints c
+            ON c.constraint_schema = cc.constraint_schema
+            AND c.constraint_table = cc.constraint_table
+            AND c.constraint_name = cc.constraint_name
+        WHERE cc.constraint_schema = ?
+            AND cc.constraint_table = ?
+            AND cc.constraint_type IN ('NOT NULL', 'PRIMARY KEY')
+            AND c.constraint_enabled;",
+    )
+    .bind(schema)
+    .bind(table)
+    .persistent(false)
+    .fetch_all(&mut *conn)
+    .await?
+    .into_iter()
+    .collect();
+
+    Ok(columns
+        .into_iter()
+        .map(|c| {
+            let non_null = non_null.contains(&c);
+            (c, non_null)
+        })
+        .collect())
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    /// An unquoted identifier or keyword, uppercased like Exasol does.
+    Word(String),
+    /// A quoted identifier.
+    Quoted(String),
+    /// A string literal. Exasol treats empty strings as `NULL`.
+    String {
+        empty: bool,
+    },
+    Number,
+    Symbol(char),
+}
+
+impl Token {
+    fn is_word(&self, word: &str) -> bool {
+        matches!(self, Self::Word(w) if w == word)
+    }
+
+    fn identifier(&self) -> Option<&str> {
+        match self {
+            Self::Word(w) | Self::Quoted(w) => Some(w),
+            _ => None,
+        }
+    }
+}
+
+/// A result column of a `SELECT` statement.
+#[derive(Debug, PartialEq)]
+enum Item {
+    Star {
+        qualifier: Option<String>,
+    },
+    Column {
+        qualifier: Option<String>,
+        name: String,
+    },
+    NonNull,
+    Null,
+    Unknown,
+}
+
+/// A table in the `FROM` clause of a `SELECT` statement.
+#[derive(Debug, PartialEq)]
+struct TableRef {
+    schema: Option<String>,
+    name: String,
+    alias: Option<String>,
+    /// Whether the table is on the optional side of an outer join.
+    outer: bool,
+}
+
+impl TableRef {
+    fn is_named(&self, qualifier: &str) -> bool {
+        self.alias.as_deref().unwrap_or(&self.name) == qualifier
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Join {
+    Inner,
+    Left,
+    Right,
+    Full,
+}
+
+#[derive(Debug)]
+struct Select {
+    items: Vec<Item>,
+    tables: Vec<TableRef>,
+}
+
+fn tokenize(sql: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '-' if chars.peek() == Some(&'-') => {
+                chars.by_ref().find(|c| *c == '\n');
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = ' ';
+                chars.by_ref().find(|c| {
+                    let end = prev == '*' && *c == '/';
+                    prev = *c;
+                    end
+                })?;
+            }
+            '\'' | '"' => {
+                let mut value = String::new();
+
+                loop {
+                    match chars.next()? {
+                        // Quotes are escaped by doubling them.
+                        q if q == c && chars.peek() == Some(&c) => {
+                            chars.next();
+                            value.push(c);
+                        }
+                        q if q == c => break,
+                        ch => value.push(ch),
+                    }
+                }
+
+                if c == '"' {
+                    tokens.push(Token::Quoted(value));
+                } else {
+                    tokens.push(Token::String {
+                        empty: value.is_empty(),
+                    });
+                }
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut word = c.to_uppercase().collect::<String>();
+                while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || "_#$".contains(*c)) {
+                    word.extend(c.to_uppercase());
+                }
+                tokens.push(Token::Word(word));
+            }
+            c if c.is_ascii_digit() => {
+                while chars
+                    .next_if(|c| c.is_ascii_alphanumeric() || *c == '.')
+                    .is_some()
+                {}
+                tokens.push(Token::Number);
+            }
+            c => tokens.push(Token::Symbol(c)),
+        }
+    }
+
+    Some(tokens)
+}
+
+/// Splits tokens on a symbol outside parentheses.
+fn split_top_level(tokens: &[Token], separator: char) -> Vec<&[Token]> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => depth -= 1,
+            Token::Symbol(c) if *c == separator && depth == 0 => {
+                parts.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&tokens[start..]);
+    parts
+}
+
+/// Returns the position of the parenthesis closing the one the tokens start with.
+fn closing_paren(tokens: &[Token]) -> Option<usize> {
+    let mut depth = 0i32;
+
+    tokens.iter().position(|token| {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => depth -= 1,
+            _ => {}
+        }
+        depth == 0
+    })
+}
+
+/// Returns the position of the first top level token matching the predicate.
+fn position_top_level(
+    tokens: &[Token],
+    mut predicate: impl FnMut(&Token) -> bool,
+) -> Option<usize> {
+    let mut depth = 0i32;
+
+    tokens.iter().position(|token| {
+        match token {
+            Token::Symbol('(') => depth += 1,
+            Token::Symbol(')') => depth -= 1,
+            _ => return depth == 0 && predicate(token),
+        }
+        false
+    })
+}
+
+fn parse_select(tokens: &[Token]) -> Option<Select> {
+    let tokens = match tokens {
+        [rest @ .., Token::Symbol(';')] => rest,
+        tokens => tokens,
+    };
+
+    let [first, rest @ ..] = tokens else {
+        return None;
+    };
+
+    // The `(+)` operator makes the tables it is applied to outer joined.
+    if !first.is_word("SELECT")
+        || tokens
+            .iter()
+            .any(|t| UNSUPPORTED.iter().any(|w| t.is_word(w)))
+        || tokens
+            .windows(3)
+            .any(|w| w == [Token::Symbol('('), Token::Symbol('+'), Token::Symbol(')')])
+    {
+        return None;
+    }
+
+    let rest = match rest {
+        [t, rest @ ..] if t.is_word("DISTINCT") || t.is_word("ALL") => rest,
+        rest => rest,
+    };
+
+    let (select_list, from) = match position_top_level(rest, |t| t.is_word("FROM")) {
+        Some(pos) => {
+            let from = &rest[pos + 1..];
+            let end = position_top_level(from, |t| CLAUSE_END.iter().any(|w| t.is_word(w)))
+                .unwrap_or(from.len());
+            (&rest[..pos], Some(&from[..end]))
+        }
+        None => (rest, None),
+    };
+
+    let items = split_top_level(select_list, ',')
+        .into_iter()
+        .map(parse_item)
+        .collect();
+
+    let tables = match from {
+        Some(from) => parse_from(from)?,
+        None => Vec::new(),
+    };
+
+    Some(Select { items, tables })
+}
+
+fn parse_item(tokens: &[Token]) -> Item {
+    // Strip the alias, if any.
+    let tokens = match tokens {
+        [rest @ .., as_, alias] if as_.is_word("AS") && alias.identifier().is_some() => rest,
+        [rest @ .., prev, alias] if alias.identifier().is_some() && *prev != Token::Symbol('.') => {
+            &tokens[..=rest.len()]
+        }
+        tokens => tokens,
+    };
+
+    match tokens {
+        [Token::Symbol('*')] => Item::Star { qualifier: None },
+        [table, Token::Symbol('.'), Token::Symbol('*')] => Item::Star {
+            qualifier: table.identifier().map(ToOwned::to_owned),
+        },
+        [Token::Word(w)] if w == "NULL" => Item::Null,
+        [Token::Word(w)] if NON_NULL_WORDS.contains(&w.as_str()) => Item::NonNull,
+        [column] => match column.identifier() {
+            Some(name) => Item::Column {
+                qualifier: None,
+                name: name.to_owned(),
+            },
+            None => match column {
+                Token::Number | Token::String { empty: false } => Item::NonNull,
+                Token::String { empty: true } => Item::Null,
+                _ => Item::Unknown,
+            },
+        },
+        [.., table, Token::Symbol('.'), column] => {
+            match (table.identifier(), column.identifier()) {
+                (Some(table), Some(column))
+                    if tokens.len() == 3
+                        || (tokens.len() == 5 && tokens[1] == Token::Symbol('.')) =>
+                {
+                    Item::Column {
+                        qualifier: Some(table.to_owned()),
+                        name: column.to_owned(),
+                    }
+                }
+                _ => Item::Unknown,
+            }
+        }
+        // Only when the whole item is the `COUNT` call, not an expression starting with one.
+        [count, Token::Symbol('('), ..]
+            if count.is_word("COUNT") && closing_paren(&tokens[1..]) == Some(tokens.len() - 2) =>
+        {
+            Item::NonNull
+        }
+        _ => Item::Unknown,
+    }
+}
+
+fn parse_from(tokens: &[Token]) -> Option<Vec<TableRef>> {
+    let mut tables = Vec::<TableRef>::new();
+    let mut pos = 0;
+    let mut join = Join::Inner;
+
+    loop {
+        let (schema, name, next) = match tokens.get(pos..)? {
+            [schema, Token::Symbol('.'), table, ..] => (
+                Some(schema.identifier()?.to_owned()),
+                table.identifier()?.to_owned(),
+                pos + 3,
+            ),
+            [table, ..] => (None, table.identifier()?.to_owned(), pos + 1),
+            [] => return None,
+        };
+
+        // Table functions and subqueries.
+        if tokens.get(next) == Some(&Token::Symbol('(')) {
+            return None;
+        }
+
+        let (alias, next) = match tokens.get(next..).unwrap_or_default() {
+            [as_, alias, ..] if as_.is_word("AS") => {
+                (Some(alias.identifier()?.to_owned()), next + 2)
+            }
+            [Token::Word(w), ..] if JOIN_WORDS.contains(&w.as_str()) => (None, next),
+            [alias, ..] => (alias.identifier().map(ToOwned::to_owned), next + 1),
+            [] => (None, next),
+        };
+
+        let mut table = TableRef {
+            schema,
+            name,
+            alias,
+            outer: false,
+        };
+
+        if matches!(join, Join::Left | Join::Full) {
+            table.outer = true;
+        }
+
+        if matches!(join, Join::Right | Join::Full) {
+            for t in &mut tables {
+                t.outer = true;
+            }
+        }
+
+        tables.push(table);
+
+        // Skip the join condition, up to the next table reference.
+        let rest = tokens.get(next..).unwrap_or_default();
+        let Some(offset) = position_top_level(rest, |t| {
+            *t == Token::Symbol(',') || TABLE_SEPARATORS.iter().any(|w| t.is_word(w))
+        }) else {
+            break;
+        };
+
+        // `LEFT` and `RIGHT` are functions as well.
+        if rest.get(offset + 1) == Some(&Token::Symbol('(')) {
+            return None;
+        }
+
+        pos = next + offset;
+        join = Join::Inner;
+
+        if tokens[pos] == Token::Symbol(',') {
+            pos += 1;
+            continue;
+        }
+
+        while let Some(Token::Word(w)) = tokens.get(pos) {
+            pos += 1;
+
+            match w.as_str() {
+                "JOIN" => break,
+                "LEFT" => join = Join::Left,
+                "RIGHT" => join = Join::Right,
+                "FULL" => join = Join::Full,
+                "NATURAL" => return None,
+                _ => {}
+            }
+        }
+    }
+
+    Some(tables)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn select(sql: &str) -> Option<Select> {
+        parse_select(&tokenize(sql)?)
+    }
+
+    fn column(qualifier: Option<&str>, name: &str) -> Item {
+        Item::Column {
+            qualifier: qualifier.map(ToOwned::to_owned),
+            name: name.to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_parse_items() {
+        let parsed = select(
+            r#"SELECT DISTINCT id, u."Name" AS name, s.users.email e, 1, 'a', '', NULL,
+                COUNT(*) cnt, MAX(id), u.*, * -- comment
+            FROM users u;"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            parsed.items,
+            [
+                column(None, "ID"),
+                column(Some("U"), "Name"),
+                column(Some("USERS"), "EMAIL"),
+                Item::NonNull,
+                Item::NonNull,
+                Item::Null,
+                Item::Null,
+                Item::NonNull,
+                Item::Unknown,
+                Item::Star {
+                    qualifier: Some("U".to_owned())
+                },
+                Item::Star { qualifier: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_joins() {
+        let parsed = select(
+            "SELECT * FROM s.users u
+            JOIN posts p ON p.author = u.id AND LENGTH(p.title) > 3
+            LEFT OUTER JOIN comments c ON c.post = p.id, tags
+            WHERE u.id = ?",
+        )
+        .unwrap();
+
+        let names = parsed
+            .tables
+            .iter()
+            .map(|t| {
+                (
+                    t.schema.as_deref(),
+                    t.name.as_str(),
+                    t.alias.as_deref(),
+                    t.outer,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            names,
+            [
+                (Some("S"), "USERS", Some("U"), false),
+                (None, "POSTS", Some("P"), false),
+                (None, "COMMENTS", Some("C"), true),
+                (None, "TAGS", None, false),
+            ]
+        );
+
+        let right = select("SELECT * FROM a RIGHT JOIN b ON a.id = b.id").unwrap();
+        assert!(right.tables[0].outer);
+        assert!(!right.tables[1].outer);
+    }
+
+    #[test]
+    fn test_parse_unsupported() {
+        assert!(select("INSERT INTO t VALUES (1)").is_none());
+        assert!(select("SELECT a FROM t UNION ALL SELECT a FROM u").is_none());
+        assert!(select("SELECT a FROM (SELECT 1 a)").is_none());
+        assert!(select("WITH c AS (SELECT 1 a) SELECT a FROM c").is_none());
+        assert!(select("SELECT a FROM t NATURAL JOIN u").is_none());
+        assert!(select("SELECT b.a FROM t a, u b WHERE a.id = b.id(+)").is_none());
+    }
+
+    #[test]
+    fn test_parse_count_expressions() {
+        let parsed =
+            select("SELECT COUNT(DISTINCT (a)), COUNT(*) / NULLIF(x, 0), COUNT(a) + MAX(b) FROM t")
+                .unwrap();
+
+        assert_eq!(parsed.items, [Item::NonNull, Item::Unknown, Item::Unknown]);
+    }
+}
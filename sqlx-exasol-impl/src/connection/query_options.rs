@@ -0,0 +1,122 @@
+use futures_core::{future::BoxFuture, stream::BoxStream};
+use sqlx_core::{
+    describe::Describe,
+    executor::{Execute, Executor},
+    sql_str::SqlStr,
+    Either,
+};
+
+use crate::{
+    database::Exasol, ExaConnection, ExaQueryResult, ExaRow, ExaStatement, ExaTypeInfo, SqlxResult,
+};
+
+/// Execution options applied to a single query through [`ExaConnection::with_options`].
+///
+/// Unset options leave the connection's respective [`crate::ExaAttributes`] untouched. The set
+/// ones are sent along with the query execution request and the previous values are restored once
+/// the query's results are consumed or dropped, so they never leak into subsequent queries or
+/// other users of a pooled connection.
+///
+/// ```rust,no_run
+/// # use sqlx_exasol_impl as sqlx_exasol;
+/// # async fn example(conn: &mut sqlx_exasol::ExaConnection) -> sqlx_core::Result<()> {
+/// use sqlx_exasol::ExaQueryOptions;
+///
+/// let options = ExaQueryOptions {
+///     timeout: Some(30),
+///     max_rows: Some(100),
+///     ..Default::default()
+/// };
+///
+/// let rows = sqlx_core::query::query("SELECT * FROM users")
+///     .fetch_all(conn.with_options(options))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExaQueryOptions {
+    /// The query timeout, in seconds. `0` means no timeout.
+    pub timeout: Option<u64>,
+    /// The size, in bytes, of the data chunks fetched when retrieving the result set.
+    pub fetch_size: Option<usize>,
+    /// The maximum number of rows the database returns in the result set. `0` means no limit.
+    pub max_rows: Option<u64>,
+}
+
+/// An [`Executor`] that runs a single query with [`ExaQueryOptions`] applied.
+///
+/// Created through [`ExaConnection::with_options`].
+#[derive(Debug)]
+pub struct ExaOptionsExecutor<'c> {
+    conn: &'c mut ExaConnection,
+    options: ExaQueryOptions,
+}
+
+impl<'c> ExaOptionsExecutor<'c> {
+    pub(crate) fn new(conn: &'c mut ExaConnection, options: ExaQueryOptions) -> Self {
+        Self { conn, options }
+    }
+}
+
+/// Delegates to the [`ExaConnection`] executor, with the options applied to each query.
+impl<'c> Executor<'c> for ExaOptionsExecutor<'c> {
+    type Database = Exasol;
+
+    fn execute<'e, 'q, E>(self, query: E) -> BoxFuture<'e, SqlxResult<ExaQueryResult>>
+    where
+        'q: 'e,
+        'c: 'e,
+        E: 'q + Execute<'q, Exasol>,
+    {
+        self.conn.execute_with(query, Some(self.options))
+    }
+
+    fn fetch<'e, 'q, E>(self, query: E) -> BoxStream<'e, SqlxResult<ExaRow>>
+    where
+        'q: 'e,
+        'c: 'e,
+        E: 'q + Execute<'q, Exasol>,
+    {
+        self.conn.fetch_with(query, Some(self.options))
+    }
+
+    fn fetch_many<'e, 'q, E>(
+        self,
+        query: E,
+    ) -> BoxStream<'e, SqlxResult<Either<ExaQueryResult, ExaRow>>>
+    where
+        'q: 'e,
+        'c: 'e,
+        E: 'q + Execute<'q, Exasol>,
+    {
+        self.conn.fetch_many_with(query, Some(self.options))
+    }
+
+    fn fetch_optional<'e, 'q, E>(self, query: E) -> BoxFuture<'e, SqlxResult<Option<ExaRow>>>
+    where
+        'q: 'e,
+        'c: 'e,
+        E: 'q + Execute<'q, Exasol>,
+    {
+        self.conn.fetch_optional_with(query, Some(self.options))
+    }
+
+    fn prepare_with<'e>(
+        self,
+        sql: SqlStr,
+        parameters: &'e [ExaTypeInfo],
+    ) -> BoxFuture<'e, SqlxResult<ExaStatement>>
+    where
+        'c: 'e,
+    {
+        self.conn.prepare_with(sql, parameters)
+    }
+
+    fn describe<'e>(self, sql: SqlStr) -> BoxFuture<'e, SqlxResult<Describe<Exasol>>>
+    where
+        'c: 'e,
+    {
+        self.conn.describe(sql)
+    }
+}
@@ -18,16 +18,21 @@ use sqlx_core::{ext::ustr::UStr, logger::QueryLogger, Either, HashMap};
 
 use crate::{
     column::ExaColumn,
-    connection::websocket::{
-        future::{
-            CloseResultSets, Execute, ExecuteBatch, ExecutePrepared, FetchChunk, WebSocketFuture,
+    connection::{
+        websocket::{
+            future::{
+                CloseResultSets, Execute, ExecuteBatch, ExecutePrepared, FetchChunk,
+                WebSocketFuture,
+            },
+            ExaWebSocket,
         },
-        ExaWebSocket,
+        ExaQueryOptions,
     },
     error::ExaProtocolError,
     query_result::ExaQueryResult,
     responses::{DataChunk, MultiResults, QueryResult, ResultSet, ResultSetOutput, SingleResult},
     row::ExaRow,
+    types::time_zone::SessionTimeZone,
     SqlxError, SqlxResult,
 };
 
@@ -41,6 +46,8 @@ pub struct ResultStream<'ws> {
     result_set_handles: Vec<u16>,
     state: ResultStreamState,
     had_err: bool,
+    restore_options: Option<ExaQueryOptions>,
+    time_zone: Option<Arc<SessionTimeZone>>,
 }
 
 impl<'ws> ResultStream<'ws> {
@@ -54,9 +61,18 @@ impl<'ws> ResultStream<'ws> {
             result_set_handles: Vec::new(),
             state: future.into(),
             had_err: false,
+            restore_options: None,
+            time_zone: None,
         }
     }
 
+    /// Registers the [`ExaQueryOptions`] to restore the connection attributes to once the stream
+    /// is dropped.
+    pub fn restore_options(mut self, options: Option<ExaQueryOptions>) -> Self {
+        self.restore_options = options;
+        self
+    }
+
     /// Inner polling function that handles the actual logic.
     fn poll(&mut self, cx: &mut Context<'_>) -> Poll<Option<<Self as Stream>::Item>> {
         loop {
@@ -78,14 +94,24 @@ impl<'ws> ResultStream<'ws> {
                     self.state = ResultStreamState::Stream(multi_stream);
                 }
                 ResultStreamState::Stream(stream) => {
-                    let Some(either) = ready!(stream.poll_next_unpin(cx, self.ws)).transpose()?
+                    let Some(mut either) =
+                        ready!(stream.poll_next_unpin(cx, self.ws)).transpose()?
                     else {
                         return Poll::Ready(None);
                     };
 
-                    match &either {
+                    match &mut either {
                         Either::Left(q) => self.logger.increase_rows_affected(q.rows_affected()),
-                        Either::Right(_) => self.logger.increment_rows_returned(),
+                        Either::Right(row) => {
+                            self.logger.increment_rows_returned();
+
+                            // Timestamps with local time zone are rendered in the session time
+                            // zone, which decoding them requires.
+                            let time_zone = self.time_zone.get_or_insert_with(|| {
+                                Arc::new(SessionTimeZone::new(&self.ws.attributes))
+                            });
+                            row.time_zone = Some(time_zone.clone());
+                        }
                     }
 
                     return Poll::Ready(Some(Ok(either)));
@@ -127,6 +153,13 @@ impl Drop for ResultStream<'_> {
             // Register the result set handles to be closed in the next database interaction.
             self.ws.pending_close = Some(CloseResultSets::new(handles));
         }
+
+        // Restore the attributes changed for this query only. These get sent to the database
+        // along with the next request.
+        if let Some(options) = self.restore_options.take() {
+            // The options were already applied, so restoring them cannot fail.
+            let _ = self.ws.attributes.apply_query_options(options);
+        }
     }
 }
 
@@ -35,8 +35,9 @@ use crate::{
     error::ExaProtocolError,
     responses::{
         DataChunk, DescribeStatement, ExaResult, MultiResults, PreparedStatement, PublicKey,
-        SingleResult,
+        QueryResult, SingleResult,
     },
+    types::time_zone::SessionTimeZone,
     ExaArguments, SessionInfo, SqlxError, SqlxResult,
 };
 
@@ -87,6 +88,10 @@ pub struct ExecutePrepared {
 }
 
 impl ExecutePrepared {
+    /// Space reserved in a request for everything other than the parameter data, such as the
+    /// command, the parameter types and the connection attributes.
+    const MESSAGE_HEADROOM: usize = 64 * 1024;
+
     pub fn new(sql: SqlStr, persist: bool, arguments: ExaArguments) -> Self {
         let future = GetOrPrepare::new(sql, persist);
         let state = ExecutePreparedState::GetOrPrepare(future);
@@ -106,19 +111,75 @@ impl WebSocketFuture for ExecutePrepared {
             match &mut self.state {
                 ExecutePreparedState::GetOrPrepare(future) => {
                     let prepared = ready!(future.poll_unpin(cx, ws))?;
-                    let buf = std::mem::take(&mut self.arguments.buf);
-                    let command = ExecutePreparedStmt::new(
+                    let mut buf = std::mem::take(&mut self.arguments.buf);
+                    let utc_params = std::mem::take(&mut buf.utc_timestamp_params);
+                    let mut command = ExecutePreparedStmt::new(
                         prepared.statement_handle,
                         prepared.parameters.clone(),
                         buf,
                     );
 
-                    let future = ExaRoundtrip::new(command);
-                    self.state = ExecutePreparedState::ExecutePrepared(future);
+                    if !utc_params.is_empty() {
+                        let time_zone = SessionTimeZone::new(&ws.attributes);
+                        command = command
+                            .localize_timestamps(&utc_params, &time_zone)
+                            .map_err(SqlxError::Encode)?;
+                    }
+
+                    let max_len = ws
+                        .max_data_message_size
+                        .saturating_sub(Self::MESSAGE_HEADROOM);
+
+                    // Statements returning result sets or parameter data that fits in a single
+                    // message are executed as is. Otherwise, the parameter rows are split and the
+                    // prepared statement gets executed once for each chunk.
+                    if !prepared.columns.is_empty() || command.data_len() <= max_len {
+                        let future = ExaRoundtrip::new(command);
+                        self.state = ExecutePreparedState::ExecutePrepared(future);
+                        continue;
+                    }
+
+                    let mut chunks = command.split(max_len)?.into_iter();
+                    tracing::debug!(
+                        "splitting prepared statement parameters in {} chunks",
+                        chunks.len()
+                    );
+
+                    // There's always at least one chunk.
+                    let future = ExaRoundtrip::new(chunks.next().unwrap());
+                    self.state = ExecutePreparedState::ExecuteChunks {
+                        future,
+                        chunks,
+                        rows_affected: 0,
+                    };
                 }
                 ExecutePreparedState::ExecutePrepared(future) => {
                     return future.poll_unpin(cx, ws).map_ok(From::from);
                 }
+                ExecutePreparedState::ExecuteChunks {
+                    future,
+                    chunks,
+                    rows_affected,
+                } => {
+                    let QueryResult::RowCount { row_count } =
+                        ready!(future.poll_unpin(cx, ws))?.into()
+                    else {
+                        return Err(ExaProtocolError::UnexpectedResultSet)?;
+                    };
+
+                    *rows_affected += row_count;
+
+                    let Some(chunk) = chunks.next() else {
+                        let query_result = QueryResult::RowCount {
+                            row_count: *rows_affected,
+                        };
+
+                        let stream = MultiResultStream::new(query_result, Vec::new().into_iter());
+                        return Poll::Ready(Ok(stream));
+                    };
+
+                    *future = ExaRoundtrip::new(chunk);
+                }
             }
         }
     }
@@ -128,6 +189,13 @@ impl WebSocketFuture for ExecutePrepared {
 enum ExecutePreparedState {
     GetOrPrepare(GetOrPrepare),
     ExecutePrepared(ExaRoundtrip<ExecutePreparedStmt, SingleResult>),
+    /// Executes the prepared statement once for every parameter data chunk, summing up the
+    /// affected rows.
+    ExecuteChunks {
+        future: ExaRoundtrip<ExecutePreparedStmt, SingleResult>,
+        chunks: std::vec::IntoIter<ExecutePreparedStmt>,
+        rows_affected: u64,
+    },
 }
 
 /// Implementor of [`WebSocketFuture`] that executes a batch of SQL statements.
@@ -568,13 +636,13 @@ impl WebSocketFuture for ExaLogin<'_> {
                 LoginState::Token(future) => {
                     ready!(future.poll_unpin(cx, ws))?;
                     let command = self.opts.take().unwrap();
-                    self.state = LoginState::Complete(ExaRoundtrip::new(command));
+                    self.state = LoginState::Complete(Box::new(ExaRoundtrip::new(command)));
                 }
                 LoginState::Credentials(future) => {
                     let public_key = ready!(future.poll_unpin(cx, ws))?.into();
                     let mut command = self.opts.take().unwrap();
                     command.encrypt_password(&public_key)?;
-                    self.state = LoginState::Complete(ExaRoundtrip::new(command));
+                    self.state = LoginState::Complete(Box::new(ExaRoundtrip::new(command)));
                 }
                 LoginState::Complete(future) => return future.poll_unpin(cx, ws),
             }
@@ -586,7 +654,7 @@ impl WebSocketFuture for ExaLogin<'_> {
 enum LoginState<'a> {
     Token(ExaRoundtrip<LoginToken, Option<IgnoredAny>>),
     Credentials(ExaRoundtrip<LoginCreds, PublicKey>),
-    Complete(ExaRoundtrip<ExaLoginRequest<'a>, SessionInfo>),
+    Complete(Box<ExaRoundtrip<ExaLoginRequest<'a>, SessionInfo>>),
 }
 
 /// Low-level implementor of [`WebSocketFuture`] that sends a request and awaits its response.
@@ -597,6 +665,7 @@ enum LoginState<'a> {
 /// All I/O interactions with the database should be built on top of this type.
 #[derive(Debug)]
 pub enum ExaRoundtrip<REQ, OUT> {
+    Starting(REQ),
     Waiting(REQ),
     Flushing,
     Receiving(PhantomData<fn() -> OUT>),
@@ -605,14 +674,14 @@ pub enum ExaRoundtrip<REQ, OUT> {
 
 impl<REQ, OUT> ExaRoundtrip<REQ, OUT> {
     pub fn new(request: REQ) -> Self {
-        Self::Waiting(request)
+        Self::Starting(request)
     }
 
     /// Returns whether the request was successfully sent to the database, even if the response was
     /// not yet received.
     fn has_sent(&self) -> bool {
         match self {
-            Self::Waiting(_) | Self::Flushing => false,
+            Self::Starting(_) | Self::Waiting(_) | Self::Flushing => false,
             Self::Receiving(_) | Self::Finished => true,
         }
     }
@@ -643,6 +712,15 @@ where
     ) -> Poll<SqlxResult<Self::Output>> {
         loop {
             match self {
+                Self::Starting(_) => {
+                    // A deadline armed by a previously cancelled request must not carry over to
+                    // this one, as the connection might have been idle in the meantime.
+                    ws.inner.reset_io_deadline();
+
+                    if let Self::Starting(request) = std::mem::replace(self, Self::Finished) {
+                        *self = Self::Waiting(request);
+                    }
+                }
                 Self::Waiting(request) => {
                     // Check if we need to rollback a transaction.
                     // We need to take the future out of the websocket for ownership reasons.
@@ -46,6 +46,9 @@ pub struct ExaWebSocket {
     /// received. This is used for connection consistency, so an upcoming response can be ignored
     /// if needed, such as when a future gets dropped/cancelled before completion.
     pub active_request: bool,
+    /// The maximum size of a message accepted by the database, as advertised in the
+    /// [`SessionInfo`] during login. Used for splitting oversized prepared statement parameters.
+    pub max_data_message_size: usize,
 }
 
 impl ExaWebSocket {
@@ -91,10 +94,15 @@ impl ExaWebSocket {
             pending_rollback: None,
             statement_cache,
             active_request: false,
+            max_data_message_size: usize::MAX,
         };
 
         // Login is always uncompressed!
         let session_info = ExaLogin::new(options).future(&mut this).await?;
+        this.attributes
+            .set_protocol_version(session_info.protocol_version());
+        this.max_data_message_size =
+            usize::try_from(session_info.max_data_message_size()).unwrap_or(usize::MAX);
 
         // Use compression if indicated to do so and it's enabled through the feature flagged.
         this.inner = this.inner.maybe_compress(use_compression);
@@ -1,17 +1,22 @@
 //! Module containing the various requests that can be sent to the Exasol server through its
 //! WebSocket API.
 
-use std::{borrow::Cow, sync::Arc};
+use std::{borrow::Cow, ops::Range, sync::Arc};
 
 use base64::{engine::general_purpose::STANDARD as STD_BASE64_ENGINE, Engine};
 use rsa::{Pkcs1v15Encrypt, RsaPublicKey};
 use serde::{Serialize, Serializer};
 use serde_json::value::RawValue;
-use sqlx_core::sql_str::SqlStr;
+use sqlx_core::{error::BoxDynError, sql_str::SqlStr};
 
 use crate::{
-    arguments::ExaBuffer, options::ProtocolVersion, responses::ExaRwAttributes, ExaAttributes,
-    ExaTypeInfo, SqlxError, SqlxResult,
+    arguments::{ExaBuffer, UtcTimestampParam},
+    error::ExaProtocolError,
+    options::ProtocolVersion,
+    responses::ExaRwAttributes,
+    type_info::ExaDataType,
+    types::time_zone::{LocalTimeZone, ParamTimeZone, SessionTimeZone},
+    ExaAttributes, ExaTypeInfo, SqlxError, SqlxResult,
 };
 
 /// Serialization wrapper type that adds the read-write attributes to the database request if needed
@@ -371,6 +376,64 @@ impl ExecutePreparedStmt {
             data: data.into(),
         }
     }
+
+    /// Converts the UTC timestamps bound to `TIMESTAMP WITH LOCAL TIME ZONE` parameters to the
+    /// session time zone, which is what Exasol interprets them in, and the ones bound to other
+    /// parameters to the time zone they are meant to be sent in.
+    pub fn localize_timestamps(
+        mut self,
+        utc_params: &[UtcTimestampParam],
+        time_zone: &SessionTimeZone,
+    ) -> Result<Self, BoxDynError> {
+        let params = utc_params
+            .iter()
+            .filter_map(|param| {
+                let column = self.columns.get(param.index)?;
+
+                let local_time_zone = match (column.data_type, param.time_zone) {
+                    (ExaDataType::TimestampWithLocalTimeZone { .. }, _) => {
+                        (!time_zone.is_utc()).then_some(LocalTimeZone::Session(time_zone))
+                    }
+                    (_, ParamTimeZone::Utc) => None,
+                    (_, ParamTimeZone::Client) => Some(LocalTimeZone::Client),
+                };
+
+                local_time_zone.map(|tz| (param.range.clone(), tz))
+            })
+            .collect::<Vec<_>>();
+
+        if !params.is_empty() {
+            self.data = self.data.localize_timestamps(&params)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the length of the serialized parameter data.
+    pub fn data_len(&self) -> usize {
+        self.data.buffer.len()
+    }
+
+    /// Splits this request into multiple requests executing the same prepared statement, each
+    /// carrying a subset of the parameter rows whose serialized length does not exceed `max_len`.
+    ///
+    /// A single row exceeding `max_len` on its own still ends up in a request by itself.
+    pub fn split(self, max_len: usize) -> Result<Vec<Self>, ExaProtocolError> {
+        let chunks = self
+            .data
+            .split(max_len)?
+            .into_iter()
+            .map(|data| Self {
+                statement_handle: self.statement_handle,
+                num_columns: self.num_columns,
+                num_rows: data.num_rows,
+                columns: self.columns.clone(),
+                data,
+            })
+            .collect();
+
+        Ok(chunks)
+    }
 }
 
 impl Serialize for WithAttributes<'_, ExecutePreparedStmt> {
@@ -581,6 +644,102 @@ impl PreparedStmtData {
     fn is_empty(&self) -> bool {
         self.num_rows == 0
     }
+
+    /// Splits the columnar parameter data into row chunks whose serialized length does not exceed
+    /// `max_len`, unless a single row is bigger than that.
+    fn split(&self, max_len: usize) -> Result<Vec<Self>, ExaProtocolError> {
+        let columns: Vec<Vec<&RawValue>> = serde_json::from_str(&self.buffer)?;
+
+        if let Some(column) = columns.iter().find(|c| c.len() != self.num_rows) {
+            let err = ExaProtocolError::ParameterLengthMismatch(self.num_rows, column.len());
+            return Err(err);
+        }
+
+        // The brackets of the data array and of each column, plus the separators between columns,
+        // minus the separators after each column's last value which are accounted for below.
+        let overhead = 1 + 2 * columns.len();
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        let mut chunk_len = overhead;
+
+        for row in 0..self.num_rows {
+            // The length of the row values plus their separators.
+            let row_len = columns
+                .iter()
+                .map(|c| c[row].get().len() + 1)
+                .sum::<usize>();
+
+            if row > start && chunk_len + row_len > max_len {
+                chunks.push(Self::from_rows(&columns, start..row));
+                start = row;
+                chunk_len = overhead;
+            }
+
+            chunk_len += row_len;
+        }
+
+        chunks.push(Self::from_rows(&columns, start..self.num_rows));
+        Ok(chunks)
+    }
+
+    /// Converts the UTC timestamps of the parameter columns at the given positions in the buffer
+    /// to the given time zones. The other parameter columns are copied as they are.
+    fn localize_timestamps(
+        &self,
+        params: &[(Range<usize>, LocalTimeZone<'_>)],
+    ) -> Result<Self, BoxDynError> {
+        let mut buffer = String::with_capacity(self.buffer.len());
+        let mut pos = 0;
+
+        for (range, time_zone) in params {
+            let column: Vec<Option<&str>> = serde_json::from_str(&self.buffer[range.clone()])?;
+            let column = column
+                .into_iter()
+                .map(|value| value.map(|ts| time_zone.utc_to_local(ts)).transpose())
+                .collect::<Result<Vec<_>, _>>()?;
+
+            buffer.push_str(&self.buffer[pos..range.start]);
+            buffer.push_str(&serde_json::to_string(&column)?);
+            pos = range.end;
+        }
+
+        buffer.push_str(&self.buffer[pos..]);
+
+        Ok(Self {
+            buffer,
+            num_rows: self.num_rows,
+        })
+    }
+
+    /// Serializes the given range of rows from the columnar parameter data.
+    fn from_rows(columns: &[Vec<&RawValue>], rows: Range<usize>) -> Self {
+        let mut buffer = String::from('[');
+
+        for (idx, column) in columns.iter().enumerate() {
+            if idx > 0 {
+                buffer.push(',');
+            }
+
+            buffer.push('[');
+
+            for (idx, value) in column[rows.clone()].iter().enumerate() {
+                if idx > 0 {
+                    buffer.push(',');
+                }
+
+                buffer.push_str(value.get());
+            }
+
+            buffer.push(']');
+        }
+
+        buffer.push(']');
+
+        Self {
+            buffer,
+            num_rows: rows.len(),
+        }
+    }
 }
 
 impl Serialize for PreparedStmtData {
@@ -608,9 +767,10 @@ impl From<ExaBuffer> for PreparedStmtData {
 
 #[cfg(test)]
 mod tests {
-    use sqlx_core::sql_str::SqlStr;
+    use sqlx_core::{arguments::Arguments, sql_str::SqlStr};
 
-    use super::ExecuteBatch;
+    use super::{ExecuteBatch, PreparedStmtData};
+    use crate::ExaArguments;
 
     #[test]
     fn test_simple_statements() {
@@ -784,4 +944,85 @@ mod tests {
             ]
         );
     }
+
+    fn prepared_stmt_data() -> PreparedStmtData {
+        let mut args = ExaArguments::default();
+        args.add(vec![1, 22, 333, 4444]).unwrap();
+        args.add(vec!["a", "bb", "ccc", "dddd"]).unwrap();
+        args.buf.into()
+    }
+
+    #[test]
+    fn test_split_params_fits() {
+        let data = prepared_stmt_data();
+        let chunks = data.split(data.buffer.len()).unwrap();
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].num_rows, 4);
+        assert_eq!(chunks[0].buffer, data.buffer);
+    }
+
+    #[test]
+    fn test_split_params_chunks() {
+        let data = prepared_stmt_data();
+        let chunks = data.split(20).unwrap();
+        let buffers = chunks.iter().map(|c| c.buffer.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(
+            buffers,
+            vec![
+                r#"[[1,22],["a","bb"]]"#,
+                r#"[[333],["ccc"]]"#,
+                r#"[[4444],["dddd"]]"#
+            ]
+        );
+        assert_eq!(chunks.iter().map(|c| c.num_rows).sum::<usize>(), 4);
+    }
+
+    #[test]
+    fn test_split_params_oversized_rows() {
+        let data = prepared_stmt_data();
+        let chunks = data.split(1).unwrap();
+
+        assert_eq!(chunks.len(), 4);
+        assert!(chunks.iter().all(|c| c.num_rows == 1));
+        assert_eq!(chunks[3].buffer, r#"[[4444],["dddd"]]"#);
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_localize_timestamps() {
+        use chrono::{DateTime, Utc};
+
+        use super::ExecutePreparedStmt;
+        use crate::{type_info::ExaDataType, types::time_zone::SessionTimeZone};
+
+        let timestamp = "2023-01-12T19:22:36Z".parse::<DateTime<Utc>>().unwrap();
+        let timestamps = vec![Some(timestamp), None];
+
+        let mut args = ExaArguments::default();
+        args.add(vec![1, 22]).unwrap();
+        args.add(timestamps.clone()).unwrap();
+        args.add(timestamps).unwrap();
+
+        let columns = [
+            ExaDataType::Double,
+            ExaDataType::TimestampWithLocalTimeZone { precision: None },
+            ExaDataType::Timestamp { precision: None },
+        ]
+        .map(Into::into);
+
+        let utc_params = std::mem::take(&mut args.buf.utc_timestamp_params);
+        let time_zone =
+            SessionTimeZone::with_behavior("EUROPE/BERLIN", "INVALID SHIFT AMBIGUOUS ST");
+        let command = ExecutePreparedStmt::new(1, columns.into(), args.buf)
+            .localize_timestamps(&utc_params, &time_zone)
+            .unwrap();
+
+        // Only the timestamps bound to the `TIMESTAMP WITH LOCAL TIME ZONE` get converted.
+        assert_eq!(
+            command.data.buffer,
+            r#"[[1,22],["2023-01-12 20:22:36",null],["2023-01-12 19:22:36",null]]"#
+        );
+    }
 }
@@ -1,9 +1,11 @@
 use std::{
     fmt::Debug,
+    future::Future,
     io,
     net::SocketAddr,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 
 use futures_core::ready;
@@ -24,15 +26,75 @@ impl WithSocket for WithExaSocket {
         ExaSocket {
             server: self.0,
             inner: Box::new(socket),
+            io_timeout: None,
+            deadline: None,
+            timed_out: false,
         }
     }
 }
 
 /// A wrapper so we can implement [`AsyncRead`] and [`AsyncWrite`] for the underlying TCP socket.
 /// The traits are needed by the [`async_tungstenite::WebSocketStream`] wrapper.
+///
+/// When an I/O timeout is set, the socket fails with [`io::ErrorKind::TimedOut`] if it stays
+/// silent, i.e. it cannot make any read or write progress, for longer than the timeout. Once that
+/// happens the socket is considered broken and every subsequent operation fails as well.
 pub struct ExaSocket {
     pub server: SocketAddr,
     pub inner: Box<dyn Socket>,
+    io_timeout: Option<Duration>,
+    deadline: Option<Pin<Box<dyn Future<Output = ()> + Send + Sync>>>,
+    timed_out: bool,
+}
+
+impl ExaSocket {
+    /// Sets the maximum duration the socket can stay silent while waiting for I/O readiness.
+    pub fn set_io_timeout(&mut self, io_timeout: Option<Duration>) {
+        self.io_timeout = io_timeout;
+        self.deadline = None;
+    }
+
+    /// Fails immediately if the socket previously timed out.
+    fn check_timed_out(&self) -> io::Result<()> {
+        if self.timed_out {
+            Err(Self::timed_out_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Clears the armed deadline.
+    ///
+    /// Called whenever the socket makes progress, but also when a new request starts so that a
+    /// deadline armed by a previously cancelled operation does not fire while the socket was idle.
+    pub fn reset_deadline(&mut self) {
+        self.deadline = None;
+    }
+
+    /// Called when the socket is not ready for I/O. Arms the deadline, if not already armed, and
+    /// polls it, returning an error if it elapsed.
+    fn poll_deadline<T>(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<T>> {
+        let Some(io_timeout) = self.io_timeout else {
+            return Poll::Pending;
+        };
+
+        let deadline = self
+            .deadline
+            .get_or_insert_with(|| Box::pin(sqlx_core::rt::sleep(io_timeout)));
+
+        ready!(deadline.as_mut().poll(cx));
+
+        self.deadline = None;
+        self.timed_out = true;
+        Poll::Ready(Err(Self::timed_out_error()))
+    }
+
+    fn timed_out_error() -> io::Error {
+        io::Error::new(
+            io::ErrorKind::TimedOut,
+            "no data exchanged with the database within the configured io-timeout",
+        )
+    }
 }
 
 impl Debug for ExaSocket {
@@ -47,12 +109,19 @@ impl AsyncRead for ExaSocket {
         cx: &mut Context<'_>,
         mut buf: &mut [u8],
     ) -> Poll<io::Result<usize>> {
+        self.check_timed_out()?;
+
         while buf.has_remaining_mut() {
             match self.inner.try_read(&mut buf) {
                 Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
-                    ready!(self.inner.poll_read_ready(cx)?);
+                    if self.inner.poll_read_ready(cx)?.is_pending() {
+                        return self.poll_deadline(cx);
+                    }
+                }
+                ready => {
+                    self.reset_deadline();
+                    return Poll::Ready(ready);
                 }
-                ready => return Poll::Ready(ready),
             }
         }
 
@@ -66,12 +135,19 @@ impl AsyncWrite for ExaSocket {
         cx: &mut Context<'_>,
         buf: &[u8],
     ) -> Poll<io::Result<usize>> {
+        self.check_timed_out()?;
+
         while !buf.is_empty() {
             match self.inner.try_write(buf) {
                 Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
-                    ready!(self.inner.poll_write_ready(cx)?);
+                    if self.inner.poll_write_ready(cx)?.is_pending() {
+                        return self.poll_deadline(cx);
+                    }
+                }
+                ready => {
+                    self.reset_deadline();
+                    return Poll::Ready(ready);
                 }
-                ready => return Poll::Ready(ready),
             }
         }
 
@@ -79,6 +155,7 @@ impl AsyncWrite for ExaSocket {
     }
 
     fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.check_timed_out()?;
         self.inner.poll_flush(cx)
     }
 
@@ -86,3 +163,90 @@ impl AsyncWrite for ExaSocket {
         self.inner.poll_shutdown(cx)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{Ipv4Addr, SocketAddr},
+        task::Waker,
+    };
+
+    use futures_util::AsyncReadExt;
+    use sqlx_core::io::ReadBuf;
+
+    use super::*;
+
+    /// A socket that never becomes ready for I/O.
+    struct SilentSocket;
+
+    impl Socket for SilentSocket {
+        fn try_read(&mut self, _: &mut dyn ReadBuf) -> io::Result<usize> {
+            Err(io::ErrorKind::WouldBlock.into())
+        }
+
+        fn try_write(&mut self, _: &[u8]) -> io::Result<usize> {
+            Err(io::ErrorKind::WouldBlock.into())
+        }
+
+        fn poll_read_ready(&mut self, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Pending
+        }
+
+        fn poll_write_ready(&mut self, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Pending
+        }
+
+        fn poll_shutdown(&mut self, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    const IO_TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn silent_socket() -> ExaSocket {
+        ExaSocket {
+            server: SocketAddr::from((Ipv4Addr::LOCALHOST, 8563)),
+            inner: Box::new(SilentSocket),
+            io_timeout: Some(IO_TIMEOUT),
+            deadline: None,
+            timed_out: false,
+        }
+    }
+
+    #[test]
+    fn test_io_timeout_elapses() {
+        let mut socket = silent_socket();
+        let mut buf = [0; 8];
+
+        let err = sqlx_core::rt::test_block_on(socket.read(&mut buf)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+
+        // The socket is broken once timed out.
+        let mut cx = Context::from_waker(Waker::noop());
+        let poll = Pin::new(&mut socket).poll_read(&mut cx, &mut buf);
+        assert!(matches!(poll, Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::TimedOut));
+    }
+
+    #[test]
+    fn test_io_timeout_reset_after_cancellation() {
+        sqlx_core::rt::test_block_on(async {
+            let mut socket = silent_socket();
+            let mut buf = [0; 8];
+
+            // Arms the deadline, then gets cancelled.
+            let read = sqlx_core::rt::timeout(IO_TIMEOUT / 5, socket.read(&mut buf)).await;
+            assert!(read.is_err());
+
+            // The connection stays idle for longer than the timeout.
+            sqlx_core::rt::sleep(IO_TIMEOUT * 2).await;
+
+            // A new request starts and gets a fresh deadline.
+            socket.reset_deadline();
+            let mut cx = Context::from_waker(Waker::noop());
+            assert!(Pin::new(&mut socket)
+                .poll_read(&mut cx, &mut buf)
+                .is_pending());
+            assert!(!socket.timed_out);
+        });
+    }
+}
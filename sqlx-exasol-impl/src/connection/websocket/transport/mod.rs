@@ -62,6 +62,17 @@ impl MaybeCompressedWebSocket {
 
         ws.get_ref().get_ref().server
     }
+
+    /// Clears the I/O deadline of the underlying socket.
+    pub fn reset_io_deadline(&mut self) {
+        let ws = match self {
+            MaybeCompressedWebSocket::Plain(ws) => &mut ws.0,
+            #[cfg(feature = "compression")]
+            MaybeCompressedWebSocket::Compressed(ws) => &mut ws.inner,
+        };
+
+        ws.get_mut().get_mut().reset_deadline();
+    }
 }
 
 impl Stream for MaybeCompressedWebSocket {
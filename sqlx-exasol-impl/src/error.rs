@@ -5,7 +5,7 @@ use rsa::errors::Error as RsaError;
 use serde_json::error::Error as JsonError;
 use thiserror::Error as ThisError;
 
-use crate::SqlxError;
+use crate::{options::ProtocolVersion, SqlxError};
 
 /// Enum representing protocol implementation errors.
 #[derive(Debug, ThisError)]
@@ -20,10 +20,14 @@ pub enum ExaProtocolError {
     SendNotReady,
     #[error("no response received")]
     NoResponse,
+    #[error("expected a row count but received a result set")]
+    UnexpectedResultSet,
     #[error("server closed connection; info: {0}")]
     WebSocketClosed(CloseError),
     #[error("feature 'compression' must be enabled to use compression")]
     CompressionDisabled,
+    #[error("attribute '{0}' requires protocol version {version} or later", version = u8::from(*.1))]
+    UnsupportedAttribute(&'static str, ProtocolVersion),
 }
 
 #[derive(Debug)]
@@ -11,6 +11,8 @@ use native_tls as _;
 use rcgen as _;
 #[cfg(feature = "rustls")]
 use rustls as _;
+#[cfg(feature = "tls")]
+use sha2 as _;
 
 #[cfg(feature = "any")]
 pub mod any;
@@ -29,7 +31,6 @@ mod statement;
 #[cfg(feature = "migrate")]
 mod testing;
 mod transaction;
-#[cfg(feature = "macros")]
 mod type_checking;
 mod type_info;
 pub mod types;
@@ -39,18 +40,26 @@ pub use arguments::ExaArguments;
 pub use column::ExaColumn;
 #[cfg(feature = "etl")]
 pub use connection::etl;
-pub use connection::ExaConnection;
+pub use connection::{
+    ExaConnection, ExaHostDiagnostics, ExaLoginDiagnostics, ExaNodeDiagnostics, ExaOptionsExecutor,
+    ExaQueryOptions, ExaTlsDiagnostics,
+};
 pub use database::Exasol;
 pub use options::{ExaCompressionMode, ExaConnectOptions, ExaConnectOptionsBuilder, ExaSslMode};
 pub use query_result::ExaQueryResult;
-pub use responses::{ExaAttributes, ExaDatabaseError, SessionInfo};
+pub use responses::{ExaAttributes, ExaDatabaseError, ExaErrorCode, ExaErrorPosition, SessionInfo};
 pub use row::ExaRow;
 use sqlx_core::{
     executor::Executor, impl_acquire, impl_column_index_for_row, impl_column_index_for_statement,
     impl_into_arguments_for_arguments,
 };
 pub use statement::ExaStatement;
-pub use transaction::ExaTransactionManager;
+#[cfg(feature = "migrate")]
+pub use testing::ExaFixtureSnapshot;
+#[cfg(all(feature = "migrate", feature = "etl"))]
+#[doc(hidden)]
+pub use testing::{ExaCsvFixture, ExaCsvFixtureTarget};
+pub use transaction::{ExaPoolExt, ExaRetryPolicy, ExaTransactionManager, ExaTxOptions};
 #[doc(hidden)]
 #[cfg(feature = "macros")]
 pub use type_checking::QUERY_DRIVER;
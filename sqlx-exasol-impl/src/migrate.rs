@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     str::FromStr,
     time::{Duration, Instant},
 };
@@ -7,21 +8,25 @@ use futures_core::future::BoxFuture;
 use sqlx_core::{
     connection::{ConnectOptions, Connection},
     executor::Executor,
-    migrate::{AppliedMigration, Migrate, MigrateDatabase, MigrateError, Migration},
+    migrate::{AppliedMigration, Migrate, MigrateDatabase, MigrateError, Migration, Migrator},
     sql_str::AssertSqlSafe,
 };
+use thiserror::Error as ThisError;
 
 use crate::{
     connection::{
-        websocket::future::{ExecuteBatch, WebSocketFuture},
+        websocket::future::{Execute, ExecuteBatch, WebSocketFuture},
         ExaConnection,
     },
     database::Exasol,
     options::ExaConnectOptions,
-    SqlxError, SqlxResult,
+    ExaDatabaseError, ExaErrorCode, SqlxError, SqlxResult,
 };
 
-const LOCK_WARN: &str = "Exasol does not support database locking!";
+/// Table holding the migration lock row.
+pub(crate) const LOCK_TABLE: &str = "_sqlx_migrations_lock";
+/// Delay between attempts of acquiring a lock held by another session.
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_secs(1);
 
 fn parse_for_maintenance(url: &str) -> SqlxResult<(ExaConnectOptions, String)> {
     let mut options = ExaConnectOptions::from_str(url)?;
@@ -162,57 +167,106 @@ impl Migrate for ExaConnection {
         })
     }
 
+    /// Exasol has no advisory locks, so the lock is implemented as a row in a lock table, inserted
+    /// in its own transaction. The primary key ensures that only one session holds the lock.
+    ///
+    /// Locks not refreshed within the `migration-lock-expiry` connection option, e.g. because the
+    /// session holding them died, are taken over. The lock is refreshed before every migration,
+    /// so the expiry bounds the duration of a single migration rather than the whole run.
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
-            tracing::warn!("{LOCK_WARN}");
+            let query = format!(
+                r#"
+                CREATE TABLE IF NOT EXISTS "{LOCK_TABLE}" (
+                    id DECIMAL(1, 0) PRIMARY KEY,
+                    session_id DECIMAL(20, 0) NOT NULL,
+                    acquired_at TIMESTAMP NOT NULL,
+                    expires_at TIMESTAMP NOT NULL
+                );"#
+            );
+
+            // Concurrent deployers might race on creating the table as well.
+            match self.execute(AssertSqlSafe(query)).await {
+                Err(e) if !is_lock_contention(&e) => return Err(e.into()),
+                _ => (),
+            }
+
+            while !self.try_lock().await? {
+                tracing::debug!("migration lock held by another session; retrying");
+                sqlx_core::rt::sleep(LOCK_RETRY_INTERVAL).await;
+            }
+
+            self.migration_lock.held = true;
             Ok(())
         })
     }
 
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
-            tracing::warn!("{LOCK_WARN}");
+            let query = format!(
+                r#"DELETE FROM "{LOCK_TABLE}" WHERE id = 1 AND session_id = CURRENT_SESSION;"#
+            );
+            let rows_affected = self.execute(AssertSqlSafe(query)).await?.rows_affected();
+            self.migration_lock.held = false;
+
+            // Releasing a lock this session does not hold means that it expired and that another
+            // session could have run migrations concurrently.
+            if rows_affected != 1 {
+                return Err(ExaMigrateError::LockNotHeld.into());
+            }
+
             Ok(())
         })
     }
 
+    /// Migrations are run in a transaction and split into individual statements, unless opted
+    /// out through [`MigrationDirectives`].
     fn apply<'e>(
         &'e mut self,
         table_name: &'e str,
         migration: &'e Migration,
     ) -> BoxFuture<'e, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
-            let start = Instant::now();
-
-            ExecuteBatch::new(migration.sql.clone())
-                .future(&mut tx.ws)
-                .await?;
+            let directives = MigrationDirectives::parse(migration)?;
+            self.refresh_lock().await?;
 
             let checksum = hex::encode(&*migration.checksum);
+            let start = Instant::now();
 
             let query = format!(
                 r#"
             INSERT INTO "{table_name}" ( version, description, success, checksum, execution_time )
-            VALUES ( ?, ?, TRUE, ?, -1 );
+            VALUES ( ?, ?, ?, ?, -1 );
             "#
             );
 
-            let _ = sqlx_core::query::query(AssertSqlSafe(query))
+            let insert = sqlx_core::query::query(AssertSqlSafe(query))
                 .bind(migration.version)
                 .bind(&*migration.description)
-                .bind(checksum)
-                .execute(&mut *tx)
-                .await?;
-
-            tx.commit().await?;
+                .bind(!directives.no_transaction)
+                .bind(checksum);
+
+            if directives.no_transaction {
+                // Record the migration as unsuccessful first, so that it is detected as dirty if
+                // it fails midway, as it cannot be rolled back.
+                let _ = insert.execute(&mut *self).await?;
+
+                self.execute_migration(migration, directives)
+                    .await
+                    .map_err(|e| MigrateError::ExecuteMigration(e, migration.version))?;
+            } else {
+                let mut tx = self.begin().await?;
+                tx.execute_migration(migration, directives).await?;
+                let _ = insert.execute(&mut *tx).await?;
+                tx.commit().await?;
+            }
 
             let elapsed = start.elapsed();
 
             let query = format!(
                 r#"
                 UPDATE "{table_name}"
-                SET execution_time = ?
+                SET success = TRUE, execution_time = ?
                 WHERE version = ?
                 "#
             );
@@ -234,24 +288,435 @@ impl Migrate for ExaConnection {
         migration: &'e Migration,
     ) -> BoxFuture<'e, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
+            let directives = MigrationDirectives::parse(migration)?;
+            self.refresh_lock().await?;
+
             let start = Instant::now();
+            let query = format!(r#" DELETE FROM "{table_name}" WHERE version = ? "#);
+            let delete = sqlx_core::query::query(AssertSqlSafe(query)).bind(migration.version);
+
+            if directives.no_transaction {
+                self.execute_migration(migration, directives)
+                    .await
+                    .map_err(|e| MigrateError::ExecuteMigration(e, migration.version))?;
+                let _ = delete.execute(&mut *self).await?;
+            } else {
+                let mut tx = self.begin().await?;
+                tx.execute_migration(migration, directives).await?;
+                let _ = delete.execute(&mut *tx).await?;
+                tx.commit().await?;
+            }
 
-            ExecuteBatch::new(migration.sql.clone())
-                .future(&mut tx.ws)
+            let elapsed = start.elapsed();
+
+            Ok(elapsed)
+        })
+    }
+}
+
+/// Exasol specific directives controlling how a migration is run, given as a line comment at the
+/// start of the migration file:
+///
+/// ```sql
+/// -- sqlx-exasol: no-transaction, no-split
+/// CREATE OR REPLACE SCRIPT my_script() AS ...
+/// ```
+///
+/// - `no-transaction`: runs the migration outside of a transaction; also enabled by the `--
+///   no-transaction` directive supported by `sqlx`.
+/// - `no-split`: sends the migration as a single statement instead of splitting it into individual
+///   statements, needed for statements such as `CREATE SCRIPT` whose body contains semicolons.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct MigrationDirectives {
+    no_transaction: bool,
+    no_split: bool,
+}
+
+impl MigrationDirectives {
+    const PREFIX: &str = "-- sqlx-exasol:";
+    const NO_TRANSACTION: &str = "no-transaction";
+    const NO_SPLIT: &str = "no-split";
+
+    /// Parses the directives from the leading comment lines of the migration.
+    fn parse(migration: &Migration) -> Result<Self, MigrateError> {
+        let mut directives = Self {
+            no_transaction: migration.no_tx,
+            no_split: false,
+        };
+
+        let lines = migration
+            .sql
+            .as_str()
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .take_while(|l| l.starts_with("--"));
+
+        for line in lines {
+            let Some(list) = line.strip_prefix(Self::PREFIX) else {
+                continue;
+            };
+
+            for directive in list.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+                match directive {
+                    Self::NO_TRANSACTION => directives.no_transaction = true,
+                    Self::NO_SPLIT => directives.no_split = true,
+                    _ => {
+                        let msg = format!("unknown migration directive: {directive}");
+                        let err = SqlxError::Configuration(msg.into());
+                        return Err(MigrateError::ExecuteMigration(err, migration.version));
+                    }
+                }
+            }
+        }
+
+        Ok(directives)
+    }
+}
+
+impl ExaConnection {
+    /// Marks all migrations of the [`Migrator`] up to and including `version` as applied without
+    /// running them.
+    ///
+    /// Useful for adopting migrations on a schema whose objects already exist.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the migrations table cannot be read or written to or if there is a
+    /// dirty migration.
+    pub async fn baseline_migrations(
+        &mut self,
+        migrator: &Migrator,
+        version: i64,
+    ) -> Result<(), MigrateError> {
+        if migrator.locking {
+            self.lock().await?;
+        }
+
+        let result = self.baseline_migrations_locked(migrator, version).await;
+        self.unlock_migrations(migrator, result).await
+    }
+
+    /// Rewrites the stored checksums of applied migrations to match the ones of the [`Migrator`],
+    /// for migrations that were changed after being applied. Only the migration with the given
+    /// version is repaired, if provided.
+    ///
+    /// Returns the versions of the repaired migrations.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the migrations table cannot be read or written to.
+    pub async fn repair_migrations(
+        &mut self,
+        migrator: &Migrator,
+        version: Option<i64>,
+    ) -> Result<Vec<i64>, MigrateError> {
+        if migrator.locking {
+            self.lock().await?;
+        }
+
+        let result = self.repair_migrations_locked(migrator, version).await;
+        self.unlock_migrations(migrator, result).await
+    }
+
+    async fn baseline_migrations_locked(
+        &mut self,
+        migrator: &Migrator,
+        version: i64,
+    ) -> Result<(), MigrateError> {
+        let table_name = &*migrator.table_name;
+
+        for schema_name in migrator.create_schemas.iter() {
+            self.create_schema_if_not_exists(schema_name).await?;
+        }
+
+        self.ensure_migrations_table(table_name).await?;
+
+        if let Some(version) = self.dirty_version(table_name).await? {
+            return Err(MigrateError::Dirty(version));
+        }
+
+        let applied = self
+            .list_applied_migrations(table_name)
+            .await?
+            .into_iter()
+            .map(|m| (m.version, m.checksum))
+            .collect::<HashMap<_, _>>();
+
+        let query = format!(
+            r#"
+            INSERT INTO "{table_name}" ( version, description, success, checksum, execution_time )
+            VALUES ( ?, ?, TRUE, ?, -1 );
+            "#
+        );
+
+        let migrations = migrator
+            .iter()
+            .filter(|m| !m.migration_type.is_down_migration())
+            .take_while(|m| m.version <= version);
+
+        for migration in migrations {
+            match applied.get(&migration.version) {
+                Some(checksum) if *checksum != migration.checksum => {
+                    return Err(MigrateError::VersionMismatch(migration.version));
+                }
+                Some(_) => continue,
+                None => (),
+            }
+
+            let _ = sqlx_core::query::query(AssertSqlSafe(query.clone()))
+                .bind(migration.version)
+                .bind(&*migration.description)
+                .bind(hex::encode(&*migration.checksum))
+                .execute(&mut *self)
                 .await?;
 
-            let query = format!(r#" DELETE FROM "{table_name}" WHERE version = ? "#);
-            let _ = sqlx_core::query::query(AssertSqlSafe(query))
+            tracing::info!("baselined migration {}", migration.version);
+        }
+
+        Ok(())
+    }
+
+    async fn repair_migrations_locked(
+        &mut self,
+        migrator: &Migrator,
+        version: Option<i64>,
+    ) -> Result<Vec<i64>, MigrateError> {
+        let table_name = &*migrator.table_name;
+
+        self.ensure_migrations_table(table_name).await?;
+
+        let applied = self
+            .list_applied_migrations(table_name)
+            .await?
+            .into_iter()
+            .map(|m| (m.version, m.checksum))
+            .collect::<HashMap<_, _>>();
+
+        let query = format!(r#"UPDATE "{table_name}" SET checksum = ? WHERE version = ?;"#);
+        let mut repaired = Vec::new();
+
+        let migrations = migrator
+            .iter()
+            .filter(|m| !m.migration_type.is_down_migration())
+            .filter(|m| version.is_none_or(|v| v == m.version));
+
+        for migration in migrations {
+            match applied.get(&migration.version) {
+                Some(checksum) if *checksum != migration.checksum => (),
+                _ => continue,
+            }
+
+            let _ = sqlx_core::query::query(AssertSqlSafe(query.clone()))
+                .bind(hex::encode(&*migration.checksum))
                 .bind(migration.version)
-                .execute(&mut *tx)
+                .execute(&mut *self)
                 .await?;
 
-            tx.commit().await?;
+            tracing::info!("repaired checksum of migration {}", migration.version);
+            repaired.push(migration.version);
+        }
 
-            let elapsed = start.elapsed();
+        Ok(repaired)
+    }
 
-            Ok(elapsed)
-        })
+    /// Releases the migration lock, if taken, regardless of the outcome of the locked operation.
+    ///
+    /// The error of the operation takes precedence over the one of releasing the lock.
+    async fn unlock_migrations<T>(
+        &mut self,
+        migrator: &Migrator,
+        result: Result<T, MigrateError>,
+    ) -> Result<T, MigrateError> {
+        if !migrator.locking {
+            return result;
+        }
+
+        let unlocked = self.unlock().await;
+        let value = result?;
+        unlocked.map(|()| value)
+    }
+
+    /// Runs the migration SQL, honoring the `no-split` directive.
+    async fn execute_migration(
+        &mut self,
+        migration: &Migration,
+        directives: MigrationDirectives,
+    ) -> SqlxResult<()> {
+        let sql = migration.sql.clone();
+
+        if directives.no_split {
+            Execute::new(sql).future(&mut self.ws).await?;
+        } else {
+            ExecuteBatch::new(sql).future(&mut self.ws).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Attempts to acquire the migration lock, taking over stale locks.
+    ///
+    /// Returns `false` if the lock is held by another session.
+    async fn try_lock(&mut self) -> SqlxResult<bool> {
+        let expiry = self.migration_lock.expiry.as_secs();
+        let mut tx = self.begin().await?;
+
+        let query =
+            format!(r#"DELETE FROM "{LOCK_TABLE}" WHERE id = 1 AND expires_at < SYSTIMESTAMP;"#);
+
+        let stale = match tx.execute(AssertSqlSafe(query)).await {
+            Ok(res) => res.rows_affected(),
+            Err(e) if is_lock_contention(&e) => return tx.rollback().await.map(|()| false),
+            Err(e) => return Err(e),
+        };
+
+        let query = format!(
+            r#"
+            INSERT INTO "{LOCK_TABLE}" ( id, session_id, acquired_at, expires_at )
+            VALUES ( 1, CURRENT_SESSION, SYSTIMESTAMP, ADD_SECONDS(SYSTIMESTAMP, {expiry}) );
+            "#
+        );
+
+        match tx.execute(AssertSqlSafe(query)).await {
+            Ok(_) => (),
+            Err(e) if is_lock_contention(&e) => return tx.rollback().await.map(|()| false),
+            Err(e) => return Err(e),
+        }
+
+        match tx.commit().await {
+            Ok(()) => (),
+            Err(e) if is_lock_contention(&e) => return Ok(false),
+            Err(e) => return Err(e),
+        }
+
+        if stale > 0 {
+            tracing::warn!("took over stale migration lock");
+        }
+
+        Ok(true)
+    }
+
+    /// Extends the expiry of the migration lock, if held by this session.
+    ///
+    /// This is a no-op if migrations are run without locking. Fails if the lock expired and was
+    /// taken over by another session, as running migrations concurrently is unsafe.
+    async fn refresh_lock(&mut self) -> Result<(), MigrateError> {
+        if !self.migration_lock.held {
+            return Ok(());
+        }
+
+        let expiry = self.migration_lock.expiry.as_secs();
+        let query = format!(
+            r#"
+            UPDATE "{LOCK_TABLE}"
+            SET expires_at = ADD_SECONDS(SYSTIMESTAMP, {expiry})
+            WHERE id = 1 AND session_id = CURRENT_SESSION;
+            "#
+        );
+
+        if self.execute(AssertSqlSafe(query)).await?.rows_affected() == 0 {
+            self.migration_lock.held = false;
+            return Err(ExaMigrateError::LockNotHeld.into());
+        }
+
+        Ok(())
+    }
+}
+
+/// Errors related to the migration lock.
+///
+/// Losing the lock is a runtime failure of the migration run, so these surface as protocol errors.
+#[derive(Clone, Copy, Debug, ThisError)]
+enum ExaMigrateError {
+    #[error(
+        "migration lock is not held by this session; it expired and was possibly taken over by \
+         another session, consider increasing the `migration-lock-expiry` connection option"
+    )]
+    LockNotHeld,
+}
+
+impl From<ExaMigrateError> for MigrateError {
+    fn from(value: ExaMigrateError) -> Self {
+        Self::Execute(SqlxError::Protocol(value.to_string()))
+    }
+}
+
+/// The state of the migration lock of a connection.
+#[derive(Debug)]
+pub(crate) struct MigrationLock {
+    /// Duration after which a lock that was not refreshed is considered stale and can be taken
+    /// over.
+    expiry: Duration,
+    /// Whether the lock is held by this connection, in which case it gets refreshed.
+    held: bool,
+}
+
+impl MigrationLock {
+    pub(crate) fn new(expiry: Duration) -> Self {
+        Self {
+            expiry,
+            held: false,
+        }
+    }
+}
+
+/// Returns whether the error is caused by another session holding or racing for the lock.
+pub(crate) fn is_lock_contention(error: &SqlxError) -> bool {
+    let SqlxError::Database(e) = error else {
+        return false;
+    };
+
+    e.try_downcast_ref::<ExaDatabaseError>().is_some_and(|e| {
+        matches!(
+            e.exa_code(),
+            ExaErrorCode::UniqueViolation | ExaErrorCode::TransactionCollision
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx_core::{migrate::MigrationType, sql_str::SqlStr};
+
+    use super::*;
+
+    fn migration(sql: &'static str, no_tx: bool) -> Migration {
+        let sql = SqlStr::from_static(sql);
+        Migration::new(1, "test".into(), MigrationType::Simple, sql, no_tx)
+    }
+
+    #[test]
+    fn test_directives_none() {
+        let directives = MigrationDirectives::parse(&migration("SELECT 1;", false)).unwrap();
+        assert_eq!(directives, MigrationDirectives::default());
+    }
+
+    #[test]
+    fn test_directives_parsed() {
+        let sql = "-- initial comment\n-- sqlx-exasol: no-transaction, no-split\nSELECT 1;";
+        let directives = MigrationDirectives::parse(&migration(sql, false)).unwrap();
+        assert!(directives.no_transaction);
+        assert!(directives.no_split);
+    }
+
+    #[test]
+    fn test_directives_sqlx_no_tx() {
+        let sql = "-- no-transaction\n-- sqlx-exasol: no-split\nSELECT 1;";
+        let directives = MigrationDirectives::parse(&migration(sql, true)).unwrap();
+        assert!(directives.no_transaction);
+        assert!(directives.no_split);
+    }
+
+    #[test]
+    fn test_directives_after_statement_ignored() {
+        let sql = "SELECT 1;\n-- sqlx-exasol: no-split";
+        let directives = MigrationDirectives::parse(&migration(sql, false)).unwrap();
+        assert_eq!(directives, MigrationDirectives::default());
+    }
+
+    #[test]
+    fn test_directives_unknown() {
+        let sql = "-- sqlx-exasol: no-splitting\nSELECT 1;";
+        assert!(MigrationDirectives::parse(&migration(sql, false)).is_err());
     }
 }
@@ -4,7 +4,8 @@ use sqlx_core::{connection::LogSettings, net::tls::CertificateInput};
 
 use super::{
     error::ExaConfigError, ssl_mode::ExaSslMode, ExaConnectOptions, Login, ProtocolVersion,
-    DEFAULT_CACHE_CAPACITY, DEFAULT_FETCH_SIZE, DEFAULT_PORT,
+    DEFAULT_CACHE_CAPACITY, DEFAULT_FETCH_SIZE, DEFAULT_MIGRATION_LOCK_EXPIRY, DEFAULT_PORT,
+    IO_TIMEOUT, MIGRATION_LOCK_EXPIRY,
 };
 use crate::{options::compression::ExaCompressionMode, SqlxResult};
 
@@ -29,6 +30,10 @@ pub struct ExaConnectOptionsBuilder {
     query_timeout: u64,
     compression_mode: ExaCompressionMode,
     feedback_interval: u64,
+    io_timeout: u64,
+    migration_lock_expiry: u64,
+    nanosecond_timestamps: bool,
+    infer_nullability: bool,
 }
 
 impl Default for ExaConnectOptionsBuilder {
@@ -52,6 +57,10 @@ impl Default for ExaConnectOptionsBuilder {
             query_timeout: 0,
             compression_mode: ExaCompressionMode::default(),
             feedback_interval: 1,
+            io_timeout: 0,
+            migration_lock_expiry: DEFAULT_MIGRATION_LOCK_EXPIRY,
+            nanosecond_timestamps: false,
+            infer_nullability: true,
         }
     }
 }
@@ -61,9 +70,22 @@ impl ExaConnectOptionsBuilder {
     ///
     /// # Errors
     ///
-    /// Will return an error if no host or other than exactly one login method were provided.
+    /// Will return an error if no host or other than exactly one login method were provided, if a
+    /// non-zero I/O timeout does not exceed the feedback interval or if the migration lock expiry
+    /// is zero.
     pub fn build(self) -> SqlxResult<ExaConnectOptions> {
         let url_host = self.url_host.ok_or(ExaConfigError::MissingHost)?;
+
+        // The database only sends keep-alive frames every feedback interval while executing a
+        // query, so a shorter I/O timeout would break connections running long queries.
+        if self.io_timeout != 0 && self.io_timeout <= self.feedback_interval {
+            return Err(ExaConfigError::InvalidParameter(IO_TIMEOUT).into());
+        }
+
+        if self.migration_lock_expiry == 0 {
+            return Err(ExaConfigError::InvalidParameter(MIGRATION_LOCK_EXPIRY).into());
+        }
+
         let password = self.password.unwrap_or_default();
 
         // Only one authentication method can be used at once
@@ -97,6 +119,10 @@ impl ExaConnectOptionsBuilder {
             query_timeout: self.query_timeout,
             compression_mode: self.compression_mode,
             feedback_interval: self.feedback_interval,
+            io_timeout: self.io_timeout,
+            migration_lock_expiry: self.migration_lock_expiry,
+            nanosecond_timestamps: self.nanosecond_timestamps,
+            infer_nullability: self.infer_nullability,
             log_settings: LogSettings::default(),
         };
 
@@ -212,6 +238,52 @@ impl ExaConnectOptionsBuilder {
         self
     }
 
+    /// Sets the maximum amount of seconds a request/response exchange with the database can stay
+    /// silent before the connection is considered broken. `0` means no timeout.
+    ///
+    /// Keep-alive frames sent every feedback interval count as activity, so the timeout must be
+    /// greater than the feedback interval.
+    #[must_use = "call build() to get connection options"]
+    pub fn io_timeout(mut self, io_timeout: u64) -> Self {
+        self.io_timeout = io_timeout;
+        self
+    }
+
+    /// Sets the amount of seconds after which a migration lock that was not refreshed is
+    /// considered stale and can be taken over by another session.
+    ///
+    /// The lock is refreshed before every migration, so the expiry must exceed the duration of the
+    /// longest migration. Defaults to 15 minutes.
+    #[must_use = "call build() to get connection options"]
+    pub fn migration_lock_expiry(mut self, migration_lock_expiry: u64) -> Self {
+        self.migration_lock_expiry = migration_lock_expiry;
+        self
+    }
+
+    /// Sets whether the session renders timestamps with nanoseconds, by changing the
+    /// `NLS_TIMESTAMP_FORMAT` session parameter.
+    ///
+    /// The default format only has microseconds, which truncates values of `TIMESTAMP(p)` columns
+    /// with a higher precision. Since the format also applies to converting timestamps to strings
+    /// in SQL, this is disabled by default.
+    #[must_use = "call build() to get connection options"]
+    pub fn nanosecond_timestamps(mut self, nanosecond_timestamps: bool) -> Self {
+        self.nanosecond_timestamps = nanosecond_timestamps;
+        self
+    }
+
+    /// Sets whether describing a statement infers the nullability of its result columns from the
+    /// constraints of the tables they come from.
+    ///
+    /// Columns whose nullability cannot be proven are always reported as unknown. Disabling this
+    /// makes the `query!` macros map all columns to [`Option`], like they did before the
+    /// inference was introduced. Enabled by default.
+    #[must_use = "call build() to get connection options"]
+    pub fn infer_nullability(mut self, infer_nullability: bool) -> Self {
+        self.infer_nullability = infer_nullability;
+        self
+    }
+
     /// Exasol supports host ranges, e.g: hostname1..4.com.
     /// This method parses the provided host in the connection string and generates one for each
     /// possible entry in the range.
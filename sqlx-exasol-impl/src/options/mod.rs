@@ -4,7 +4,7 @@ mod error;
 mod protocol_version;
 mod ssl_mode;
 
-use std::{borrow::Cow, path::PathBuf, str::FromStr, sync::Arc};
+use std::{borrow::Cow, path::PathBuf, str::FromStr, sync::Arc, time::Duration};
 
 pub use builder::ExaConnectOptionsBuilder;
 pub use compression::ExaCompressionMode;
@@ -34,6 +34,7 @@ const URL_SCHEME: &str = "exa";
 const DEFAULT_FETCH_SIZE: usize = 5 * 1024 * 1024;
 const DEFAULT_PORT: u16 = 8563;
 const DEFAULT_CACHE_CAPACITY: usize = 100;
+const DEFAULT_MIGRATION_LOCK_EXPIRY: u64 = 15 * 60;
 
 const ACCESS_TOKEN: &str = "access-token";
 const REFRESH_TOKEN: &str = "refresh-token";
@@ -46,6 +47,10 @@ const FETCH_SIZE: &str = "fetch-size";
 const QUERY_TIMEOUT: &str = "query-timeout";
 const COMPRESSION: &str = "compression";
 const FEEDBACK_INTERVAL: &str = "feedback-interval";
+const IO_TIMEOUT: &str = "io-timeout";
+const MIGRATION_LOCK_EXPIRY: &str = "migration-lock-expiry";
+const NANOSECOND_TIMESTAMPS: &str = "nanosecond-timestamps";
+const INFER_NULLABILITY: &str = "infer-nullability";
 
 /// Options for connecting to the Exasol database. Implementor of [`ConnectOptions`].
 ///
@@ -64,6 +69,14 @@ const FEEDBACK_INTERVAL: &str = "feedback-interval";
 /// - `query-timeout`: The query timeout amount, in seconds. 0 means no timeout
 /// - `compression`: Set the desired compression mode.
 /// - `feedback-interval`: Interval at which Exasol sends keep-alive Pong frames
+/// - `io-timeout`: Seconds a request/response exchange can stay silent before the connection is
+///   considered broken. Must exceed the feedback interval. 0 means no timeout
+/// - `migration-lock-expiry`: Seconds after which a migration lock that was not refreshed can be
+///   taken over by another session. Must exceed the duration of the longest migration
+/// - `nanosecond-timestamps`: Whether the session renders timestamps with nanoseconds instead of
+///   microseconds, which `TIMESTAMP(p)` columns with a precision above 6 need
+/// - `infer-nullability`: Whether describing statements infers the nullability of result columns
+///   from table constraints. Enabled by default
 #[derive(Debug, Clone)]
 pub struct ExaConnectOptions {
     pub(crate) hosts: Vec<(Arc<str>, u16)>,
@@ -75,6 +88,8 @@ pub struct ExaConnectOptions {
     pub(crate) schema: Option<String>,
     pub(crate) compression_mode: ExaCompressionMode,
     pub(crate) log_settings: LogSettings,
+    pub(crate) nanosecond_timestamps: bool,
+    pub(crate) infer_nullability: bool,
     url_host: String,
     url_port: u16,
     login: Login,
@@ -82,6 +97,8 @@ pub struct ExaConnectOptions {
     fetch_size: usize,
     query_timeout: u64,
     feedback_interval: u64,
+    io_timeout: u64,
+    migration_lock_expiry: u64,
 }
 
 impl ExaConnectOptions {
@@ -164,40 +181,50 @@ impl ExaConnectOptions {
                 }
 
                 STATEMENT_CACHE_CAPACITY => {
-                    let capacity = value
-                        .parse::<usize>()
-                        .map_err(|_| ExaConfigError::InvalidParameter(STATEMENT_CACHE_CAPACITY))?;
+                    let capacity = Self::parse_param(STATEMENT_CACHE_CAPACITY, &value)?;
                     builder = builder.statement_cache_capacity(capacity);
                 }
 
                 FETCH_SIZE => {
-                    let fetch_size = value
-                        .parse::<usize>()
-                        .map_err(|_| ExaConfigError::InvalidParameter(FETCH_SIZE))?;
+                    let fetch_size = Self::parse_param(FETCH_SIZE, &value)?;
                     builder = builder.fetch_size(fetch_size);
                 }
 
                 QUERY_TIMEOUT => {
-                    let query_timeout = value
-                        .parse::<u64>()
-                        .map_err(|_| ExaConfigError::InvalidParameter(QUERY_TIMEOUT))?;
+                    let query_timeout = Self::parse_param(QUERY_TIMEOUT, &value)?;
                     builder = builder.query_timeout(query_timeout);
                 }
 
                 COMPRESSION => {
-                    let compression_mode = value
-                        .parse::<ExaCompressionMode>()
-                        .map_err(|_| ExaConfigError::InvalidParameter(COMPRESSION))?;
+                    let compression_mode = Self::parse_param(COMPRESSION, &value)?;
                     builder = builder.compression_mode(compression_mode);
                 }
 
                 FEEDBACK_INTERVAL => {
-                    let feedback_interval = value
-                        .parse::<u64>()
-                        .map_err(|_| ExaConfigError::InvalidParameter(FEEDBACK_INTERVAL))?;
+                    let feedback_interval = Self::parse_param(FEEDBACK_INTERVAL, &value)?;
                     builder = builder.feedback_interval(feedback_interval);
                 }
 
+                IO_TIMEOUT => {
+                    let io_timeout = Self::parse_param(IO_TIMEOUT, &value)?;
+                    builder = builder.io_timeout(io_timeout);
+                }
+
+                MIGRATION_LOCK_EXPIRY => {
+                    let migration_lock_expiry = Self::parse_param(MIGRATION_LOCK_EXPIRY, &value)?;
+                    builder = builder.migration_lock_expiry(migration_lock_expiry);
+                }
+
+                NANOSECOND_TIMESTAMPS => {
+                    let nanosecond_timestamps = Self::parse_param(NANOSECOND_TIMESTAMPS, &value)?;
+                    builder = builder.nanosecond_timestamps(nanosecond_timestamps);
+                }
+
+                INFER_NULLABILITY => {
+                    let infer_nullability = Self::parse_param(INFER_NULLABILITY, &value)?;
+                    builder = builder.infer_nullability(infer_nullability);
+                }
+
                 _ => {
                     return Err(SqlxError::Protocol(format!(
                         "Unknown connection string parameter: {value}"
@@ -209,6 +236,13 @@ impl ExaConnectOptions {
         Ok(builder)
     }
 
+    /// Parses the value of a connection string query parameter.
+    fn parse_param<T: FromStr>(name: &'static str, value: &str) -> Result<T, ExaConfigError> {
+        value
+            .parse()
+            .map_err(|_| ExaConfigError::InvalidParameter(name))
+    }
+
     /// Create an [`ExaConnectOptionsBuilder`] by starting from a connection string.
     ///
     /// # Errors
@@ -221,6 +255,17 @@ impl ExaConnectOptions {
             .map_err(SqlxError::Configuration)?;
         Self::builder_from_url(&url)
     }
+
+    /// Returns the I/O timeout of the connection's socket, if one is set.
+    pub(crate) fn io_timeout(&self) -> Option<Duration> {
+        (self.io_timeout != 0).then(|| Duration::from_secs(self.io_timeout))
+    }
+
+    /// Returns the duration after which a migration lock that was not refreshed is stale.
+    #[cfg(feature = "migrate")]
+    pub(crate) fn migration_lock_expiry(&self) -> Duration {
+        Duration::from_secs(self.migration_lock_expiry)
+    }
 }
 
 impl FromStr for ExaConnectOptions {
@@ -300,6 +345,22 @@ impl ConnectOptions for ExaConnectOptions {
         url.query_pairs_mut()
             .append_pair(FEEDBACK_INTERVAL, &self.feedback_interval.to_string());
 
+        url.query_pairs_mut()
+            .append_pair(IO_TIMEOUT, &self.io_timeout.to_string());
+
+        url.query_pairs_mut().append_pair(
+            MIGRATION_LOCK_EXPIRY,
+            &self.migration_lock_expiry.to_string(),
+        );
+
+        url.query_pairs_mut().append_pair(
+            NANOSECOND_TIMESTAMPS,
+            &self.nanosecond_timestamps.to_string(),
+        );
+
+        url.query_pairs_mut()
+            .append_pair(INFER_NULLABILITY, &self.infer_nullability.to_string());
+
         url
     }
 
@@ -315,11 +376,7 @@ impl ConnectOptions for ExaConnectOptions {
         self
     }
 
-    fn log_slow_statements(
-        mut self,
-        level: log::LevelFilter,
-        duration: std::time::Duration,
-    ) -> Self {
+    fn log_slow_statements(mut self, level: log::LevelFilter, duration: Duration) -> Self {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
@@ -480,6 +537,63 @@ mod tests {
         assert_eq!(options.feedback_interval, 10);
     }
 
+    #[test]
+    fn test_from_url_io_timeout() {
+        let url = "exa://user:p@ssw0rd@localhost:8563?io-timeout=30";
+        let options = ExaConnectOptions::from_str(url).unwrap();
+        assert_eq!(options.io_timeout(), Some(Duration::from_secs(30)));
+
+        let url = "exa://user:p@ssw0rd@localhost:8563";
+        let options = ExaConnectOptions::from_str(url).unwrap();
+        assert_eq!(options.io_timeout(), None);
+
+        // The timeout must exceed the feedback interval.
+        let url = "exa://user:p@ssw0rd@localhost:8563?io-timeout=5&feedback-interval=5";
+        assert!(ExaConnectOptions::from_str(url).is_err());
+    }
+
+    #[test]
+    fn test_from_url_migration_lock_expiry() {
+        let url = "exa://user:p@ssw0rd@localhost:8563?migration-lock-expiry=3600";
+        let options = ExaConnectOptions::from_str(url).unwrap();
+        assert_eq!(options.migration_lock_expiry, 3600);
+
+        let url = "exa://user:p@ssw0rd@localhost:8563";
+        let options = ExaConnectOptions::from_str(url).unwrap();
+        assert_eq!(options.migration_lock_expiry, DEFAULT_MIGRATION_LOCK_EXPIRY);
+
+        let url = "exa://user:p@ssw0rd@localhost:8563?migration-lock-expiry=0";
+        assert!(ExaConnectOptions::from_str(url).is_err());
+    }
+
+    #[test]
+    fn test_from_url_nanosecond_timestamps() {
+        let url = "exa://user:p@ssw0rd@localhost:8563?nanosecond-timestamps=true";
+        let options = ExaConnectOptions::from_str(url).unwrap();
+        assert!(options.nanosecond_timestamps);
+
+        let url = "exa://user:p@ssw0rd@localhost:8563";
+        let options = ExaConnectOptions::from_str(url).unwrap();
+        assert!(!options.nanosecond_timestamps);
+
+        let url = "exa://user:p@ssw0rd@localhost:8563?nanosecond-timestamps=yes";
+        assert!(ExaConnectOptions::from_str(url).is_err());
+    }
+
+    #[test]
+    fn test_from_url_infer_nullability() {
+        let url = "exa://user:p@ssw0rd@localhost:8563?infer-nullability=false";
+        let options = ExaConnectOptions::from_str(url).unwrap();
+        assert!(!options.infer_nullability);
+
+        let url = "exa://user:p@ssw0rd@localhost:8563";
+        let options = ExaConnectOptions::from_str(url).unwrap();
+        assert!(options.infer_nullability);
+
+        let url = "exa://user:p@ssw0rd@localhost:8563?infer-nullability=no";
+        assert!(ExaConnectOptions::from_str(url).is_err());
+    }
+
     #[test]
     fn test_from_url_invalid_scheme() {
         let url = "mysql://localhost:8563";
@@ -562,6 +676,10 @@ mod tests {
             .fetch_size(2048)
             .query_timeout(60)
             .feedback_interval(5)
+            .io_timeout(30)
+            .migration_lock_expiry(3600)
+            .nanosecond_timestamps(true)
+            .infer_nullability(false)
             .statement_cache_capacity(200)
             .build()
             .unwrap();
@@ -575,6 +693,19 @@ mod tests {
         assert_eq!(query_pairs.get(FETCH_SIZE), Some(&"2048".to_string()));
         assert_eq!(query_pairs.get(QUERY_TIMEOUT), Some(&"60".to_string()));
         assert_eq!(query_pairs.get(FEEDBACK_INTERVAL), Some(&"5".to_string()));
+        assert_eq!(query_pairs.get(IO_TIMEOUT), Some(&"30".to_string()));
+        assert_eq!(
+            query_pairs.get(MIGRATION_LOCK_EXPIRY),
+            Some(&"3600".to_string())
+        );
+        assert_eq!(
+            query_pairs.get(NANOSECOND_TIMESTAMPS),
+            Some(&"true".to_string())
+        );
+        assert_eq!(
+            query_pairs.get(INFER_NULLABILITY),
+            Some(&"false".to_string())
+        );
         assert_eq!(
             query_pairs.get(STATEMENT_CACHE_CAPACITY),
             Some(&"200".to_string())
@@ -2,6 +2,11 @@ use std::{borrow::Cow, ops::Not};
 
 use serde::{Deserialize, Deserializer, Serialize};
 
+use crate::{
+    connection::ExaQueryOptions, error::ExaProtocolError, options::ProtocolVersion,
+    transaction::ExaTxOptions, SqlxResult,
+};
+
 /// Struct representing attributes related to the connection with the Exasol server.
 /// These can either be returned by an explicit `getAttributes` call or as part of any response.
 ///
@@ -18,6 +23,9 @@ pub struct ExaAttributes {
 }
 
 impl ExaAttributes {
+    /// The protocol version that introduced the `resultSetMaxRows` attribute.
+    const RESULT_SET_MAX_ROWS_VERSION: ProtocolVersion = ProtocolVersion::V3;
+
     pub(crate) fn new(
         compression_enabled: bool,
         fetch_size: usize,
@@ -90,6 +98,27 @@ impl ExaAttributes {
         self
     }
 
+    /// The maximum number of rows returned in a result set. `0` means no limit.
+    #[must_use]
+    pub fn result_set_max_rows(&self) -> u64 {
+        self.read_write.result_set_max_rows.unwrap_or_default()
+    }
+
+    /// Limits the number of rows returned in a result set. `0` means no limit.
+    ///
+    /// The attribute is only sent to the database once set and requires protocol version 3 or
+    /// later.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the connection uses an older protocol version.
+    pub fn set_result_set_max_rows(&mut self, result_set_max_rows: u64) -> SqlxResult<&mut Self> {
+        self.check_protocol_version("resultSetMaxRows", Self::RESULT_SET_MAX_ROWS_VERSION)?;
+        self.driver.needs_send = true;
+        self.read_write.result_set_max_rows = Some(result_set_max_rows);
+        Ok(self)
+    }
+
     #[must_use]
     pub fn snapshot_transactions_enabled(&self) -> bool {
         self.read_write.snapshot_transactions_enabled
@@ -185,13 +214,87 @@ impl ExaAttributes {
         self.driver.needs_send = true;
         self.read_write.autocommit = autocommit;
         self.driver.open_transaction = !autocommit;
+
+        // Ending a transaction restores the attributes changed through its options.
+        if autocommit {
+            if let Some(enabled) = self.driver.restore_snapshot_transactions.take() {
+                self.read_write.snapshot_transactions_enabled = enabled;
+            }
+        }
+
         self
     }
 
+    /// Applies the provided [`ExaTxOptions`] to the open transaction, storing the previous
+    /// attribute values so they get restored when the transaction ends.
+    pub(crate) fn apply_tx_options(&mut self, options: ExaTxOptions) {
+        if options.snapshot != self.read_write.snapshot_transactions_enabled {
+            self.driver.needs_send = true;
+            self.driver.restore_snapshot_transactions =
+                Some(self.read_write.snapshot_transactions_enabled);
+            self.read_write.snapshot_transactions_enabled = options.snapshot;
+        }
+    }
+
     pub(crate) fn read_write(&self) -> &ExaRwAttributes<'static> {
         &self.read_write
     }
 
+    /// Sets the protocol version negotiated when logging in.
+    pub(crate) fn set_protocol_version(&mut self, protocol_version: ProtocolVersion) -> &mut Self {
+        self.driver.protocol_version = protocol_version;
+        self
+    }
+
+    fn check_protocol_version(
+        &self,
+        attribute: &'static str,
+        required: ProtocolVersion,
+    ) -> SqlxResult<()> {
+        if u8::from(self.driver.protocol_version) < u8::from(required) {
+            return Err(ExaProtocolError::UnsupportedAttribute(attribute, required).into());
+        }
+
+        Ok(())
+    }
+
+    /// Applies the provided [`ExaQueryOptions`], returning the options needed to restore the
+    /// previous attribute values.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if an option is not supported by the connection's protocol version.
+    pub(crate) fn apply_query_options(
+        &mut self,
+        options: ExaQueryOptions,
+    ) -> SqlxResult<ExaQueryOptions> {
+        if options.max_rows.is_some() {
+            self.check_protocol_version("resultSetMaxRows", Self::RESULT_SET_MAX_ROWS_VERSION)?;
+        }
+
+        let previous = ExaQueryOptions {
+            timeout: options.timeout.map(|_| self.query_timeout()),
+            fetch_size: options.fetch_size.map(|_| self.fetch_size()),
+            max_rows: options.max_rows.map(|_| self.result_set_max_rows()),
+        };
+
+        if let Some(timeout) = options.timeout {
+            self.driver.needs_send = true;
+            self.read_write.query_timeout = timeout;
+        }
+
+        if let Some(fetch_size) = options.fetch_size {
+            self.driver.fetch_size = fetch_size;
+        }
+
+        if let Some(max_rows) = options.max_rows {
+            self.driver.needs_send = true;
+            self.read_write.result_set_max_rows = Some(max_rows);
+        }
+
+        Ok(previous)
+    }
+
     pub(crate) fn update(&mut self, other: ExaAttributesOpt) {
         macro_rules! other_or_prev {
             ($kind:tt, $field:tt) => {
@@ -207,6 +310,9 @@ impl ExaAttributes {
         other_or_prev!(read_write, feedback_interval);
         other_or_prev!(read_write, numeric_characters);
         other_or_prev!(read_write, query_timeout);
+        if let Some(max_rows) = other.result_set_max_rows {
+            self.read_write.result_set_max_rows = Some(max_rows);
+        }
         other_or_prev!(read_write, snapshot_transactions_enabled);
         other_or_prev!(read_write, timestamp_utc_enabled);
         other_or_prev!(read_only, compression_enabled);
@@ -234,6 +340,9 @@ pub struct ExaRwAttributes<'a> {
     feedback_interval: u64,
     numeric_characters: Cow<'a, str>,
     query_timeout: u64,
+    // Only sent once set, as older protocol versions do not support it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result_set_max_rows: Option<u64>,
     snapshot_transactions_enabled: bool,
     timestamp_utc_enabled: bool,
 }
@@ -261,6 +370,7 @@ impl Default for ExaRwAttributes<'_> {
             feedback_interval: 1,
             numeric_characters: Cow::Owned(".,".into()),
             query_timeout: 0,
+            result_set_max_rows: None,
             snapshot_transactions_enabled: false,
             timestamp_utc_enabled: false,
         }
@@ -297,20 +407,26 @@ struct ExaDriverAttributes {
     // This is technically a read-only attribute, but Exasol doesn't seem to correctly set or even
     // return it. We therefore control it manually.
     open_transaction: bool,
+    // The snapshot transactions setting to restore once the open transaction ends.
+    restore_snapshot_transactions: Option<bool>,
     needs_send: bool,
     fetch_size: usize,
     encryption_enabled: bool,
     statement_cache_capacity: usize,
+    protocol_version: ProtocolVersion,
 }
 
 impl ExaDriverAttributes {
     fn new(fetch_size: usize, encryption_enabled: bool, statement_cache_capacity: usize) -> Self {
         Self {
             open_transaction: false,
+            restore_snapshot_transactions: None,
             needs_send: false,
             fetch_size,
             encryption_enabled,
             statement_cache_capacity,
+            // Updated once logged in.
+            protocol_version: ProtocolVersion::V1,
         }
     }
 }
@@ -334,6 +450,7 @@ pub struct ExaAttributesOpt {
     feedback_interval: Option<u64>,
     numeric_characters: Option<String>,
     query_timeout: Option<u64>,
+    result_set_max_rows: Option<u64>,
     snapshot_transactions_enabled: Option<bool>,
     timestamp_utc_enabled: Option<bool>,
     // ##########################################################
@@ -1,4 +1,8 @@
-use std::{borrow::Cow, error::Error, fmt::Display};
+use std::{
+    borrow::Cow,
+    error::Error,
+    fmt::{self, Display},
+};
 
 use serde::Deserialize;
 use sqlx_core::error::{DatabaseError, ErrorKind};
@@ -10,11 +14,140 @@ pub struct ExaDatabaseError {
     text: String,
     #[serde(rename = "sqlCode")]
     code: String,
+    /// The SQL that caused the error, if attached through [`ExaDatabaseError::with_sql`], used to
+    /// render the error position.
+    #[serde(skip)]
+    sql: Option<Box<str>>,
+}
+
+impl ExaDatabaseError {
+    /// Returns the position in the SQL statement at which the error occurred, if Exasol provided
+    /// one in the error message.
+    #[must_use]
+    pub fn position(&self) -> Option<ExaErrorPosition> {
+        ExaErrorPosition::parse(&self.text)
+    }
+
+    /// Returns a [`Display`] implementor that renders the error along with the offending line of
+    /// the provided SQL statement and a caret pointing at the error position.
+    ///
+    /// ```rust
+    /// # use sqlx_exasol_impl as sqlx_exasol;
+    /// use sqlx_exasol::ExaDatabaseError;
+    ///
+    /// # let error: ExaDatabaseError = serde_json::from_str(
+    /// #     r#"{"sqlCode":"42000","text":"syntax error, unexpected FROM_ [line 1, column 8]"}"#,
+    /// # )
+    /// # .unwrap();
+    /// let rendered = error.display_with_sql("SELECT FROM DUAL").to_string();
+    ///
+    /// assert_eq!(
+    ///     rendered,
+    ///     concat!(
+    ///         "Exasol error 42000: syntax error, unexpected FROM_ [line 1, column 8]\n",
+    ///         "1 | SELECT FROM DUAL\n",
+    ///         "  |        ^",
+    ///     )
+    /// );
+    /// ```
+    #[must_use]
+    pub fn display_with_sql<'a>(&'a self, sql: &'a str) -> impl Display + 'a {
+        ExaErrorWithSql { error: self, sql }
+    }
+
+    /// Attaches the SQL that caused the error, so that the [`Display`] implementation renders the
+    /// error position.
+    #[cfg(feature = "macros")]
+    pub(crate) fn with_sql(mut self, sql: &str) -> Self {
+        self.sql = Some(sql.into());
+        self
+    }
+
+    /// Returns the [`ExaErrorCode`] class of this error.
+    #[must_use]
+    pub fn exa_code(&self) -> ExaErrorCode {
+        ExaErrorCode::new(&self.code, &self.text)
+    }
+
+    /// Returns whether the error is caused by a transaction collision, in which case the
+    /// transaction was rolled back and can be safely re-run.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        self.exa_code() == ExaErrorCode::TransactionCollision
+    }
+
+    /// Returns whether the query was terminated because it reached the query timeout.
+    #[must_use]
+    pub fn is_timeout(&self) -> bool {
+        self.exa_code() == ExaErrorCode::QueryTimeout
+    }
 }
 
 impl Display for ExaDatabaseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Exasol error {}: {}", self.code, self.text)
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.sql.as_deref() {
+            Some(sql) => ExaErrorWithSql { error: self, sql }.fmt(f),
+            None => write!(f, "Exasol error {}: {}", self.code, self.text),
+        }
+    }
+}
+
+/// The position of an error in a SQL statement, as reported by Exasol.
+///
+/// Both the line and the column are 1-based.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExaErrorPosition {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl ExaErrorPosition {
+    const PREFIX: &str = "[line ";
+    const SEPARATOR: &str = ", column ";
+
+    /// Parses the position from error messages containing `[line <line>, column <column>]`.
+    fn parse(text: &str) -> Option<Self> {
+        let start = text.rfind(Self::PREFIX)? + Self::PREFIX.len();
+        let (position, _) = text[start..].split_once(']')?;
+        let (line, column) = position.split_once(Self::SEPARATOR)?;
+
+        Some(Self {
+            line: line.trim().parse().ok()?,
+            column: column.trim().parse().ok()?,
+        })
+    }
+}
+
+/// Helper type rendering an [`ExaDatabaseError`] along with the position of the error in the SQL
+/// statement that caused it.
+struct ExaErrorWithSql<'a> {
+    error: &'a ExaDatabaseError,
+    sql: &'a str,
+}
+
+impl Display for ExaErrorWithSql<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Exasol error {}: {}", self.error.code, self.error.text)?;
+
+        let Some(ExaErrorPosition { line, column }) = self.error.position() else {
+            return Ok(());
+        };
+
+        let Some(sql_line) = line.checked_sub(1).and_then(|i| self.sql.lines().nth(i)) else {
+            return Ok(());
+        };
+
+        let line_number = line.to_string();
+        let gutter = " ".repeat(line_number.len());
+
+        // Preserve tabs so the caret lines up with the rendered SQL line.
+        let padding = sql_line
+            .chars()
+            .take(column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+
+        write!(f, "\n{line_number} | {sql_line}\n{gutter} | {padding}^")
     }
 }
 
@@ -42,16 +175,212 @@ impl DatabaseError for ExaDatabaseError {
     }
 
     /// Uniqueness is only available to PRIMARY KEY columns in Exasol.
-    /// Additionally, there's no distinction between the PRIMARY and FOREIGN key
-    /// constraint violation codes, so we have to rely on the message as well.
     ///
     /// Furthermore, there's no CHECK constraint in Exasol.
     fn kind(&self) -> ErrorKind {
-        match self.code.as_str() {
-            "27001" => ErrorKind::NotNullViolation,
-            "42X91" if self.text.contains("primary key") => ErrorKind::UniqueViolation,
-            "42X91" if self.text.contains("foreign key") => ErrorKind::ForeignKeyViolation,
+        match self.exa_code() {
+            ExaErrorCode::NotNullViolation => ErrorKind::NotNullViolation,
+            ExaErrorCode::UniqueViolation => ErrorKind::UniqueViolation,
+            ExaErrorCode::ForeignKeyViolation => ErrorKind::ForeignKeyViolation,
             _ => ErrorKind::Other,
         }
     }
 }
+
+/// Classes of errors issued by Exasol, derived from the SQL code of an [`ExaDatabaseError`].
+///
+/// Exasol reuses some SQL codes for multiple error classes, in which case the error message is
+/// used as well to tell them apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ExaErrorCode {
+    /// The statement could not be parsed.
+    SyntaxError,
+    /// A referenced database object does not exist.
+    ObjectNotFound,
+    /// The user lacks the privileges required by the statement.
+    InsufficientPrivileges,
+    /// The transaction was rolled back due to a collision with another transaction.
+    TransactionCollision,
+    /// A numeric value is out of the range of its data type.
+    NumericOverflow,
+    /// A string value exceeds the size of its data type.
+    StringTruncation,
+    /// Any other data exception, such as invalid casts or conversions.
+    DataException,
+    /// The query was terminated because it reached the query timeout.
+    QueryTimeout,
+    /// The statement was aborted because its session or the statement itself was killed.
+    SessionKilled,
+    /// A NOT NULL constraint was violated.
+    NotNullViolation,
+    /// A PRIMARY KEY constraint was violated.
+    UniqueViolation,
+    /// A FOREIGN KEY constraint was violated.
+    ForeignKeyViolation,
+    /// An error not covered by the other classes.
+    Other,
+}
+
+impl ExaErrorCode {
+    fn new(code: &str, text: &str) -> Self {
+        match code {
+            // There's no distinction between the PRIMARY and FOREIGN key constraint violation
+            // codes.
+            "42X91" if text.contains("primary key") => Self::UniqueViolation,
+            "42X91" if text.contains("foreign key") => Self::ForeignKeyViolation,
+            // Missing objects and syntax errors share the same code.
+            "42000" if text.contains("not found") => Self::ObjectNotFound,
+            "42000" => Self::SyntaxError,
+            "42500" => Self::InsufficientPrivileges,
+            "27001" => Self::NotNullViolation,
+            "40001" => Self::TransactionCollision,
+            "22001" => Self::StringTruncation,
+            "22003" => Self::NumericOverflow,
+            // Exasol reports statements it terminated with `R` codes, which are not SQLSTATEs.
+            // These are the codes `pyexasol` maps to its `ExaQueryTimeoutError` and
+            // `ExaQueryAbortError`: `R0001` when the `QUERY_TIMEOUT` is reached and `R0003` when
+            // the execution is aborted, which is what `KILL SESSION` and `KILL STATEMENT` cause.
+            //
+            // See <https://github.com/exasol/pyexasol/blob/master/pyexasol/connection.py>.
+            "R0001" => Self::QueryTimeout,
+            "R0003" => Self::SessionKilled,
+            _ if code.starts_with("22") => Self::DataException,
+            _ => Self::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(code: &str, text: &str) -> ExaDatabaseError {
+        ExaDatabaseError {
+            text: text.to_owned(),
+            code: code.to_owned(),
+            sql: None,
+        }
+    }
+
+    #[test]
+    fn test_exa_code() {
+        let cases = [
+            (
+                "42000",
+                "syntax error, unexpected $end",
+                ExaErrorCode::SyntaxError,
+            ),
+            (
+                "42000",
+                "object TEST not found",
+                ExaErrorCode::ObjectNotFound,
+            ),
+            (
+                "42500",
+                "insufficient privileges",
+                ExaErrorCode::InsufficientPrivileges,
+            ),
+            (
+                "40001",
+                "GlobalTransactionRollback",
+                ExaErrorCode::TransactionCollision,
+            ),
+            (
+                "22003",
+                "numeric value out of range",
+                ExaErrorCode::NumericOverflow,
+            ),
+            (
+                "22001",
+                "string data, right truncation",
+                ExaErrorCode::StringTruncation,
+            ),
+            (
+                "22018",
+                "invalid character value for cast",
+                ExaErrorCode::DataException,
+            ),
+            (
+                "R0001",
+                "Query terminated because timeout has been reached.",
+                ExaErrorCode::QueryTimeout,
+            ),
+            (
+                "R0003",
+                "Client requested execution abort.",
+                ExaErrorCode::SessionKilled,
+            ),
+            ("08004", "connection rejected", ExaErrorCode::Other),
+            (
+                "27001",
+                "NOT NULL constraint violated",
+                ExaErrorCode::NotNullViolation,
+            ),
+            (
+                "42X91",
+                "primary key constraint violated",
+                ExaErrorCode::UniqueViolation,
+            ),
+            (
+                "42X91",
+                "foreign key constraint violated",
+                ExaErrorCode::ForeignKeyViolation,
+            ),
+            ("00000", "unknown", ExaErrorCode::Other),
+        ];
+
+        for (code, text, expected) in cases {
+            assert_eq!(error(code, text).exa_code(), expected, "{code}: {text}");
+        }
+    }
+
+    #[test]
+    fn test_predicates() {
+        assert!(error("40001", "GlobalTransactionRollback").is_retryable());
+        assert!(!error("40001", "GlobalTransactionRollback").is_timeout());
+        assert!(error(
+            "R0001",
+            "Query terminated because timeout has been reached."
+        )
+        .is_timeout());
+        assert!(!error("R0003", "Client requested execution abort.").is_timeout());
+        assert!(!error("42000", "syntax error").is_retryable());
+    }
+
+    #[test]
+    fn test_position() {
+        let err = error("42000", "syntax error, unexpected $end [line 3, column 17]");
+        let expected = ExaErrorPosition {
+            line: 3,
+            column: 17,
+        };
+        assert_eq!(err.position(), Some(expected));
+
+        assert_eq!(error("42000", "syntax error").position(), None);
+        assert_eq!(
+            error("42000", "invalid [line x, column 2]").position(),
+            None
+        );
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn test_display_with_sql() {
+        let sql = "SELECT 1\nFROM DUAL\nWHER 1 = 1";
+        let err = error("42000", "syntax error [line 3, column 6]");
+
+        let expected =
+            "Exasol error 42000: syntax error [line 3, column 6]\n3 | WHER 1 = 1\n  |      ^";
+
+        assert_eq!(err.display_with_sql(sql).to_string(), expected);
+        assert_eq!(err.with_sql(sql).to_string(), expected);
+    }
+
+    #[test]
+    fn test_display_with_sql_no_position() {
+        let err = error("42000", "syntax error");
+        let expected = "Exasol error 42000: syntax error";
+        assert_eq!(err.display_with_sql("SELEC 1").to_string(), expected);
+    }
+}
@@ -15,7 +15,7 @@ use std::fmt;
 
 pub use attributes::{ExaAttributes, ExaAttributesOpt, ExaRwAttributes};
 pub use describe::DescribeStatement;
-pub use error::ExaDatabaseError;
+pub use error::{ExaDatabaseError, ExaErrorCode, ExaErrorPosition};
 pub use fetch::DataChunk;
 #[cfg(feature = "etl")]
 pub use hosts::Hosts;
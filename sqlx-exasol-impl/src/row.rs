@@ -3,7 +3,10 @@ use std::{fmt::Debug, sync::Arc};
 use serde_json::Value;
 use sqlx_core::{column::ColumnIndex, database::Database, ext::ustr::UStr, row::Row, HashMap};
 
-use crate::{column::ExaColumn, database::Exasol, value::ExaValueRef, SqlxError, SqlxResult};
+use crate::{
+    column::ExaColumn, database::Exasol, types::time_zone::SessionTimeZone, value::ExaValueRef,
+    SqlxError, SqlxResult,
+};
 
 /// Struct representing a result set row. Implementor of [`Row`].
 #[derive(Debug)]
@@ -11,6 +14,8 @@ pub struct ExaRow {
     pub(crate) column_names: Arc<HashMap<UStr, usize>>,
     columns: Arc<[ExaColumn]>,
     data: Vec<Value>,
+    /// Set once the row reaches the connection, which knows the session time zone.
+    pub(crate) time_zone: Option<Arc<SessionTimeZone>>,
 }
 
 impl ExaRow {
@@ -24,6 +29,7 @@ impl ExaRow {
             column_names,
             columns,
             data,
+            time_zone: None,
         }
     }
 }
@@ -47,7 +53,11 @@ impl Row for ExaRow {
 
         let value = self.data.get(col_idx).ok_or_else(err_fn)?;
         let type_info = &self.columns.get(col_idx).ok_or_else(err_fn)?.data_type;
-        let val = ExaValueRef { value, type_info };
+        let val = ExaValueRef {
+            value,
+            type_info,
+            time_zone: self.time_zone.as_ref(),
+        };
 
         Ok(val)
     }
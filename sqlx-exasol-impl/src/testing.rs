@@ -1,19 +1,37 @@
-use std::{ops::Deref, str::FromStr, sync::OnceLock, time::Duration};
+#[cfg(feature = "etl")]
+use std::borrow::Cow;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{self, Display, Write},
+    io,
+    ops::Deref,
+    path::Path,
+    str::FromStr,
+    sync::OnceLock,
+    time::Duration,
+};
 
 use futures_util::TryStreamExt;
+use serde_json::Value as JsonValue;
 use sqlx_core::{
     connection::Connection,
     error::DatabaseError,
     executor::Executor,
+    migrate::Migrator,
     pool::{Pool, PoolOptions},
+    row::Row,
     sql_str::AssertSqlSafe,
     testing::{FixtureSnapshot, TestArgs, TestContext, TestSupport},
     Error,
 };
 
 use crate::{
-    connection::ExaConnection, database::Exasol, options::ExaConnectOptions, ExaQueryResult,
-    SqlxResult,
+    connection::ExaConnection,
+    database::Exasol,
+    migrate::{is_lock_contention, LOCK_TABLE},
+    options::ExaConnectOptions,
+    type_info::ExaDataType,
+    ExaQueryResult, SqlxError, SqlxResult,
 };
 
 static MASTER_POOL: OnceLock<Pool<Exasol>> = OnceLock::new();
@@ -80,12 +98,396 @@ impl TestSupport for Exasol {
         Ok(Some(db_names_to_delete.len()))
     }
 
+    /// Not supported, always returns an error.
+    ///
+    /// # Limitation
+    ///
+    /// The fields of [`FixtureSnapshot`] are private to `sqlx-core` and it provides no public
+    /// constructor, so no driver outside of `sqlx-core` can implement this method. Take snapshots
+    /// through [`ExaConnection::snapshot`] instead, which returns an [`ExaFixtureSnapshot`] that
+    /// can be written out as fixture files.
     async fn snapshot(_conn: &mut Self::Connection) -> SqlxResult<FixtureSnapshot<Self>> {
-        // TODO: SQLx doesn't implement this yet either.
-        todo!()
+        Err(SqlxError::InvalidArgument(
+            "`FixtureSnapshot` cannot be constructed outside of `sqlx-core`; use \
+             `ExaConnection::snapshot` instead"
+                .to_owned(),
+        ))
+    }
+}
+
+/// Snapshot of the data in the tables of a schema, created through [`ExaConnection::snapshot`].
+///
+/// The snapshot renders as `INSERT` statements, one per table row, that can be stored as fixture
+/// files and reused through the `fixtures(...)` attribute of `#[sqlx_exasol::test]`. Tables are
+/// ordered so that referenced tables are inserted before the tables referencing them.
+#[derive(Clone, Debug, Default)]
+pub struct ExaFixtureSnapshot {
+    tables: Vec<TableSnapshot>,
+}
+
+#[derive(Clone, Debug)]
+struct TableSnapshot {
+    name: String,
+    columns: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl ExaFixtureSnapshot {
+    /// Writes the snapshot to a fixture file per table in the given directory, named after the
+    /// lowercased table name and prefixed with the position of the table in the insertion order,
+    /// e.g. `00_users.sql` and `01_orders.sql`.
+    ///
+    /// The prefix keeps referenced tables sorted before the tables referencing them, so the
+    /// fixtures load when listed in the order of their file names.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if writing the files fails.
+    pub fn write_fixtures(&self, dir: impl AsRef<Path>) -> io::Result<()> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+
+        for (name, table) in self.fixture_names().zip(&self.tables) {
+            std::fs::write(dir.join(name), table.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    fn fixture_names(&self) -> impl Iterator<Item = String> + '_ {
+        let width = self.tables.len().saturating_sub(1).to_string().len().max(2);
+
+        self.tables
+            .iter()
+            .enumerate()
+            .map(move |(i, t)| format!("{i:0width$}_{}.sql", t.name.to_lowercase()))
     }
 }
 
+impl Display for ExaFixtureSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for table in &self.tables {
+            table.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for TableSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| quote_identifier(c))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        for row in &self.rows {
+            writeln!(
+                f,
+                "INSERT INTO {} ({columns}) VALUES ({});",
+                quote_identifier(&self.name),
+                row.join(", ")
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ExaConnection {
+    /// Takes a snapshot of the data in the tables of the currently open schema.
+    ///
+    /// Tables used by `sqlx` internally, such as the migrations table, are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if no schema is open or if querying the tables fails.
+    pub async fn snapshot(&mut self) -> SqlxResult<ExaFixtureSnapshot> {
+        let schema = self
+            .attributes()
+            .current_schema()
+            .ok_or_else(|| SqlxError::InvalidArgument("no schema is open".to_owned()))?
+            .to_owned();
+
+        let query = "
+            SELECT column_table, column_name
+            FROM exa_all_columns
+            WHERE column_schema = ? AND column_object_type = 'TABLE'
+            ORDER BY column_table, column_ordinal_position;";
+
+        let columns: Vec<(String, String)> = sqlx_core::query_as::query_as(query)
+            .bind(&schema)
+            .fetch_all(&mut *self)
+            .await?;
+
+        let mut tables = Vec::<TableSnapshot>::new();
+
+        for (table, column) in columns {
+            if table.starts_with("_sqlx_") {
+                continue;
+            }
+
+            match tables.last_mut() {
+                Some(t) if t.name == table => t.columns.push(column),
+                _ => tables.push(TableSnapshot {
+                    name: table,
+                    columns: vec![column],
+                    rows: Vec::new(),
+                }),
+            }
+        }
+
+        for table in &mut tables {
+            let columns = table
+                .columns
+                .iter()
+                .map(|c| quote_identifier(c))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let query = format!(
+                "SELECT {columns} FROM {}.{};",
+                quote_identifier(&schema),
+                quote_identifier(&table.name)
+            );
+
+            let rows = self.fetch_all(AssertSqlSafe(query)).await?;
+
+            for row in rows {
+                let literals = (0..row.len())
+                    .map(|i| {
+                        let value = row.try_get_raw(i)?;
+                        Ok(sql_literal(value.value, &value.type_info.data_type))
+                    })
+                    .collect::<SqlxResult<_>>()?;
+
+                table.rows.push(literals);
+            }
+        }
+
+        let query = "
+            SELECT constraint_table, referenced_table
+            FROM exa_all_constraint_columns
+            WHERE constraint_schema = ? AND constraint_type = 'FOREIGN KEY';";
+
+        let references: Vec<(String, String)> = sqlx_core::query_as::query_as(query)
+            .bind(&schema)
+            .fetch_all(&mut *self)
+            .await?;
+
+        Ok(ExaFixtureSnapshot {
+            tables: order_by_references(tables, &references),
+        })
+    }
+}
+
+/// Orders the tables so that referenced tables come before the ones referencing them, on a
+/// best-effort basis. Tables part of reference cycles are appended at the end.
+fn order_by_references(
+    mut tables: Vec<TableSnapshot>,
+    references: &[(String, String)],
+) -> Vec<TableSnapshot> {
+    let mut dependencies = HashMap::<&str, HashSet<&str>>::new();
+
+    for (table, referenced) in references {
+        // Self references do not affect the order.
+        if table != referenced {
+            dependencies.entry(table).or_default().insert(referenced);
+        }
+    }
+
+    let mut ordered = Vec::with_capacity(tables.len());
+    let mut inserted = HashSet::new();
+
+    loop {
+        let (ready, pending): (Vec<_>, Vec<_>) = tables.into_iter().partition(|t| {
+            dependencies
+                .get(t.name.as_str())
+                .is_none_or(|deps| deps.iter().all(|d| inserted.contains(*d)))
+        });
+
+        tables = pending;
+
+        if ready.is_empty() {
+            break;
+        }
+
+        inserted.extend(ready.iter().map(|t| t.name.clone()));
+        ordered.extend(ready);
+    }
+
+    ordered.extend(tables);
+    ordered
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
+
+fn quote_string(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+/// Renders a value returned by Exasol as an SQL literal of the given data type.
+fn sql_literal(value: &JsonValue, data_type: &ExaDataType) -> String {
+    let s = match value {
+        JsonValue::Null => return "NULL".to_owned(),
+        JsonValue::Bool(true) => return "TRUE".to_owned(),
+        JsonValue::Bool(false) => return "FALSE".to_owned(),
+        JsonValue::Number(n) => return n.to_string(),
+        JsonValue::String(s) => s.as_str(),
+        JsonValue::Array(_) | JsonValue::Object(_) => return quote_string(&value.to_string()),
+    };
+
+    let mut literal = String::new();
+
+    // Writing to a `String` is infallible.
+    let _ = match data_type {
+        ExaDataType::Date => write!(literal, "DATE {}", quote_string(s)),
+        ExaDataType::Timestamp { .. } | ExaDataType::TimestampWithLocalTimeZone { .. } => {
+            write!(literal, "TIMESTAMP {}", quote_string(s))
+        }
+        ExaDataType::IntervalDayToSecond {
+            precision,
+            fraction,
+        } => write!(
+            literal,
+            "INTERVAL {} DAY({precision}) TO SECOND({fraction})",
+            quote_string(s)
+        ),
+        ExaDataType::IntervalYearToMonth { precision } => write!(
+            literal,
+            "INTERVAL {} YEAR({precision}) TO MONTH",
+            quote_string(s)
+        ),
+        // Large numbers are returned as strings and get implicitly converted back.
+        ExaDataType::Boolean
+        | ExaDataType::Char { .. }
+        | ExaDataType::Decimal(_)
+        | ExaDataType::Double
+        | ExaDataType::Geometry { .. }
+        | ExaDataType::Varchar { .. }
+        | ExaDataType::HashType { .. } => write!(literal, "{}", quote_string(s)),
+    };
+
+    literal
+}
+
+/// CSV fixture embedded by `#[sqlx_exasol::test]` through the `csv_fixtures(...)` argument.
+///
+/// Not meant to be used directly.
+#[cfg(feature = "etl")]
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug)]
+pub struct ExaCsvFixture {
+    pub path: &'static str,
+    pub table: &'static str,
+    pub contents: &'static [u8],
+}
+
+/// Test function argument types that CSV fixtures can be loaded through.
+///
+/// Not meant to be used directly.
+#[cfg(feature = "etl")]
+#[doc(hidden)]
+pub trait ExaCsvFixtureTarget {
+    fn load_csv_fixtures(
+        &mut self,
+        fixtures: &[ExaCsvFixture],
+    ) -> impl Future<Output = SqlxResult<()>> + Send;
+}
+
+#[cfg(feature = "etl")]
+impl ExaCsvFixtureTarget for Pool<Exasol> {
+    async fn load_csv_fixtures(&mut self, fixtures: &[ExaCsvFixture]) -> SqlxResult<()> {
+        load_csv_fixtures(&mut *self.acquire().await?, fixtures).await
+    }
+}
+
+#[cfg(feature = "etl")]
+impl ExaCsvFixtureTarget for sqlx_core::pool::PoolConnection<Exasol> {
+    async fn load_csv_fixtures(&mut self, fixtures: &[ExaCsvFixture]) -> SqlxResult<()> {
+        load_csv_fixtures(self, fixtures).await
+    }
+}
+
+#[cfg(feature = "etl")]
+impl ExaCsvFixtureTarget for ExaConnectOptions {
+    async fn load_csv_fixtures(&mut self, fixtures: &[ExaCsvFixture]) -> SqlxResult<()> {
+        let mut conn = ExaConnection::establish(self).await?;
+        load_csv_fixtures(&mut conn, fixtures).await?;
+        conn.close().await
+    }
+}
+
+/// Loads the CSV fixtures, in order, by running an `IMPORT` job for each of them. Gzipped fixtures
+/// are decompressed before being sent to the database.
+#[cfg(feature = "etl")]
+async fn load_csv_fixtures(conn: &mut ExaConnection, fixtures: &[ExaCsvFixture]) -> SqlxResult<()> {
+    use futures_util::{future::try_join, AsyncWriteExt};
+
+    use crate::etl::ImportBuilder;
+
+    for fixture in fixtures {
+        let data = csv_fixture_data(fixture).await?;
+
+        let (query_fut, mut writers) = ImportBuilder::new(fixture.table, None)
+            .num_writers(1)
+            .build(&mut *conn)
+            .await?;
+
+        let write_fut = async move {
+            for writer in &mut writers {
+                writer.write_all(&data).await?;
+                writer.close().await?;
+            }
+
+            Ok(())
+        };
+
+        try_join(query_fut, write_fut).await.map_err(|e| {
+            SqlxError::Protocol(format!(
+                "error loading CSV fixture {} into table {}: {e}",
+                fixture.path, fixture.table
+            ))
+        })?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "etl")]
+#[cfg_attr(not(feature = "compression"), allow(clippy::unused_async))]
+async fn csv_fixture_data(fixture: &ExaCsvFixture) -> SqlxResult<Cow<'static, [u8]>> {
+    if !Path::new(fixture.path)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gz"))
+    {
+        return Ok(Cow::Borrowed(fixture.contents));
+    }
+
+    #[cfg(feature = "compression")]
+    {
+        use async_compression::futures::bufread::GzipDecoder;
+        use futures_util::AsyncReadExt;
+
+        let mut data = Vec::new();
+        GzipDecoder::new(fixture.contents)
+            .read_to_end(&mut data)
+            .await?;
+
+        Ok(Cow::Owned(data))
+    }
+
+    #[cfg(not(feature = "compression"))]
+    Err(SqlxError::Protocol(format!(
+        "gzipped CSV fixture {} requires the `compression` feature",
+        fixture.path
+    )))
+}
+
 async fn test_context(args: &TestArgs) -> Result<TestContext<Exasol>, Error> {
     let url = dotenvy::var("DATABASE_URL").expect("DATABASE_URL must be set");
     let master_opts = ExaConnectOptions::from_str(&url).expect("failed to parse DATABASE_URL");
@@ -127,6 +529,13 @@ async fn test_context(args: &TestArgs) -> Result<TestContext<Exasol>, Error> {
             db_name CLOB NOT NULL,
             test_path CLOB NOT NULL,
             created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+        );
+
+        CREATE TABLE IF NOT EXISTS "_sqlx_tests"."_sqlx_test_templates" (
+            template_name VARCHAR(128) PRIMARY KEY,
+            ready BOOLEAN NOT NULL,
+            created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+            last_used_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
         );"#,
         )
         .try_collect::<ExaQueryResult>()
@@ -173,6 +582,10 @@ async fn test_context(args: &TestArgs) -> Result<TestContext<Exasol>, Error> {
 
     eprintln!("created database {db_name}");
 
+    if let Some(migrator) = args.migrator {
+        setup_from_template(&mut conn, &master_opts, migrator, &db_name).await?;
+    }
+
     let mut connect_opts = master_pool.connect_options().deref().clone();
 
     connect_opts.schema = Some(db_name.clone());
@@ -191,6 +604,424 @@ async fn test_context(args: &TestArgs) -> Result<TestContext<Exasol>, Error> {
     })
 }
 
+/// Populates the test schema by cloning a template schema that has the migrations applied.
+///
+/// The template is migrated once and then reused for as long as the migrations do not change. If
+/// the template cannot be used, the test schema is left empty and the migrations get applied to it
+/// as usual.
+async fn setup_from_template(
+    conn: &mut ExaConnection,
+    master_opts: &ExaConnectOptions,
+    migrator: &Migrator,
+    db_name: &str,
+) -> Result<(), Error> {
+    let Some(template) = template_schema(conn, master_opts, migrator).await? else {
+        return Ok(());
+    };
+
+    if let Err(e) = clone_schema(conn, &template, db_name).await {
+        eprintln!("failed to clone template {template} into {db_name}: {e}");
+
+        let query =
+            format!(r#"DROP SCHEMA IF EXISTS "{db_name}" CASCADE; CREATE SCHEMA "{db_name}";"#);
+        conn.execute_many(AssertSqlSafe(query))
+            .try_collect::<ExaQueryResult>()
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Condition matching templates that were not used for a day, which get dropped so that they do
+/// not pile up as the migrations change.
+const UNUSED_TEMPLATE: &str = "last_used_at < ADD_DAYS(CURRENT_TIMESTAMP, -1)";
+/// Condition matching templates that are still not ready an hour after being claimed, e.g. because
+/// the test building them got killed, which get rebuilt.
+const ABANDONED_TEMPLATE: &str = "NOT ready AND created_at < ADD_HOURS(CURRENT_TIMESTAMP, -1)";
+/// Format of the template creation time, which identifies a template build.
+const TEMPLATE_CREATED_AT_FORMAT: &str = "YYYY-MM-DD HH24:MI:SS.FF3";
+
+/// Returns the name of the up to date template schema of the migrations, building it if it does
+/// not exist yet or rebuilding it if it is outdated or was abandoned.
+///
+/// Returns `None` if the template cannot be used, such as when it is still being built by another
+/// test or when the migrations act on other schemas too.
+async fn template_schema(
+    conn: &mut ExaConnection,
+    master_opts: &ExaConnectOptions,
+    migrator: &Migrator,
+) -> Result<Option<String>, Error> {
+    if !migrator.create_schemas.is_empty() || migrator.table_name.contains('.') {
+        return Ok(None);
+    }
+
+    let template = format!("_sqlx_template_{:016x}", migrations_key(migrator));
+
+    let query = format!(
+        r#"
+        SELECT ready, {ABANDONED_TEMPLATE}, TO_CHAR(created_at, '{TEMPLATE_CREATED_AT_FORMAT}')
+        FROM "_sqlx_tests"."_sqlx_test_templates"
+        WHERE template_name = ?;"#
+    );
+
+    let row: Option<(bool, bool, String)> = sqlx_core::query_as::query_as(AssertSqlSafe(query))
+        .bind(&template)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+    match row {
+        Some((true, _, created_at)) => {
+            if is_template_current(conn, &template, migrator).await? {
+                // Keeps concurrent runs from dropping the template while it gets cloned.
+                sqlx_core::query::query(
+                    r#"
+                    UPDATE "_sqlx_tests"."_sqlx_test_templates"
+                    SET last_used_at = CURRENT_TIMESTAMP
+                    WHERE template_name = ?;"#,
+                )
+                .bind(&template)
+                .execute(&mut *conn)
+                .await?;
+
+                return Ok(Some(template));
+            }
+
+            eprintln!("template {template} is outdated; rebuilding it");
+            drop_template(conn, &template, &created_at).await?;
+        }
+        Some((false, true, created_at)) => {
+            eprintln!("template {template} was abandoned; rebuilding it");
+            drop_template(conn, &template, &created_at).await?;
+        }
+        // Still being built by another test.
+        Some((false, false, _)) => return Ok(None),
+        None => (),
+    }
+
+    drop_stale_templates(conn).await?;
+
+    let claim = sqlx_core::query::query(
+        r#"INSERT INTO "_sqlx_tests"."_sqlx_test_templates" (template_name, ready) VALUES (?, FALSE);"#,
+    )
+    .bind(&template)
+    .execute(&mut *conn)
+    .await;
+
+    match claim {
+        Ok(_) => (),
+        // Another test started building the template first.
+        Err(e) if is_lock_contention(&e) => return Ok(None),
+        Err(e) => return Err(e),
+    }
+
+    if let Err(e) = build_template(conn, master_opts, migrator, &template).await {
+        eprintln!("failed to build template {template}: {e}");
+        do_template_cleanup(conn, &template).await?;
+        return Ok(None);
+    }
+
+    sqlx_core::query::query(
+        r#"UPDATE "_sqlx_tests"."_sqlx_test_templates" SET ready = TRUE WHERE template_name = ?;"#,
+    )
+    .bind(&template)
+    .execute(&mut *conn)
+    .await?;
+
+    eprintln!("created template {template}");
+
+    Ok(Some(template))
+}
+
+async fn build_template(
+    conn: &mut ExaConnection,
+    master_opts: &ExaConnectOptions,
+    migrator: &Migrator,
+    template: &str,
+) -> Result<(), Error> {
+    conn.execute(AssertSqlSafe(format!(r#"CREATE SCHEMA "{template}";"#)))
+        .await?;
+
+    let mut template_opts = master_opts.clone();
+    template_opts.schema = Some(template.to_owned());
+
+    let mut template_conn = ExaConnection::establish(&template_opts).await?;
+    migrator.run_direct(None, &mut template_conn).await?;
+    template_conn.close().await
+}
+
+/// Checks that the migrations applied to the template match the ones of the [`Migrator`].
+async fn is_template_current(
+    conn: &mut ExaConnection,
+    template: &str,
+    migrator: &Migrator,
+) -> Result<bool, Error> {
+    let query = format!(
+        r#"SELECT version, checksum FROM "{template}"."{}" WHERE success ORDER BY version;"#,
+        migrator.table_name
+    );
+
+    let applied = match sqlx_core::query_as::query_as::<_, (i64, String)>(AssertSqlSafe(query))
+        .fetch_all(&mut *conn)
+        .await
+    {
+        Ok(applied) => applied,
+        Err(Error::Database(_)) => return Ok(false),
+        Err(e) => return Err(e),
+    };
+
+    let expected = migrator
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| (m.version, hex::encode(&*m.checksum)));
+
+    Ok(applied.into_iter().eq(expected))
+}
+
+/// Drops unused and abandoned templates.
+async fn drop_stale_templates(conn: &mut ExaConnection) -> Result<(), Error> {
+    let query = format!(
+        r#"
+        SELECT template_name, TO_CHAR(created_at, '{TEMPLATE_CREATED_AT_FORMAT}')
+        FROM "_sqlx_tests"."_sqlx_test_templates"
+        WHERE {UNUSED_TEMPLATE} OR {ABANDONED_TEMPLATE};"#
+    );
+
+    let templates: Vec<(String, String)> = sqlx_core::query_as::query_as(AssertSqlSafe(query))
+        .fetch_all(&mut *conn)
+        .await?;
+
+    for (template, created_at) in templates {
+        drop_template(conn, &template, &created_at).await?;
+    }
+
+    Ok(())
+}
+
+/// Drops the template built at the given creation time.
+///
+/// Does nothing if the template got dropped or rebuilt by another test in the meantime, so that
+/// concurrent tests never drop a template that was just built.
+async fn drop_template(
+    conn: &mut ExaConnection,
+    template: &str,
+    created_at: &str,
+) -> Result<(), Error> {
+    let query = format!(
+        r#"
+        DELETE FROM "_sqlx_tests"."_sqlx_test_templates"
+        WHERE template_name = ? AND TO_CHAR(created_at, '{TEMPLATE_CREATED_AT_FORMAT}') = ?;"#
+    );
+
+    let deleted = sqlx_core::query::query(AssertSqlSafe(query))
+        .bind(template)
+        .bind(created_at)
+        .execute(&mut *conn)
+        .await?
+        .rows_affected();
+
+    if deleted > 0 {
+        let query = format!(r#"DROP SCHEMA IF EXISTS "{template}" CASCADE"#);
+        conn.execute(AssertSqlSafe(query)).await?;
+    }
+
+    Ok(())
+}
+
+async fn do_template_cleanup(conn: &mut ExaConnection, template: &str) -> Result<(), Error> {
+    let query = format!(r#"DROP SCHEMA IF EXISTS "{template}" CASCADE"#);
+    conn.execute(AssertSqlSafe(query)).await?;
+
+    sqlx_core::query::query(
+        r#"DELETE FROM "_sqlx_tests"."_sqlx_test_templates" WHERE template_name = ?;"#,
+    )
+    .bind(template)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Copies the tables of the template schema, along with their data and primary and foreign keys,
+/// to the test schema.
+async fn clone_schema(
+    conn: &mut ExaConnection,
+    template: &str,
+    db_name: &str,
+) -> Result<(), Error> {
+    // Only tables can be cloned.
+    let has_other_objects: bool = sqlx_core::query_scalar::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM exa_all_objects WHERE root_name = ? AND object_type <> \
+         'TABLE');",
+    )
+    .bind(template)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    if has_other_objects {
+        return Err(Error::Protocol(
+            "template contains objects other than tables".to_owned(),
+        ));
+    }
+
+    let tables: Vec<String> = sqlx_core::query_scalar::query_scalar(
+        "SELECT table_name FROM exa_all_tables WHERE table_schema = ?;",
+    )
+    .bind(template)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for table in tables.iter().filter(|t| *t != LOCK_TABLE) {
+        let query = format!(
+            r#"
+            CREATE TABLE "{db_name}"."{table}" LIKE "{template}"."{table}"
+            INCLUDING DEFAULTS INCLUDING IDENTITY INCLUDING COMMENTS;
+
+            INSERT INTO "{db_name}"."{table}" SELECT * FROM "{template}"."{table}";"#
+        );
+
+        conn.execute_many(AssertSqlSafe(query))
+            .try_collect::<ExaQueryResult>()
+            .await?;
+    }
+
+    let identities: Vec<(String, String, String)> = sqlx_core::query_as::query_as(
+        "
+        SELECT column_table, column_name, CAST(column_identity AS VARCHAR(40))
+        FROM exa_all_columns
+        WHERE column_schema = ? AND column_identity IS NOT NULL;",
+    )
+    .bind(template)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for (table, column, identity) in identities {
+        let query = format!(
+            r#"ALTER TABLE "{db_name}"."{table}" MODIFY COLUMN "{column}" SET IDENTITY {identity};"#
+        );
+        conn.execute(AssertSqlSafe(query)).await?;
+    }
+
+    // Primary keys must exist before the foreign keys referencing them.
+    for constraint_type in ["PRIMARY KEY", "FOREIGN KEY"] {
+        for query in constraints(conn, template, db_name, constraint_type).await? {
+            conn.execute(AssertSqlSafe(query)).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Columns of a template constraint: table, name, column, referenced table and referenced column.
+type ConstraintColumn = (String, String, String, Option<String>, Option<String>);
+
+#[derive(Debug)]
+struct Constraint {
+    table: String,
+    name: String,
+    columns: Vec<String>,
+    ref_table: Option<String>,
+    ref_columns: Vec<String>,
+}
+
+/// Returns the statements recreating the template constraints of the given type in the test
+/// schema.
+async fn constraints(
+    conn: &mut ExaConnection,
+    template: &str,
+    db_name: &str,
+    constraint_type: &str,
+) -> Result<Vec<String>, Error> {
+    let rows: Vec<ConstraintColumn> = sqlx_core::query_as::query_as(
+        "
+        SELECT constraint_table, constraint_name, column_name, referenced_table, referenced_column
+        FROM exa_all_constraint_columns
+        WHERE constraint_schema = ? AND constraint_type = ?
+        ORDER BY constraint_table, constraint_name, ordinal_position;",
+    )
+    .bind(template)
+    .bind(constraint_type)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let mut constraints = Vec::<Constraint>::new();
+
+    for (table, name, column, ref_table, ref_column) in rows {
+        let column = format!(r#""{column}""#);
+        let ref_column = ref_column.map(|c| format!(r#""{c}""#));
+
+        match constraints.last_mut() {
+            Some(c) if c.table == table && c.name == name => {
+                c.columns.push(column);
+                c.ref_columns.extend(ref_column);
+            }
+            _ => constraints.push(Constraint {
+                table,
+                name,
+                columns: vec![column],
+                ref_table,
+                ref_columns: ref_column.into_iter().collect(),
+            }),
+        }
+    }
+
+    let queries = constraints
+        .into_iter()
+        .map(|c| {
+            // System generated names are reserved.
+            let name = if c.name.starts_with("SYS_") {
+                String::new()
+            } else {
+                format!(r#"CONSTRAINT "{}" "#, c.name)
+            };
+
+            let mut query = format!(
+                r#"ALTER TABLE "{db_name}"."{}" ADD {name}{constraint_type} ({})"#,
+                c.table,
+                c.columns.join(", ")
+            );
+
+            if let Some(ref_table) = c.ref_table {
+                // Writing to a `String` is infallible.
+                let _ = write!(
+                    query,
+                    r#" REFERENCES "{db_name}"."{ref_table}" ({})"#,
+                    c.ref_columns.join(", ")
+                );
+            }
+
+            query
+        })
+        .collect();
+
+    Ok(queries)
+}
+
+/// Stable FNV-1a hash of the migrations, used to key the template schema.
+fn migrations_key(migrator: &Migrator) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+    let migrations = migrator
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration());
+
+    let bytes = migrator
+        .table_name
+        .as_bytes()
+        .iter()
+        .copied()
+        .chain(migrations.flat_map(|m| {
+            m.version
+                .to_le_bytes()
+                .into_iter()
+                .chain(m.checksum.iter().copied())
+        }));
+
+    bytes.fold(FNV_OFFSET, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
 async fn do_cleanup(conn: &mut ExaConnection, db_name: &str) -> Result<(), Error> {
     let query = format!(r#"DROP SCHEMA IF EXISTS "{db_name}" CASCADE"#);
     conn.execute(AssertSqlSafe(query)).await?;
@@ -251,3 +1082,135 @@ async fn cleanup_old_dbs(conn: &mut ExaConnection) -> Result<(), Error> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use sqlx_core::{
+        migrate::{Migration, MigrationType},
+        sql_str::SqlStr,
+    };
+
+    use super::*;
+    use crate::type_info::Charset;
+
+    fn table(name: &str) -> TableSnapshot {
+        TableSnapshot {
+            name: name.to_owned(),
+            columns: vec!["ID".to_owned()],
+            rows: vec![vec!["1".to_owned()]],
+        }
+    }
+
+    #[test]
+    fn test_sql_literals() {
+        let varchar = ExaDataType::Varchar {
+            size: 10,
+            character_set: Charset::Utf8,
+        };
+        let interval_dts = ExaDataType::IntervalDayToSecond {
+            precision: 2,
+            fraction: 3,
+        };
+        let interval_ytm = ExaDataType::IntervalYearToMonth { precision: 2 };
+
+        let cases = [
+            (json!(null), &varchar, "NULL"),
+            (json!(true), &ExaDataType::Boolean, "TRUE"),
+            (json!(1.5), &ExaDataType::Double, "1.5"),
+            (json!("it's"), &varchar, "'it''s'"),
+            (json!("2023-01-02"), &ExaDataType::Date, "DATE '2023-01-02'"),
+            (
+                json!("2023-01-02 03:04:05.678000"),
+                &ExaDataType::Timestamp { precision: None },
+                "TIMESTAMP '2023-01-02 03:04:05.678000'",
+            ),
+            (
+                json!("+01 02:03:04.500"),
+                &interval_dts,
+                "INTERVAL '+01 02:03:04.500' DAY(2) TO SECOND(3)",
+            ),
+            (
+                json!("+01-02"),
+                &interval_ytm,
+                "INTERVAL '+01-02' YEAR(2) TO MONTH",
+            ),
+        ];
+
+        for (value, data_type, expected) in cases {
+            assert_eq!(sql_literal(&value, data_type), expected);
+        }
+    }
+
+    #[test]
+    fn test_table_inserts() {
+        let table = TableSnapshot {
+            name: "MY\"TABLE".to_owned(),
+            columns: vec!["ID".to_owned(), "NAME".to_owned()],
+            rows: vec![
+                vec!["1".to_owned(), "'a'".to_owned()],
+                vec!["2".to_owned(), "NULL".to_owned()],
+            ],
+        };
+
+        let expected = "INSERT INTO \"MY\"\"TABLE\" (\"ID\", \"NAME\") VALUES (1, 'a');\nINSERT \
+                        INTO \"MY\"\"TABLE\" (\"ID\", \"NAME\") VALUES (2, NULL);\n";
+
+        assert_eq!(table.to_string(), expected);
+    }
+
+    #[test]
+    fn test_order_by_references() {
+        let tables = vec![table("A"), table("B"), table("C"), table("D")];
+        let references = [
+            ("A".to_owned(), "B".to_owned()),
+            ("B".to_owned(), "C".to_owned()),
+            ("D".to_owned(), "D".to_owned()),
+        ];
+
+        let ordered = order_by_references(tables, &references)
+            .into_iter()
+            .map(|t| t.name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(ordered, ["C", "D", "B", "A"]);
+    }
+
+    #[test]
+    fn test_fixture_names() {
+        let snapshot = ExaFixtureSnapshot {
+            tables: vec![table("USERS"), table("Orders")],
+        };
+        let names = snapshot.fixture_names().collect::<Vec<_>>();
+        assert_eq!(names, ["00_users.sql", "01_orders.sql"]);
+
+        let snapshot = ExaFixtureSnapshot {
+            tables: (0..101).map(|i| table(&format!("T{i}"))).collect(),
+        };
+        let names = snapshot.fixture_names().collect::<Vec<_>>();
+        assert_eq!(names[0], "000_t0.sql");
+        assert_eq!(names[100], "100_t100.sql");
+    }
+
+    #[test]
+    fn test_migrations_key() {
+        let migrator = |sqls: &[&'static str]| {
+            let migrations = sqls
+                .iter()
+                .zip(1..)
+                .map(|(sql, version)| {
+                    let sql = SqlStr::from_static(sql);
+                    Migration::new(version, "test".into(), MigrationType::Simple, sql, false)
+                })
+                .collect();
+
+            Migrator::with_migrations(migrations)
+        };
+
+        let key = migrations_key(&migrator(&["SELECT 1;", "SELECT 2;"]));
+
+        assert_eq!(key, migrations_key(&migrator(&["SELECT 1;", "SELECT 2;"])));
+        assert_ne!(key, migrations_key(&migrator(&["SELECT 1;", "SELECT 3;"])));
+        assert_ne!(key, migrations_key(&migrator(&["SELECT 1;"])));
+    }
+}
@@ -1,12 +1,41 @@
-use sqlx_core::{sql_str::SqlStr, transaction::TransactionManager};
+use std::{cmp, future::Future, time::Duration};
+
+use futures_core::future::BoxFuture;
+use rand::Rng;
+use sqlx_core::{
+    executor::Executor,
+    sql_str::SqlStr,
+    transaction::{Transaction, TransactionManager},
+};
 
 use crate::{
     connection::websocket::future::{Commit, Rollback, WebSocketFuture},
     database::Exasol,
     error::ExaProtocolError,
-    ExaConnection, SqlxResult,
+    ExaConnection, ExaDatabaseError, ExaPool, ExaTransaction, SqlxError, SqlxResult,
 };
 
+/// Options for starting a transaction through [`ExaConnection::begin_with_options`].
+///
+/// The attributes changed by these options only apply for the lifetime of the transaction and
+/// are restored when it gets committed or rolled back.
+///
+/// There is no read-only option, because Exasol has neither read-only transactions nor a session
+/// setting preventing writes. The driver cannot reliably tell whether an arbitrary statement, such
+/// as `EXECUTE SCRIPT`, writes data without running it, and rolling back on commit instead would
+/// silently discard the writes of a transaction that was not meant to make any. Use a database
+/// user lacking the write privileges if writes must be prevented.
+///
+/// There is no isolation level option either, because Exasol always runs transactions as
+/// serializable, which satisfies every other level, and offers no way of choosing another one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExaTxOptions {
+    /// Runs the transaction in snapshot mode, through the `snapshotTransactionsEnabled`
+    /// attribute. Snapshot transactions read a consistent snapshot of the data and avoid lock
+    /// collisions with concurrent writers.
+    pub snapshot: bool,
+}
+
 /// Implementor of [`TransactionManager`].
 #[derive(Debug, Clone, Copy)]
 pub struct ExaTransactionManager;
@@ -14,7 +43,7 @@ pub struct ExaTransactionManager;
 impl TransactionManager for ExaTransactionManager {
     type Database = Exasol;
 
-    async fn begin(conn: &mut ExaConnection, _: Option<SqlStr>) -> SqlxResult<()> {
+    async fn begin(conn: &mut ExaConnection, statement: Option<SqlStr>) -> SqlxResult<()> {
         // Exasol does not have nested transactions.
         if conn.attributes().open_transaction() {
             // A pending rollback indicates that a transaction was dropped before an explicit
@@ -30,6 +59,19 @@ impl TransactionManager for ExaTransactionManager {
         // We could eagerly start it as well, but that implies one more round-trip to the server
         // and back with no benefit.
         conn.attributes_mut().set_autocommit(false);
+
+        // With autocommit disabled, any statement is part of the transaction.
+        if let Some(statement) = statement {
+            if let Err(e) = conn.execute(statement).await {
+                // Roll back whatever the statement might have started and restore autocommit
+                // regardless, as the transaction is never handed out. The original error is the
+                // relevant one.
+                Rollback::default().future(&mut conn.ws).await.ok();
+                conn.attributes_mut().set_autocommit(true);
+                return Err(e);
+            }
+        }
+
         Ok(())
     }
 
@@ -49,3 +91,219 @@ impl TransactionManager for ExaTransactionManager {
         conn.attributes().open_transaction().into()
     }
 }
+
+/// Policy dictating how transactions that failed due to transaction collisions are retried.
+///
+/// Used by [`ExaConnection::transaction_with_retry`] and [`ExaPoolExt::transaction_with_retry`].
+/// The delay between attempts grows exponentially from `initial_backoff` up to `max_backoff`, with
+/// random jitter applied so that colliding transactions do not retry in lockstep.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExaRetryPolicy {
+    /// The maximum number of times the transaction is run, including the first attempt.
+    pub max_attempts: u32,
+    /// The upper bound of the delay before the first retry.
+    pub initial_backoff: Duration,
+    /// The upper bound of the delay between any two attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for ExaRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+impl ExaRetryPolicy {
+    /// Returns whether the transaction should be re-run after failing with the given error on the
+    /// given (1-based) attempt.
+    fn should_retry(&self, error: &SqlxError, attempt: u32) -> bool {
+        let retryable = match error {
+            SqlxError::Database(e) => e
+                .try_downcast_ref::<ExaDatabaseError>()
+                .is_some_and(ExaDatabaseError::is_retryable),
+            _ => false,
+        };
+
+        retryable && attempt < self.max_attempts
+    }
+
+    /// Returns the upper bound of the delay after the given (1-based) attempt.
+    fn backoff_bound(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        cmp::min(
+            self.initial_backoff.saturating_mul(factor),
+            self.max_backoff,
+        )
+    }
+
+    /// Waits for a random duration bounded by the exponential backoff of the given attempt.
+    async fn backoff(&self, attempt: u32) {
+        let delay = rand::thread_rng().gen_range(Duration::ZERO..=self.backoff_bound(attempt));
+
+        tracing::debug!("transaction collision on attempt {attempt}; retrying in {delay:?}");
+        sqlx_core::rt::sleep(delay).await;
+    }
+
+    /// Runs a single attempt in the given transaction, committing it on success and rolling it
+    /// back on failure.
+    async fn attempt<F, T>(mut tx: ExaTransaction<'_>, f: &mut F) -> SqlxResult<T>
+    where
+        F: for<'t> FnMut(&'t mut ExaTransaction<'_>) -> BoxFuture<'t, SqlxResult<T>>,
+    {
+        match f(&mut tx).await {
+            Ok(value) => {
+                tx.commit().await?;
+                Ok(value)
+            }
+            Err(e) => {
+                // The original error is the relevant one, even if the rollback fails too.
+                if let Err(rollback_err) = tx.rollback().await {
+                    tracing::warn!("failed to roll back transaction: {rollback_err}");
+                }
+
+                Err(e)
+            }
+        }
+    }
+}
+
+impl ExaConnection {
+    /// Runs the provided closure in a transaction, re-running it in a new transaction if it fails
+    /// due to a transaction collision, as dictated by the [`ExaRetryPolicy`].
+    ///
+    /// The transaction is committed if the closure succeeds and rolled back otherwise. Since the
+    /// closure can run multiple times, it should not have side effects outside the transaction.
+    ///
+    /// ```rust,no_run
+    /// # use sqlx_exasol_impl as sqlx_exasol;
+    /// # async fn example(conn: &mut sqlx_exasol::ExaConnection) -> sqlx_core::Result<()> {
+    /// use sqlx_exasol::ExaRetryPolicy;
+    ///
+    /// conn.transaction_with_retry(ExaRetryPolicy::default(), |tx| {
+    ///     Box::pin(async move {
+    ///         sqlx_core::query::query("UPDATE counters SET value = value + 1")
+    ///             .execute(&mut **tx)
+    ///             .await
+    ///     })
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the closure fails with a non-retryable error, if the retry attempts
+    /// are exhausted or if starting, committing or rolling back the transaction fails.
+    pub async fn transaction_with_retry<F, T>(
+        &mut self,
+        policy: ExaRetryPolicy,
+        mut f: F,
+    ) -> SqlxResult<T>
+    where
+        F: for<'t> FnMut(&'t mut ExaTransaction<'_>) -> BoxFuture<'t, SqlxResult<T>> + Send,
+        T: Send,
+    {
+        let mut attempt = 1;
+
+        loop {
+            let tx = Transaction::begin(&mut *self, None).await?;
+
+            match ExaRetryPolicy::attempt(tx, &mut f).await {
+                Err(e) if policy.should_retry(&e, attempt) => policy.backoff(attempt).await,
+                res => return res,
+            }
+
+            attempt += 1;
+        }
+    }
+}
+
+/// Extension trait providing Exasol specific functionality for [`ExaPool`].
+pub trait ExaPoolExt {
+    /// Pool variant of [`ExaConnection::transaction_with_retry`].
+    ///
+    /// Every attempt runs in a transaction on a connection acquired from the pool.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ExaConnection::transaction_with_retry`], along with connection acquisition
+    /// errors.
+    fn transaction_with_retry<F, T>(
+        &self,
+        policy: ExaRetryPolicy,
+        f: F,
+    ) -> impl Future<Output = SqlxResult<T>> + Send
+    where
+        F: for<'t> FnMut(&'t mut ExaTransaction<'_>) -> BoxFuture<'t, SqlxResult<T>> + Send,
+        T: Send;
+}
+
+impl ExaPoolExt for ExaPool {
+    async fn transaction_with_retry<F, T>(&self, policy: ExaRetryPolicy, mut f: F) -> SqlxResult<T>
+    where
+        F: for<'t> FnMut(&'t mut ExaTransaction<'_>) -> BoxFuture<'t, SqlxResult<T>> + Send,
+        T: Send,
+    {
+        let mut attempt = 1;
+
+        loop {
+            let tx = self.begin().await?;
+
+            match ExaRetryPolicy::attempt(tx, &mut f).await {
+                Err(e) if policy.should_retry(&e, attempt) => policy.backoff(attempt).await,
+                res => return res,
+            }
+
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn database_error(code: &str, text: &str) -> SqlxError {
+        let error: ExaDatabaseError =
+            serde_json::from_value(json!({ "sqlCode": code, "text": text })).unwrap();
+        SqlxError::Database(Box::new(error))
+    }
+
+    #[test]
+    fn test_should_retry() {
+        let policy = ExaRetryPolicy {
+            max_attempts: 3,
+            ..Default::default()
+        };
+        let collision = database_error("40001", "GlobalTransactionRollback");
+
+        assert!(policy.should_retry(&collision, 1));
+        assert!(policy.should_retry(&collision, 2));
+        assert!(!policy.should_retry(&collision, 3));
+
+        assert!(!policy.should_retry(&database_error("42000", "syntax error"), 1));
+        assert!(!policy.should_retry(&SqlxError::PoolTimedOut, 1));
+    }
+
+    #[test]
+    fn test_backoff_bound() {
+        let policy = ExaRetryPolicy {
+            max_attempts: 100,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_millis(300),
+        };
+
+        assert_eq!(policy.backoff_bound(1), Duration::from_millis(50));
+        assert_eq!(policy.backoff_bound(2), Duration::from_millis(100));
+        assert_eq!(policy.backoff_bound(3), Duration::from_millis(200));
+        assert_eq!(policy.backoff_bound(4), Duration::from_millis(300));
+        assert_eq!(policy.backoff_bound(100), Duration::from_millis(300));
+    }
+}
@@ -1,14 +1,24 @@
-#[allow(unused_imports)]
-use sqlx_core::{config::drivers::Config, describe::Describe, impl_type_checking};
+#[cfg(feature = "macros")]
+use sqlx_core::{config::drivers::Config, describe::Describe};
+use sqlx_core::{
+    config::macros::PreferredCrates,
+    impl_type_checking,
+    type_checking::{Error as TypeCheckingError, TypeChecking},
+};
+#[cfg(feature = "macros")]
 use sqlx_macros_core::{
     database::{CachingDescribeBlocking, DatabaseExt},
     query::QueryDriver,
 };
 
-use crate::{Exasol, SqlxResult};
+#[cfg(feature = "macros")]
+use crate::{ExaDatabaseError, SqlxError, SqlxResult};
+use crate::{ExaTypeInfo, Exasol};
 
+#[cfg(feature = "macros")]
 pub const QUERY_DRIVER: QueryDriver = QueryDriver::new::<Exasol>();
 
+#[cfg(feature = "macros")]
 impl DatabaseExt for Exasol {
     const DATABASE_PATH: &'static str = "sqlx_exasol::Exasol";
 
@@ -21,7 +31,40 @@ impl DatabaseExt for Exasol {
     ) -> SqlxResult<Describe<Self>> {
         static CACHE: CachingDescribeBlocking<Exasol> = CachingDescribeBlocking::new();
 
-        CACHE.describe(query, database_url, driver_config)
+        // Attach the query to database errors so the compile error points at the offending SQL.
+        CACHE
+            .describe(query, database_url, driver_config)
+            .map_err(|e| match e {
+                SqlxError::Database(e) => match e.try_downcast::<ExaDatabaseError>() {
+                    Ok(e) => SqlxError::Database(Box::new(e.with_sql(query))),
+                    Err(e) => SqlxError::Database(e),
+                },
+                e => e,
+            })
+    }
+}
+
+impl ExaTypeInfo {
+    /// Returns the path of the Rust type that values of this data type are decoded in by the
+    /// `query!` macros.
+    ///
+    /// The preferred crates decide between the date-time and numeric types when multiple crates
+    /// are enabled. If they are inferred and ambiguous, the same fallback as the macros is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no Rust type maps to the data type with the enabled features.
+    pub fn rust_type(
+        &self,
+        preferred_crates: &PreferredCrates,
+    ) -> Result<&'static str, TypeCheckingError> {
+        match Exasol::return_type_for_id(self, preferred_crates) {
+            Err(
+                TypeCheckingError::AmbiguousDateTimeType { fallback }
+                | TypeCheckingError::AmbiguousNumericType { fallback },
+            ) => Ok(fallback),
+            res => res,
+        }
     }
 }
 
@@ -45,6 +88,11 @@ mod sqlx_exasol {
 
             pub use crate::types::time::*;
         }
+
+        #[cfg(feature = "jiff")]
+        pub mod jiff {
+            pub use crate::types::jiff::*;
+        }
     }
 }
 
@@ -56,16 +104,47 @@ impl_type_checking!(
         i32,
         i64,
         f64,
+        f32,
         String | &str,
 
         sqlx_exasol::types::HashType,
         sqlx_exasol::types::ExaIntervalYearToMonth,
 
+        // Fallbacks for `INTERVAL DAY TO SECOND` and scaled `DECIMAL` columns when none of the
+        // date-time or numeric features are enabled, as these take precedence otherwise.
+        #[cfg(not(any(feature = "chrono", feature = "time", feature = "jiff")))]
+        sqlx_exasol::types::ExaIntervalDayToSecond,
+
+        // Integer `DECIMAL` columns too wide for `i64` keep mapping to the numeric types when
+        // their features are enabled.
+        //
+        // The unsigned integers share their type info with the signed ones and are never
+        // inferred, but can still be used through type overrides.
+        #[cfg(not(any(feature = "bigdecimal", feature = "rust_decimal")))]
+        i128,
+
+        #[cfg(not(any(feature = "bigdecimal", feature = "rust_decimal")))]
+        sqlx_exasol::types::ExaDecimal,
+
         #[cfg(feature = "uuid")]
         sqlx_exasol::types::Uuid,
 
         #[cfg(feature = "geo-types")]
         sqlx_exasol::types::geo_types::Geometry,
+
+        // `sqlx` has no `jiff` date-time types slot, so these are only used if neither `chrono`
+        // nor `time` are enabled, which take precedence otherwise.
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        sqlx_exasol::types::jiff::SignedDuration,
+
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        sqlx_exasol::types::jiff::civil::Date,
+
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        sqlx_exasol::types::jiff::civil::DateTime,
+
+        #[cfg(all(feature = "jiff", not(any(feature = "chrono", feature = "time"))))]
+        sqlx_exasol::types::jiff::Timestamp,
     },
     ParamChecking::Weak,
     feature-types: info => info.__type_feature_gate(),
@@ -98,3 +177,27 @@ impl_type_checking!(
         },
     },
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wide_integer_inference() {
+        let rust_type = |data_type: &str| {
+            data_type
+                .parse::<ExaTypeInfo>()
+                .unwrap()
+                .rust_type(&PreferredCrates::default())
+                .unwrap()
+        };
+
+        assert_eq!(rust_type("DECIMAL(18,0)"), "i64");
+
+        // The numeric types take precedence over `i128` to keep the inference unchanged.
+        #[cfg(not(any(feature = "bigdecimal", feature = "rust_decimal")))]
+        assert_eq!(rust_type("DECIMAL(30,0)"), "i128");
+        #[cfg(any(feature = "bigdecimal", feature = "rust_decimal"))]
+        assert_ne!(rust_type("DECIMAL(30,0)"), "i128");
+    }
+}
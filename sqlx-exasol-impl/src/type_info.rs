@@ -1,9 +1,14 @@
-use std::fmt::{Arguments, Display};
+use std::{
+    fmt::{Arguments, Display},
+    str::FromStr,
+};
 
 use arrayvec::ArrayString;
 use serde::{Deserialize, Serialize};
 use sqlx_core::type_info::TypeInfo;
 
+use crate::SqlxError;
+
 /// Information about an Exasol data type and implementor of [`TypeInfo`].
 // Note that the [`DataTypeName`] is automatically constructed from the provided [`ExaDataType`].
 #[derive(Debug, Clone, Copy, Deserialize)]
@@ -19,8 +24,8 @@ impl ExaTypeInfo {
     pub fn __type_feature_gate(&self) -> Option<&'static str> {
         match self.data_type {
             ExaDataType::Date
-            | ExaDataType::Timestamp
-            | ExaDataType::TimestampWithLocalTimeZone => Some("time"),
+            | ExaDataType::Timestamp { .. }
+            | ExaDataType::TimestampWithLocalTimeZone { .. } => Some("time"),
             ExaDataType::Decimal(decimal)
                 if decimal.scale > 0 || decimal.precision > Some(Decimal::MAX_64BIT_PRECISION) =>
             {
@@ -50,6 +55,16 @@ impl From<ExaDataType> for ExaTypeInfo {
     }
 }
 
+/// Parses a column data type definition the way Exasol displays it, such as in the
+/// `COLUMN_TYPE` column of `EXA_ALL_COLUMNS`, e.g. `DECIMAL(18,0)` or `VARCHAR(100) UTF8`.
+impl FromStr for ExaTypeInfo {
+    type Err = SqlxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<ExaDataType>().map(From::from)
+    }
+}
+
 impl PartialEq for ExaTypeInfo {
     fn eq(&self, other: &Self) -> bool {
         self.data_type == other.data_type
@@ -123,10 +138,21 @@ pub enum ExaDataType {
     #[serde(rename_all = "camelCase")]
     IntervalYearToMonth { precision: u32 },
     /// The TIMESTAMP data type.
-    Timestamp,
+    ///
+    /// The precision is the number of fractional second digits. It is only reported by Exasol
+    /// versions supporting `TIMESTAMP(p)` and its absence means universal compatibility.
+    Timestamp {
+        #[serde(default, alias = "fraction", skip_serializing_if = "Option::is_none")]
+        precision: Option<u32>,
+    },
     /// The TIMESTAMP WITH LOCAL TIME ZONE data type.
+    ///
+    /// The precision has the same meaning as for [`ExaDataType::Timestamp`].
     #[serde(rename = "TIMESTAMP WITH LOCAL TIME ZONE")]
-    TimestampWithLocalTimeZone,
+    TimestampWithLocalTimeZone {
+        #[serde(default, alias = "fraction", skip_serializing_if = "Option::is_none")]
+        precision: Option<u32>,
+    },
     /// The VARCHAR data type.
     #[serde(rename_all = "camelCase")]
     Varchar { size: u32, character_set: Charset },
@@ -176,6 +202,8 @@ impl ExaDataType {
     #[allow(dead_code, reason = "used by optional dependency")]
     pub(crate) const INTERVAL_DTS_MAX_PRECISION: u32 = 9;
     pub(crate) const INTERVAL_YTM_MAX_PRECISION: u32 = 9;
+    #[cfg_attr(not(test), expect(dead_code))]
+    pub(crate) const TIMESTAMP_MAX_PRECISION: u32 = 9;
     pub(crate) const VARCHAR_MAX_LEN: u32 = 2_000_000;
     #[cfg_attr(not(test), expect(dead_code))]
     pub(crate) const CHAR_MAX_LEN: u32 = 2_000;
@@ -200,8 +228,8 @@ impl ExaDataType {
             | (Self::Geometry { .. }, Self::Geometry { .. })
             | (Self::IntervalDayToSecond { .. }, Self::IntervalDayToSecond { .. })
             | (Self::IntervalYearToMonth { .. }, Self::IntervalYearToMonth { .. })
-            | (Self::Timestamp, Self::Timestamp)
-            | (Self::TimestampWithLocalTimeZone, Self::TimestampWithLocalTimeZone)
+            | (Self::Timestamp { .. }, Self::Timestamp { .. })
+            | (Self::TimestampWithLocalTimeZone { .. }, Self::TimestampWithLocalTimeZone { .. })
             | (Self::HashType { .. }, Self::HashType { .. }) => true,
             (Self::Decimal(d1), Self::Decimal(d2)) => d1.compatible(*d2),
             _ => false,
@@ -213,8 +241,14 @@ impl ExaDataType {
             Self::Boolean => Self::BOOLEAN.into(),
             Self::Date => Self::DATE.into(),
             Self::Double => Self::DOUBLE.into(),
-            Self::Timestamp => Self::TIMESTAMP.into(),
-            Self::TimestampWithLocalTimeZone => Self::TIMESTAMP_WITH_LOCAL_TIME_ZONE.into(),
+            Self::Timestamp { precision: None } => Self::TIMESTAMP.into(),
+            Self::Timestamp { precision: Some(p) } => format_args!("TIMESTAMP({p})").into(),
+            Self::TimestampWithLocalTimeZone { precision: None } => {
+                Self::TIMESTAMP_WITH_LOCAL_TIME_ZONE.into()
+            }
+            Self::TimestampWithLocalTimeZone { precision: Some(p) } => {
+                format_args!("TIMESTAMP({p}) WITH LOCAL TIME ZONE").into()
+            }
             Self::Char {
                 size,
                 character_set,
@@ -244,6 +278,120 @@ impl ExaDataType {
     }
 }
 
+impl FromStr for ExaDataType {
+    type Err = SqlxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || SqlxError::TypeNotFound {
+            type_name: s.to_owned(),
+        };
+
+        // Split the definition in the words outside parentheses and the arguments inside them,
+        // e.g. `INTERVAL DAY(2) TO SECOND(3)` becomes `INTERVAL DAY TO SECOND` and `["2", "3"]`.
+        let mut words = String::new();
+        let mut args = Vec::new();
+        let mut rest = s.trim();
+
+        while let Some((before, after)) = rest.split_once('(') {
+            let (arg, after) = after.split_once(')').ok_or_else(err)?;
+            words.push_str(before);
+            args.push(arg.trim());
+            rest = after;
+        }
+
+        words.push_str(rest);
+        let words = words.to_uppercase();
+        let words = words.split_whitespace().collect::<Vec<_>>();
+
+        let arg = |idx: usize, default: u32| -> Result<u32, SqlxError> {
+            args.get(idx)
+                .map_or(Ok(default), |a| a.parse().map_err(|_| err()))
+        };
+
+        let data_type = match words.as_slice() {
+            ["BOOLEAN"] => Self::Boolean,
+            ["DATE"] => Self::Date,
+            ["DOUBLE"] | ["DOUBLE", "PRECISION"] => Self::Double,
+            ["TIMESTAMP"] => Self::Timestamp {
+                precision: args
+                    .first()
+                    .map(|a| a.parse().map_err(|_| err()))
+                    .transpose()?,
+            },
+            ["TIMESTAMP", "WITH", "LOCAL", "TIME", "ZONE"] => Self::TimestampWithLocalTimeZone {
+                precision: args
+                    .first()
+                    .map(|a| a.parse().map_err(|_| err()))
+                    .transpose()?,
+            },
+            ["DECIMAL"] => {
+                // Exasol lists decimals as `DECIMAL(p,s)`, so the arguments are comma separated.
+                let (precision, scale) = match args.first() {
+                    Some(a) => a.split_once(',').unwrap_or((a, "0")),
+                    None => ("18", "0"),
+                };
+
+                Self::Decimal(Decimal {
+                    precision: Some(precision.trim().parse().map_err(|_| err())?),
+                    scale: scale.trim().parse().map_err(|_| err())?,
+                })
+            }
+            [name @ ("CHAR" | "VARCHAR"), charset] => {
+                let size = arg(0, 1)?;
+                let character_set = match *charset {
+                    "UTF8" => Charset::Utf8,
+                    "ASCII" => Charset::Ascii,
+                    _ => return Err(err()),
+                };
+
+                if *name == "CHAR" {
+                    Self::Char {
+                        size,
+                        character_set,
+                    }
+                } else {
+                    Self::Varchar {
+                        size,
+                        character_set,
+                    }
+                }
+            }
+            ["GEOMETRY"] => Self::Geometry {
+                srid: arg(0, 0)?.try_into().map_err(|_| err())?,
+            },
+            ["HASHTYPE"] => {
+                // The size is given either in bytes or bits, e.g. `HASHTYPE(16 BYTE)`.
+                let (size, unit) = args
+                    .first()
+                    .and_then(|a| a.split_once(' '))
+                    .unwrap_or(("16", "BYTE"));
+                let size: u16 = size.parse().map_err(|_| err())?;
+
+                let bytes = match unit.trim().to_uppercase().as_str() {
+                    "BYTE" => size,
+                    "BIT" => size / 8,
+                    _ => return Err(err()),
+                };
+
+                // We get the HEX len, which is double the byte count.
+                Self::HashType {
+                    size: Some(bytes * 2),
+                }
+            }
+            ["INTERVAL", "DAY", "TO", "SECOND"] => Self::IntervalDayToSecond {
+                precision: arg(0, 2)?,
+                fraction: arg(1, 3)?,
+            },
+            ["INTERVAL", "YEAR", "TO", "MONTH"] => Self::IntervalYearToMonth {
+                precision: arg(0, 2)?,
+            },
+            _ => return Err(err()),
+        };
+
+        Ok(data_type)
+    }
+}
+
 impl AsRef<str> for ExaDataType {
     fn as_ref(&self) -> &str {
         match self {
@@ -255,8 +403,8 @@ impl AsRef<str> for ExaDataType {
             Self::Geometry { .. } => Self::GEOMETRY,
             Self::IntervalDayToSecond { .. } => Self::INTERVAL_DAY_TO_SECOND,
             Self::IntervalYearToMonth { .. } => Self::INTERVAL_YEAR_TO_MONTH,
-            Self::Timestamp => Self::TIMESTAMP,
-            Self::TimestampWithLocalTimeZone => Self::TIMESTAMP_WITH_LOCAL_TIME_ZONE,
+            Self::Timestamp { .. } => Self::TIMESTAMP,
+            Self::TimestampWithLocalTimeZone { .. } => Self::TIMESTAMP_WITH_LOCAL_TIME_ZONE,
             Self::Varchar { .. } => Self::VARCHAR,
             Self::HashType { .. } => Self::HASHTYPE,
         }
@@ -271,7 +419,7 @@ impl AsRef<str> for ExaDataType {
 #[derive(Debug, Clone, Copy)]
 pub enum DataTypeName {
     Static(&'static str),
-    Inline(ArrayString<30>),
+    Inline(ArrayString<40>),
 }
 
 impl AsRef<str> for DataTypeName {
@@ -319,7 +467,6 @@ impl Decimal {
 
     /// Max supported values.
     pub(crate) const MAX_PRECISION: u8 = 36;
-    #[allow(dead_code)]
     pub(crate) const MAX_SCALE: u8 = 36;
 
     /// The purpose of this is to be able to tell if some [`Decimal`] fits inside another
@@ -475,19 +622,60 @@ mod tests {
 
     #[test]
     fn test_timestamp_name() {
-        let data_type = ExaDataType::Timestamp;
+        let data_type = ExaDataType::Timestamp { precision: None };
         assert_eq!(data_type.full_name().as_ref(), "TIMESTAMP");
     }
 
+    #[test]
+    fn test_max_timestamp_name() {
+        let data_type = ExaDataType::Timestamp {
+            precision: Some(ExaDataType::TIMESTAMP_MAX_PRECISION),
+        };
+        assert_eq!(
+            data_type.full_name().as_ref(),
+            format!("TIMESTAMP({})", ExaDataType::TIMESTAMP_MAX_PRECISION)
+        );
+    }
+
     #[test]
     fn test_timestamp_with_tz_name() {
-        let data_type = ExaDataType::TimestampWithLocalTimeZone;
+        let data_type = ExaDataType::TimestampWithLocalTimeZone { precision: None };
         assert_eq!(
             data_type.full_name().as_ref(),
             "TIMESTAMP WITH LOCAL TIME ZONE"
         );
     }
 
+    #[test]
+    fn test_max_timestamp_with_tz_name() {
+        let data_type = ExaDataType::TimestampWithLocalTimeZone {
+            precision: Some(ExaDataType::TIMESTAMP_MAX_PRECISION),
+        };
+        assert_eq!(
+            data_type.full_name().as_ref(),
+            format!(
+                "TIMESTAMP({}) WITH LOCAL TIME ZONE",
+                ExaDataType::TIMESTAMP_MAX_PRECISION
+            )
+        );
+    }
+
+    #[test]
+    fn test_deserialize_timestamp_precision() {
+        let data_type: ExaDataType =
+            serde_json::from_str(r#"{"type":"TIMESTAMP","withLocalTimeZone":false}"#).unwrap();
+        assert_eq!(data_type, ExaDataType::Timestamp { precision: None });
+
+        let data_type: ExaDataType = serde_json::from_str(
+            r#"{"type":"TIMESTAMP WITH LOCAL TIME ZONE","withLocalTimeZone":true,"fraction":9}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            data_type,
+            ExaDataType::TimestampWithLocalTimeZone { precision: Some(9) }
+        );
+    }
+
     #[test]
     fn test_max_varchar_name() {
         let data_type = ExaDataType::Varchar {
@@ -500,6 +688,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_data_types() {
+        let cases = [
+            ("BOOLEAN", "BOOLEAN"),
+            ("DOUBLE", "DOUBLE PRECISION"),
+            ("DECIMAL(18,0)", "DECIMAL(18, 0)"),
+            ("decimal(36, 4)", "DECIMAL(36, 4)"),
+            ("VARCHAR(100) UTF8", "VARCHAR(100) UTF8"),
+            ("CHAR(1) ASCII", "CHAR(1) ASCII"),
+            ("TIMESTAMP", "TIMESTAMP"),
+            ("TIMESTAMP(6)", "TIMESTAMP(6)"),
+            (
+                "TIMESTAMP WITH LOCAL TIME ZONE",
+                "TIMESTAMP WITH LOCAL TIME ZONE",
+            ),
+            (
+                "TIMESTAMP(9) WITH LOCAL TIME ZONE",
+                "TIMESTAMP(9) WITH LOCAL TIME ZONE",
+            ),
+            ("GEOMETRY(4326)", "GEOMETRY(4326)"),
+            ("GEOMETRY", "GEOMETRY(0)"),
+            ("HASHTYPE(16 BYTE)", "HASHTYPE(16 BYTE)"),
+            ("HASHTYPE(256 BIT)", "HASHTYPE(32 BYTE)"),
+            (
+                "INTERVAL DAY(2) TO SECOND(3)",
+                "INTERVAL DAY(2) TO SECOND(3)",
+            ),
+            ("INTERVAL YEAR(4) TO MONTH", "INTERVAL YEAR(4) TO MONTH"),
+        ];
+
+        for (definition, name) in cases {
+            let type_info = definition.parse::<ExaTypeInfo>().unwrap();
+            assert_eq!(type_info.name(), name, "parsing {definition}");
+        }
+
+        assert!("BLOB".parse::<ExaTypeInfo>().is_err());
+        assert!("VARCHAR(10) LATIN1".parse::<ExaTypeInfo>().is_err());
+    }
+
     #[test]
     fn test_max_hashbyte_name() {
         let data_type = ExaDataType::HashType {
@@ -32,6 +32,14 @@ impl<T> ExaHasArrayType for &T where T: ExaHasArrayType {}
 /// with cheaply clonable iterators since it expects the iteration elements to be references.
 /// However, care should still be taken so as not to clone expensive [`IntoIterator`] types.
 ///
+/// If the parameter arrays of a statement that does not return a result set exceed the maximum
+/// message size accepted by the database (see
+/// [`SessionInfo::max_data_message_size`](crate::SessionInfo::max_data_message_size)), the rows get
+/// split into chunks and the prepared statement is executed once for each of them, with the
+/// affected rows summed up in the returned [`ExaQueryResult`](crate::ExaQueryResult). The chunks
+/// are executed within the transaction state of the connection, so wrap the query in a transaction
+/// if the chunks must be applied atomically.
+///
 /// ```rust
 /// # use sqlx_exasol_impl as sqlx_exasol;
 /// use sqlx_exasol::types::ExaIter;
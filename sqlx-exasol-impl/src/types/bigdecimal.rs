@@ -11,7 +11,7 @@ use crate::{
     arguments::ExaBuffer,
     database::Exasol,
     type_info::{Decimal, ExaDataType, ExaTypeInfo},
-    types::ExaHasArrayType,
+    types::{ExaDecimal, ExaHasArrayType},
     value::ExaValueRef,
 };
 
@@ -45,3 +45,19 @@ impl Decode<'_, Exasol> for BigDecimal {
         <Self as Deserialize>::deserialize(value.value).map_err(From::from)
     }
 }
+
+impl TryFrom<ExaDecimal> for BigDecimal {
+    type Error = BoxDynError;
+
+    fn try_from(value: ExaDecimal) -> Result<Self, Self::Error> {
+        value.as_str().parse().map_err(From::from)
+    }
+}
+
+impl TryFrom<BigDecimal> for ExaDecimal {
+    type Error = BoxDynError;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        value.to_string().parse()
+    }
+}
@@ -11,27 +11,40 @@ use crate::{
     arguments::ExaBuffer,
     database::Exasol,
     type_info::{ExaDataType, ExaTypeInfo},
-    types::ExaHasArrayType,
+    types::{time_zone::ParamTimeZone, ExaHasArrayType},
     value::ExaValueRef,
 };
 
+/// Formats as many fractional second digits as the value needs, up to nanoseconds, and parses
+/// any number of them, so it works regardless of the column precision.
 const TIMESTAMP_FMT: &str = "%Y-%m-%d %H:%M:%S%.f";
 
 impl Type<Exasol> for NaiveDateTime {
     fn type_info() -> ExaTypeInfo {
-        ExaDataType::Timestamp.into()
+        ExaDataType::Timestamp { precision: None }.into()
     }
 }
 
 impl Type<Exasol> for DateTime<Utc> {
     fn type_info() -> ExaTypeInfo {
-        ExaDataType::Timestamp.into()
+        ExaDataType::Timestamp { precision: None }.into()
+    }
+
+    fn compatible(ty: &ExaTypeInfo) -> bool {
+        matches!(
+            ty.data_type,
+            ExaDataType::Timestamp { .. } | ExaDataType::TimestampWithLocalTimeZone { .. }
+        )
     }
 }
 
 impl Type<Exasol> for DateTime<Local> {
     fn type_info() -> ExaTypeInfo {
-        ExaDataType::TimestampWithLocalTimeZone.into()
+        ExaDataType::TimestampWithLocalTimeZone { precision: None }.into()
+    }
+
+    fn compatible(ty: &ExaTypeInfo) -> bool {
+        <DateTime<Utc> as Type<Exasol>>::compatible(ty)
     }
 }
 
@@ -56,6 +69,7 @@ impl Encode<'_, Exasol> for NaiveDateTime {
 
 impl Encode<'_, Exasol> for DateTime<Utc> {
     fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        buf.mark_utc_timestamps(ParamTimeZone::Utc);
         Encode::<Exasol>::encode(self.naive_utc(), buf)
     }
 
@@ -70,7 +84,10 @@ impl Encode<'_, Exasol> for DateTime<Utc> {
 
 impl Encode<'_, Exasol> for DateTime<Local> {
     fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
-        Encode::<Exasol>::encode(self.naive_local(), buf)
+        // Sent in UTC so that a `TIMESTAMP WITH LOCAL TIME ZONE` gets the correct instant, but
+        // converted back to the local time of the client for other parameters.
+        buf.mark_utc_timestamps(ParamTimeZone::Client);
+        Encode::<Exasol>::encode(self.naive_utc(), buf)
     }
 
     fn size_hint(&self) -> usize {
@@ -93,13 +110,20 @@ impl Decode<'_, Exasol> for NaiveDateTime {
 
 impl<'r> Decode<'r, Exasol> for DateTime<Utc> {
     fn decode(value: ExaValueRef<'r>) -> Result<Self, BoxDynError> {
-        let naive: NaiveDateTime = Decode::<Exasol>::decode(value)?;
+        let input = value.utc_timestamp()?;
+        let naive = NaiveDateTime::parse_from_str(&input, TIMESTAMP_FMT)?;
         Ok(DateTime::from_naive_utc_and_offset(naive, Utc))
     }
 }
 
 impl<'r> Decode<'r, Exasol> for DateTime<Local> {
     fn decode(value: ExaValueRef<'r>) -> Result<Self, BoxDynError> {
+        if let ExaDataType::TimestampWithLocalTimeZone { .. } = value.type_info.data_type {
+            let utc: DateTime<Utc> = Decode::<Exasol>::decode(value)?;
+            return Ok(utc.with_timezone(&Local));
+        }
+
+        // Other timestamps are in the local time of the client.
         let naive: NaiveDateTime = Decode::<Exasol>::decode(value)?;
         naive
             .and_local_timezone(Local)
@@ -0,0 +1,227 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use serde_json::Value;
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+use crate::{
+    arguments::ExaBuffer,
+    database::Exasol,
+    type_info::{Decimal, ExaDataType, ExaTypeInfo},
+    types::ExaHasArrayType,
+    value::ExaValueRef,
+};
+
+/// An exact decimal number as a representation of the `DECIMAL(p,s)` datatype.
+///
+/// The value is kept in its textual form, normalized to plain notation without leading or trailing
+/// zeros, along with the precision and scale of the smallest `DECIMAL` that can hold it. Values
+/// therefore compare equal regardless of the column scale, e.g. `1.50` and `1.5`.
+///
+/// Conversions to and from the types of the `bigdecimal` and `rust_decimal` features are provided
+/// through [`TryFrom`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExaDecimal {
+    value: String,
+    precision: u8,
+    scale: u8,
+}
+
+impl ExaDecimal {
+    /// Returns the decimal in plain notation, e.g. `-123.45`.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the total number of significant digits.
+    #[must_use]
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Returns the number of fractional digits, excluding trailing zeros.
+    #[must_use]
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+}
+
+impl Type<Exasol> for ExaDecimal {
+    fn type_info() -> ExaTypeInfo {
+        // A somewhat non-sensical value used to allow decoding any DECIMAL value.
+        ExaDataType::Decimal(Decimal {
+            precision: None,
+            scale: Decimal::MAX_SCALE,
+        })
+        .into()
+    }
+}
+
+impl ExaHasArrayType for ExaDecimal {}
+
+impl Encode<'_, Exasol> for ExaDecimal {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        buf.append(self.as_str())?;
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        // 1 quote + value + 1 quote
+        self.value.len() + 2
+    }
+}
+
+impl Decode<'_, Exasol> for ExaDecimal {
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        // Large decimals are sent as strings.
+        let numeric = match value.value {
+            Value::Number(n) => Cow::Owned(n.to_string()),
+            Value::String(s) => Cow::Borrowed(s.as_str()),
+            v => return Err(format!("invalid numeric value: {v}").into()),
+        };
+
+        numeric.parse()
+    }
+}
+
+impl Display for ExaDecimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.value)
+    }
+}
+
+/// Parses a decimal number in plain or scientific notation, such as `-123.45` or `1.2345E+2`.
+///
+/// Fails if the number does not fit in a `DECIMAL`, i.e. if it needs a precision larger than 36.
+impl FromStr for ExaDecimal {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input_err_fn = || format!("could not parse {s} as DECIMAL");
+        let range_err_fn = || format!("{s} is out of range for DECIMAL");
+
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let (mantissa, exponent) = match rest.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>()?),
+            None => (rest, 0),
+        };
+
+        let (int, fract) = match mantissa.split_once('.') {
+            Some((int, fract)) if !fract.is_empty() => (int, fract),
+            Some(_) => return Err(input_err_fn().into()),
+            None => (mantissa, ""),
+        };
+
+        if int.is_empty() || !int.bytes().chain(fract.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(input_err_fn().into());
+        }
+
+        let digits = int.trim_start_matches('0');
+        let scale = i64::try_from(fract.len())?.saturating_sub(exponent);
+
+        // Checking the range beforehand also bounds the amount of padding below.
+        if scale > i64::from(Decimal::MAX_SCALE) || scale < -i64::from(Decimal::MAX_PRECISION) {
+            return Err(range_err_fn().into());
+        }
+
+        let mut digits = format!("{digits}{fract}");
+        let scale = if scale < 0 {
+            digits.push_str(&"0".repeat(scale.unsigned_abs().try_into()?));
+            0
+        } else {
+            usize::try_from(scale)?
+        };
+
+        if digits.len() < scale {
+            let padding = "0".repeat(scale - digits.len());
+            digits.insert_str(0, &padding);
+        }
+
+        let (int, fract) = digits.split_at(digits.len() - scale);
+        let int = match int.trim_start_matches('0') {
+            "" => "0",
+            int => int,
+        };
+        let fract = fract.trim_end_matches('0');
+        let scale = fract.len();
+
+        let precision = (int.len() - usize::from(int == "0") + scale).max(1);
+        if precision > usize::from(Decimal::MAX_PRECISION) {
+            return Err(range_err_fn().into());
+        }
+
+        let is_zero = int == "0" && fract.is_empty();
+        let sign = if negative && !is_zero { "-" } else { "" };
+        let value = if fract.is_empty() {
+            format!("{sign}{int}")
+        } else {
+            format!("{sign}{int}.{fract}")
+        };
+
+        Ok(Self {
+            value,
+            precision: precision.try_into()?,
+            scale: scale.try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decimal(s: &str) -> (String, u8, u8) {
+        let decimal = s.parse::<ExaDecimal>().unwrap();
+        (decimal.to_string(), decimal.precision(), decimal.scale())
+    }
+
+    #[test]
+    fn test_decimal_parse() {
+        assert_eq!(decimal("0"), ("0".to_owned(), 1, 0));
+        assert_eq!(decimal("-0.00"), ("0".to_owned(), 1, 0));
+        assert_eq!(decimal("+00123.450"), ("123.45".to_owned(), 5, 2));
+        assert_eq!(decimal("1.0"), ("1".to_owned(), 1, 0));
+        assert_eq!(decimal("-0.05"), ("-0.05".to_owned(), 2, 2));
+        assert_eq!(decimal("1.2345E+2"), ("123.45".to_owned(), 5, 2));
+        assert_eq!(decimal("5e-3"), ("0.005".to_owned(), 3, 3));
+        assert_eq!(decimal("-12e3"), ("-12000".to_owned(), 5, 0));
+
+        let max = "9".repeat(36);
+        assert_eq!(decimal(&max), (max.clone(), 36, 0));
+        assert_eq!(decimal(&format!("0.{max}")), (format!("0.{max}"), 36, 36));
+        assert_eq!(
+            decimal(&format!("1.{}", "0".repeat(36))),
+            ("1".to_owned(), 1, 0)
+        );
+    }
+
+    #[test]
+    fn test_decimal_parse_invalid() {
+        let parse = |s: &str| s.parse::<ExaDecimal>();
+
+        assert!(parse("").is_err());
+        assert!(parse("-").is_err());
+        assert!(parse(".5").is_err());
+        assert!(parse("5.").is_err());
+        assert!(parse("1.2.3").is_err());
+        assert!(parse("1e").is_err());
+        assert!(parse("NaN").is_err());
+        assert!(parse(&"9".repeat(37)).is_err());
+        assert!(parse("1e36").is_err());
+        assert!(parse("1e-37").is_err());
+        assert!(parse("1e-9223372036854775807").is_err());
+    }
+}
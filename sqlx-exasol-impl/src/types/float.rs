@@ -21,7 +21,14 @@ impl Type<Exasol> for f64 {
     }
 }
 
+impl Type<Exasol> for f32 {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::Double.into()
+    }
+}
+
 impl ExaHasArrayType for f64 {}
+impl ExaHasArrayType for f32 {}
 
 impl Encode<'_, Exasol> for f64 {
     fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
@@ -44,6 +51,24 @@ impl Encode<'_, Exasol> for f64 {
     }
 }
 
+impl Encode<'_, Exasol> for f32 {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        // Serialized as is, without widening, to keep the shortest decimal representation.
+        if self.is_finite() {
+            buf.append(self)?;
+            Ok(IsNull::No)
+        } else {
+            buf.append(())?;
+            Ok(IsNull::Yes)
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        // 1 sign + 9 digits + 1 dot + 4 exponent
+        15
+    }
+}
+
 impl Decode<'_, Exasol> for f64 {
     fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
         match value.value {
@@ -53,3 +78,20 @@ impl Decode<'_, Exasol> for f64 {
         }
     }
 }
+
+/// Values are stored as `DOUBLE`, so decoding rounds them to the nearest [`f32`]. Values too large
+/// to be represented result in an error.
+impl Decode<'_, Exasol> for f32 {
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        let double = <f64 as Decode<Exasol>>::decode(value)?;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let float = double as f32;
+
+        if float.is_finite() {
+            Ok(float)
+        } else {
+            Err(format!("{double} is out of range for f32").into())
+        }
+    }
+}
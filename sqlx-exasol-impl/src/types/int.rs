@@ -21,6 +21,9 @@ use crate::{
 /// The ones above/under these thresholds are treated as strings.
 const NUMERIC_I64_RANGE: Range<i64> = -999_999_999_999_999_999..1_000_000_000_000_000_000;
 
+/// The largest magnitude that fits in a `DECIMAL(36, 0)`, the widest Exasol integer type.
+const MAX_DECIMAL_INT: u128 = 999_999_999_999_999_999_999_999_999_999_999_999;
+
 impl Type<Exasol> for i8 {
     fn type_info() -> ExaTypeInfo {
         ExaDataType::Decimal(Decimal {
@@ -61,10 +64,76 @@ impl Type<Exasol> for i64 {
     }
 }
 
+impl Type<Exasol> for u8 {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::Decimal(Decimal {
+            precision: Some(Decimal::MAX_8BIT_PRECISION),
+            scale: 0,
+        })
+        .into()
+    }
+}
+
+impl Type<Exasol> for u16 {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::Decimal(Decimal {
+            precision: Some(Decimal::MAX_16BIT_PRECISION),
+            scale: 0,
+        })
+        .into()
+    }
+}
+
+impl Type<Exasol> for u32 {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::Decimal(Decimal {
+            precision: Some(Decimal::MAX_32BIT_PRECISION),
+            scale: 0,
+        })
+        .into()
+    }
+}
+
+impl Type<Exasol> for u64 {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::Decimal(Decimal {
+            precision: Some(Decimal::MAX_64BIT_PRECISION),
+            scale: 0,
+        })
+        .into()
+    }
+}
+
+impl Type<Exasol> for i128 {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::Decimal(Decimal {
+            precision: Some(Decimal::MAX_PRECISION),
+            scale: 0,
+        })
+        .into()
+    }
+}
+
+impl Type<Exasol> for u128 {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::Decimal(Decimal {
+            precision: Some(Decimal::MAX_PRECISION),
+            scale: 0,
+        })
+        .into()
+    }
+}
+
 impl ExaHasArrayType for i8 {}
 impl ExaHasArrayType for i16 {}
 impl ExaHasArrayType for i32 {}
 impl ExaHasArrayType for i64 {}
+impl ExaHasArrayType for u8 {}
+impl ExaHasArrayType for u16 {}
+impl ExaHasArrayType for u32 {}
+impl ExaHasArrayType for u64 {}
+impl ExaHasArrayType for i128 {}
+impl ExaHasArrayType for u128 {}
 
 impl Encode<'_, Exasol> for i8 {
     fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
@@ -120,6 +189,104 @@ impl Encode<'_, Exasol> for i64 {
     }
 }
 
+impl Encode<'_, Exasol> for u8 {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        buf.append(self)?;
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        // max num digits
+        Decimal::MAX_8BIT_PRECISION as usize
+    }
+}
+
+impl Encode<'_, Exasol> for u16 {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        buf.append(self)?;
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        // max num digits
+        Decimal::MAX_16BIT_PRECISION as usize
+    }
+}
+
+impl Encode<'_, Exasol> for u32 {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        buf.append(self)?;
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        // max num digits
+        Decimal::MAX_32BIT_PRECISION as usize
+    }
+}
+
+impl Encode<'_, Exasol> for u64 {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        if i64::try_from(*self).is_ok_and(|n| NUMERIC_I64_RANGE.contains(&n)) {
+            buf.append(self)?;
+        } else {
+            // Large numbers get serialized as strings
+            buf.append(format_args!("{self}"))?;
+        }
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        // 1 quote + max num digits + 1 quote
+        1 + Decimal::MAX_64BIT_PRECISION as usize + 1
+    }
+}
+
+impl Encode<'_, Exasol> for i128 {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        if self.unsigned_abs() > MAX_DECIMAL_INT {
+            Err(format!("{self} is out of range for DECIMAL(36, 0)"))?;
+        }
+
+        if i64::try_from(*self).is_ok_and(|n| NUMERIC_I64_RANGE.contains(&n)) {
+            buf.append(self)?;
+        } else {
+            // Large numbers get serialized as strings
+            buf.append(format_args!("{self}"))?;
+        }
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        // 1 quote + 1 sign + max num digits + 1 quote
+        2 + Decimal::MAX_PRECISION as usize + 1
+    }
+}
+
+impl Encode<'_, Exasol> for u128 {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        if *self > MAX_DECIMAL_INT {
+            Err(format!("{self} is out of range for DECIMAL(36, 0)"))?;
+        }
+
+        if i64::try_from(*self).is_ok_and(|n| NUMERIC_I64_RANGE.contains(&n)) {
+            buf.append(self)?;
+        } else {
+            // Large numbers get serialized as strings
+            buf.append(format_args!("{self}"))?;
+        }
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        // 1 quote + max num digits + 1 quote
+        1 + Decimal::MAX_PRECISION as usize + 1
+    }
+}
+
 impl Decode<'_, Exasol> for i8 {
     fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
         <Self as Deserialize>::deserialize(value.value).map_err(From::from)
@@ -147,3 +314,89 @@ impl Decode<'_, Exasol> for i64 {
         }
     }
 }
+
+impl Decode<'_, Exasol> for u8 {
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        <Self as Deserialize>::deserialize(value.value).map_err(From::from)
+    }
+}
+
+impl Decode<'_, Exasol> for u16 {
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        <Self as Deserialize>::deserialize(value.value).map_err(From::from)
+    }
+}
+
+impl Decode<'_, Exasol> for u32 {
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        <Self as Deserialize>::deserialize(value.value).map_err(From::from)
+    }
+}
+
+impl Decode<'_, Exasol> for u64 {
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.value {
+            Value::Number(n) => <Self as Deserialize>::deserialize(n).map_err(From::from),
+            Value::String(s) => serde_json::from_str(s).map_err(From::from),
+            v => Err(format!("invalid u64 value: {v}").into()),
+        }
+    }
+}
+
+impl Decode<'_, Exasol> for i128 {
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.value {
+            Value::Number(n) => <Self as Deserialize>::deserialize(n).map_err(From::from),
+            Value::String(s) => serde_json::from_str(s).map_err(From::from),
+            v => Err(format!("invalid i128 value: {v}").into()),
+        }
+    }
+}
+
+impl Decode<'_, Exasol> for u128 {
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.value {
+            Value::Number(n) => <Self as Deserialize>::deserialize(n).map_err(From::from),
+            Value::String(s) => serde_json::from_str(s).map_err(From::from),
+            v => Err(format!("invalid u128 value: {v}").into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sqlx_core::encode::Encode;
+
+    use super::MAX_DECIMAL_INT;
+    use crate::{ExaArguments, Exasol};
+
+    fn encode<T: for<'q> Encode<'q, Exasol>>(value: &T) -> Result<String, String> {
+        let mut arg_buffer = ExaArguments::default();
+        let is_null = Encode::<Exasol>::encode_by_ref(value, &mut arg_buffer.buf)
+            .map_err(|e| e.to_string())?;
+
+        assert!(!is_null.is_null());
+        Ok(arg_buffer.buf.buffer)
+    }
+
+    #[test]
+    fn test_encode_u128_boundary() {
+        let encoded = encode(&MAX_DECIMAL_INT).unwrap();
+        assert!(encoded.contains(&MAX_DECIMAL_INT.to_string()));
+
+        let err = encode(&(MAX_DECIMAL_INT + 1)).unwrap_err();
+        assert!(err.contains("out of range"));
+        assert!(encode(&u128::MAX).is_err());
+    }
+
+    #[test]
+    fn test_encode_i128_boundary() {
+        let max = i128::try_from(MAX_DECIMAL_INT).unwrap();
+
+        assert!(encode(&max).is_ok());
+        assert!(encode(&-max).is_ok());
+        assert!(encode(&(max + 1)).is_err());
+        assert!(encode(&(-max - 1)).is_err());
+        assert!(encode(&i128::MIN).is_err());
+    }
+}
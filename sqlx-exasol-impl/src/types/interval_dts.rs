@@ -0,0 +1,313 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize};
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+use crate::{
+    arguments::ExaBuffer,
+    database::Exasol,
+    type_info::{ExaDataType, ExaTypeInfo},
+    types::ExaHasArrayType,
+    value::ExaValueRef,
+};
+
+/// A duration interval as a representation of the `INTERVAL DAY TO SECOND` datatype.
+///
+/// The duration is expressed in milliseconds, which is the maximum fractional seconds precision
+/// supported by Exasol for this datatype.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExaIntervalDayToSecond(pub i64);
+
+impl ExaIntervalDayToSecond {
+    const MILLIS_PER_SECOND: i64 = 1000;
+    const MILLIS_PER_MINUTE: i64 = 60 * Self::MILLIS_PER_SECOND;
+    const MILLIS_PER_HOUR: i64 = 60 * Self::MILLIS_PER_MINUTE;
+    const MILLIS_PER_DAY: i64 = 24 * Self::MILLIS_PER_HOUR;
+
+    /// Creates an interval from its components, which are all added up.
+    ///
+    /// The components are not required to be in range, e.g. 36 hours are 1 day and 12 hours.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval overflows an `i64` number of milliseconds. See
+    /// [`ExaIntervalDayToSecond::checked_new`] for a non-panicking alternative.
+    #[must_use]
+    pub const fn new(days: i64, hours: i64, minutes: i64, seconds: i64, millis: i64) -> Self {
+        match Self::checked_new(days, hours, minutes, seconds, millis) {
+            Some(interval) => interval,
+            None => panic!("INTERVAL DAY TO SECOND overflow"),
+        }
+    }
+
+    /// Creates an interval from its components, which are all added up, returning `None` if the
+    /// interval overflows an `i64` number of milliseconds.
+    #[must_use]
+    pub const fn checked_new(
+        days: i64,
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        millis: i64,
+    ) -> Option<Self> {
+        let components = [
+            (days, Self::MILLIS_PER_DAY),
+            (hours, Self::MILLIS_PER_HOUR),
+            (minutes, Self::MILLIS_PER_MINUTE),
+            (seconds, Self::MILLIS_PER_SECOND),
+            (millis, 1),
+        ];
+
+        let mut total: i64 = 0;
+        let mut i = 0;
+
+        while i < components.len() {
+            let (value, unit) = components[i];
+
+            let Some(value) = value.checked_mul(unit) else {
+                return None;
+            };
+            let Some(sum) = total.checked_add(value) else {
+                return None;
+            };
+
+            total = sum;
+            i += 1;
+        }
+
+        Some(Self(total))
+    }
+
+    /// Returns the number of whole days in the interval.
+    #[must_use]
+    pub const fn days(self) -> i64 {
+        self.0 / Self::MILLIS_PER_DAY
+    }
+
+    /// Returns the hours component of the interval, between -23 and 23.
+    #[must_use]
+    pub const fn hours(self) -> i64 {
+        self.0 % Self::MILLIS_PER_DAY / Self::MILLIS_PER_HOUR
+    }
+
+    /// Returns the minutes component of the interval, between -59 and 59.
+    #[must_use]
+    pub const fn minutes(self) -> i64 {
+        self.0 % Self::MILLIS_PER_HOUR / Self::MILLIS_PER_MINUTE
+    }
+
+    /// Returns the seconds component of the interval, between -59 and 59.
+    #[must_use]
+    pub const fn seconds(self) -> i64 {
+        self.0 % Self::MILLIS_PER_MINUTE / Self::MILLIS_PER_SECOND
+    }
+
+    /// Returns the milliseconds component of the interval, between -999 and 999.
+    #[must_use]
+    pub const fn millis(self) -> i64 {
+        self.0 % Self::MILLIS_PER_SECOND
+    }
+}
+
+impl Type<Exasol> for ExaIntervalDayToSecond {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::IntervalDayToSecond {
+            precision: ExaDataType::INTERVAL_DTS_MAX_PRECISION,
+            fraction: ExaDataType::INTERVAL_DTS_MAX_FRACTION,
+        }
+        .into()
+    }
+}
+
+impl ExaHasArrayType for ExaIntervalDayToSecond {}
+
+impl Encode<'_, Exasol> for ExaIntervalDayToSecond {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        buf.append(self)?;
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        // 1 quote + 1 sign + max days precision +
+        // 1 space + 2 hours + 1 column + 2 minutes + 1 column + 2 seconds +
+        // 1 dot + max milliseconds fraction +
+        // 1 quote
+        2 + ExaDataType::INTERVAL_DTS_MAX_PRECISION as usize
+            + 10
+            + ExaDataType::INTERVAL_DTS_MAX_FRACTION as usize
+            + 1
+    }
+}
+
+impl<'r> Decode<'r, Exasol> for ExaIntervalDayToSecond {
+    fn decode(value: ExaValueRef<'r>) -> Result<Self, BoxDynError> {
+        Self::deserialize(value.value).map_err(From::from)
+    }
+}
+
+/// Formats the interval as `[days] [hours]:[minutes]:[seconds].[milliseconds]`, prefixed by `-` if
+/// the interval is negative.
+impl Display for ExaIntervalDayToSecond {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.0.is_negative() { "-" } else { "" };
+        write!(
+            f,
+            "{sign}{} {:02}:{:02}:{:02}.{:03}",
+            self.days().abs(),
+            self.hours().abs(),
+            self.minutes().abs(),
+            self.seconds().abs(),
+            self.millis().abs()
+        )
+    }
+}
+
+/// Parses an interval in the format Exasol uses, i.e. `[days] [hours]:[minutes]:[seconds]`,
+/// optionally followed by up to three fractional seconds digits. The sign applies to the whole
+/// interval.
+impl FromStr for ExaIntervalDayToSecond {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input_err_fn = || format!("could not parse {s} as INTERVAL DAY TO SECOND");
+
+        let (sign, rest) = match s.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let (days, rest) = rest.split_once(' ').ok_or_else(input_err_fn)?;
+        let (hours, rest) = rest.split_once(':').ok_or_else(input_err_fn)?;
+        let (minutes, rest) = rest.split_once(':').ok_or_else(input_err_fn)?;
+        let (seconds, fraction) = rest.split_once('.').unwrap_or((rest, "0"));
+
+        let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+
+        if ![days, hours, minutes, seconds, fraction]
+            .into_iter()
+            .all(is_digits)
+            || fraction.len() > ExaDataType::INTERVAL_DTS_MAX_FRACTION as usize
+        {
+            return Err(input_err_fn().into());
+        }
+
+        let days: i64 = days.parse()?;
+        let hours: i64 = hours.parse()?;
+        let minutes: i64 = minutes.parse()?;
+        let seconds: i64 = seconds.parse()?;
+        let millis: i64 = format!("{fraction:0<3}").parse()?;
+
+        Self::checked_new(days, hours, minutes, seconds, millis)
+            .and_then(|interval| interval.0.checked_mul(sign))
+            .map(Self)
+            .ok_or_else(|| format!("INTERVAL DAY TO SECOND {s} is out of range").into())
+    }
+}
+
+impl Serialize for ExaIntervalDayToSecond {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        format_args!("{self}").serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExaIntervalDayToSecond {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Visitor;
+
+        impl de::Visitor<'_> for Visitor {
+            type Value = ExaIntervalDayToSecond;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    f,
+                    "INTERVAL DAY TO SECOND in the format [days] [hours]:[minutes]:[seconds]"
+                )
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_dts_round_trip() {
+        let interval = ExaIntervalDayToSecond::new(10, 20, 45, 50, 123);
+        assert_eq!(interval.to_string(), "10 20:45:50.123");
+        assert_eq!(
+            "10 20:45:50.123".parse::<ExaIntervalDayToSecond>().unwrap(),
+            interval
+        );
+
+        let negative = ExaIntervalDayToSecond(-interval.0);
+        assert_eq!(negative.to_string(), "-10 20:45:50.123");
+        assert_eq!(negative.days(), -10);
+        assert_eq!(negative.millis(), -123);
+        assert_eq!(
+            "-10 20:45:50.123"
+                .parse::<ExaIntervalDayToSecond>()
+                .unwrap(),
+            negative
+        );
+
+        // The sign applies even if there are no days.
+        let negative = ExaIntervalDayToSecond::new(0, -5, 0, 0, 0);
+        assert_eq!(negative.to_string(), "-0 05:00:00.000");
+        assert_eq!(
+            "-0 05:00:00".parse::<ExaIntervalDayToSecond>().unwrap(),
+            negative
+        );
+    }
+
+    #[test]
+    fn test_interval_dts_parse() {
+        let parse = |s: &str| s.parse::<ExaIntervalDayToSecond>();
+
+        assert_eq!(
+            parse("+1 2:3:4.5").unwrap(),
+            ExaIntervalDayToSecond::new(1, 2, 3, 4, 500)
+        );
+        assert_eq!(
+            parse("0 36:00:00").unwrap(),
+            ExaIntervalDayToSecond::new(1, 12, 0, 0, 0)
+        );
+
+        assert!(parse("1 02:03").is_err());
+        assert!(parse("1 02:03:04.1234").is_err());
+        assert!(parse("1 -02:03:04").is_err());
+        assert!(parse("1 02:03:04.").is_err());
+
+        let err = parse("106751991168 00:00:00").unwrap_err().to_string();
+        assert!(err.contains("out of range"));
+        assert!(parse("-0 2562047788016:00:00").is_err());
+        assert_eq!(
+            ExaIntervalDayToSecond::checked_new(i64::MAX, 0, 0, 0, 0),
+            None
+        );
+        assert_eq!(
+            ExaIntervalDayToSecond::checked_new(0, 0, 0, 0, i64::MAX),
+            Some(ExaIntervalDayToSecond(i64::MAX))
+        );
+    }
+}
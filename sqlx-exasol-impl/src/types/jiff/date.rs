@@ -0,0 +1,43 @@
+use jiff::civil::Date;
+use serde::Deserialize;
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+use crate::{
+    arguments::ExaBuffer,
+    database::Exasol,
+    type_info::{ExaDataType, ExaTypeInfo},
+    types::ExaHasArrayType,
+    value::ExaValueRef,
+};
+
+impl Type<Exasol> for Date {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::Date.into()
+    }
+}
+
+impl ExaHasArrayType for Date {}
+
+impl Encode<'_, Exasol> for Date {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        buf.append(format_args!("{self}"))?;
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        // 2 quotes + 4 year + 1 dash + 2 months + 1 dash + 2 days
+        12
+    }
+}
+
+impl Decode<'_, Exasol> for Date {
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        let input = <&str>::deserialize(value.value).map_err(Box::new)?;
+        input.parse().map_err(From::from)
+    }
+}
@@ -0,0 +1,210 @@
+use jiff::{
+    civil::DateTime,
+    tz::{AmbiguousOffset, TimeZone},
+    Timestamp, Zoned,
+};
+use serde::Deserialize;
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+use crate::{
+    arguments::ExaBuffer,
+    database::Exasol,
+    type_info::{ExaDataType, ExaTypeInfo},
+    types::{
+        time_zone::{ParamTimeZone, SessionTimeZone},
+        ExaHasArrayType,
+    },
+    value::ExaValueRef,
+};
+
+impl Type<Exasol> for DateTime {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::Timestamp { precision: None }.into()
+    }
+}
+
+impl Type<Exasol> for Timestamp {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::TimestampWithLocalTimeZone { precision: None }.into()
+    }
+
+    fn compatible(ty: &ExaTypeInfo) -> bool {
+        matches!(
+            ty.data_type,
+            ExaDataType::Timestamp { .. } | ExaDataType::TimestampWithLocalTimeZone { .. }
+        )
+    }
+}
+
+impl Type<Exasol> for Zoned {
+    fn type_info() -> ExaTypeInfo {
+        <Timestamp as Type<Exasol>>::type_info()
+    }
+
+    fn compatible(ty: &ExaTypeInfo) -> bool {
+        <Timestamp as Type<Exasol>>::compatible(ty)
+    }
+}
+
+impl ExaHasArrayType for DateTime {}
+impl ExaHasArrayType for Timestamp {}
+impl ExaHasArrayType for Zoned {}
+
+impl Encode<'_, Exasol> for DateTime {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        // Formats as many fractional second digits as the value needs, up to nanoseconds.
+        buf.append(format_args!("{}", self.strftime("%Y-%m-%d %H:%M:%S%.f")))?;
+
+        Ok(IsNull::No)
+    }
+
+    fn size_hint(&self) -> usize {
+        // 1 quote +
+        // 4 years + 1 dash + 2 months + 1 dash + 2 days +
+        // 1 space + 2 hours + 2 minutes + 2 seconds + 9 subseconds +
+        // 1 quote
+        28
+    }
+}
+
+impl Encode<'_, Exasol> for Timestamp {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        buf.mark_utc_timestamps(ParamTimeZone::Utc);
+        self.to_zoned(TimeZone::UTC).datetime().encode(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        // 1 quote +
+        // 4 years + 1 dash + 2 months + 1 dash + 2 days +
+        // 1 space + 2 hours + 2 minutes + 2 seconds + 9 subseconds +
+        // 1 quote
+        28
+    }
+}
+
+impl Encode<'_, Exasol> for Zoned {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        self.timestamp().encode(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        // 1 quote +
+        // 4 years + 1 dash + 2 months + 1 dash + 2 days +
+        // 1 space + 2 hours + 2 minutes + 2 seconds + 9 subseconds +
+        // 1 quote
+        28
+    }
+}
+
+impl Decode<'_, Exasol> for DateTime {
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        let input = <&str>::deserialize(value.value).map_err(Box::new)?;
+        input.parse().map_err(From::from)
+    }
+}
+
+impl<'r> Decode<'r, Exasol> for Timestamp {
+    fn decode(value: ExaValueRef<'r>) -> Result<Self, BoxDynError> {
+        let datetime: DateTime = Decode::<Exasol>::decode(value.clone())?;
+
+        match value.type_info.data_type {
+            ExaDataType::TimestampWithLocalTimeZone { .. } => {
+                local_to_timestamp(value.time_zone(), datetime)
+            }
+            _ => Ok(datetime.to_zoned(TimeZone::UTC)?.timestamp()),
+        }
+    }
+}
+
+impl<'r> Decode<'r, Exasol> for Zoned {
+    fn decode(value: ExaValueRef<'r>) -> Result<Self, BoxDynError> {
+        let time_zone = value.time_zone().jiff_tz()?;
+        let timestamp: Timestamp = Decode::<Exasol>::decode(value)?;
+        Ok(timestamp.to_zoned(time_zone))
+    }
+}
+
+/// Converts a timestamp rendered in the session time zone to an instant, looking the time zone
+/// rules up in the `jiff` database.
+pub(crate) fn local_to_timestamp(
+    time_zone: &SessionTimeZone,
+    datetime: DateTime,
+) -> Result<Timestamp, BoxDynError> {
+    let offset = match time_zone.jiff_tz()?.to_ambiguous_timestamp(datetime).offset() {
+        AmbiguousOffset::Unambiguous { offset } => offset,
+        // Clocks get turned back when daylight saving time ends, so the earliest offset is the
+        // daylight saving time one.
+        AmbiguousOffset::Fold { before, after } => {
+            if time_zone.ambiguous_dst() {
+                before
+            } else {
+                after
+            }
+        }
+        AmbiguousOffset::Gap { .. } => {
+            let msg = format!(
+                "{datetime} does not exist in session time zone {}",
+                time_zone.name()
+            );
+            return Err(msg.into());
+        }
+    };
+
+    Ok(offset.to_timestamp(datetime)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use serde_json::Value;
+
+    use super::*;
+
+    fn decode<T>(timestamp: &str, time_zone: &Arc<SessionTimeZone>) -> Result<T, BoxDynError>
+    where
+        T: for<'r> Decode<'r, Exasol>,
+    {
+        let value = Value::from(timestamp);
+        let type_info = ExaDataType::TimestampWithLocalTimeZone { precision: None }.into();
+
+        T::decode(ExaValueRef {
+            value: &value,
+            type_info: &type_info,
+            time_zone: Some(time_zone),
+        })
+    }
+
+    #[test]
+    fn test_decode_in_session_time_zone() {
+        let st = Arc::new(SessionTimeZone::with_behavior(
+            "EUROPE/BERLIN",
+            "INVALID SHIFT AMBIGUOUS ST",
+        ));
+        let dst = Arc::new(SessionTimeZone::with_behavior(
+            "EUROPE/BERLIN",
+            "INVALID SHIFT AMBIGUOUS DST",
+        ));
+
+        let timestamp: Timestamp = decode("2023-08-12 19:22:36.5", &st).unwrap();
+        assert_eq!(timestamp.to_string(), "2023-08-12T17:22:36.5Z");
+
+        let zoned: Zoned = decode("2023-08-12 19:22:36.5", &st).unwrap();
+        assert_eq!(zoned.datetime().to_string(), "2023-08-12T19:22:36.5");
+        assert_eq!(zoned.timestamp(), timestamp);
+
+        // Clocks were turned back from 03:00 to 02:00.
+        let timestamp: Timestamp = decode("2023-10-29 02:30:00", &st).unwrap();
+        assert_eq!(timestamp.to_string(), "2023-10-29T01:30:00Z");
+        let timestamp: Timestamp = decode("2023-10-29 02:30:00", &dst).unwrap();
+        assert_eq!(timestamp.to_string(), "2023-10-29T00:30:00Z");
+
+        // Clocks were turned forward from 02:00 to 03:00.
+        assert!(decode::<Timestamp>("2023-03-26 02:30:00", &st).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+mod date;
+mod datetime;
+mod span;
+
+use std::time::Duration as StdDuration;
+
+pub use jiff::{SignedDuration, Span, Timestamp, Zoned};
+use sqlx_core::{encode::IsNull, error::BoxDynError};
+
+#[cfg(not(feature = "chrono-tz"))]
+pub(crate) use self::datetime::local_to_timestamp;
+use crate::arguments::ExaBuffer;
+
+/// The supported `jiff::civil` types.
+pub mod civil {
+    pub use jiff::civil::{Date, DateTime};
+}
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Appends an `INTERVAL DAY TO SECOND` value given its sign and magnitude.
+fn append_interval_dts(
+    buf: &mut ExaBuffer,
+    negative: bool,
+    duration: StdDuration,
+) -> Result<IsNull, BoxDynError> {
+    let sign = if negative { "-" } else { "" };
+    let seconds = duration.as_secs();
+
+    buf.append(format_args!(
+        "{sign}{} {}:{}:{}.{:03}",
+        seconds / SECONDS_PER_DAY,
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+        duration.subsec_millis()
+    ))?;
+
+    Ok(IsNull::No)
+}
+
+/// Parses an `INTERVAL DAY TO SECOND` value into its sign and magnitude.
+fn parse_interval_dts(input: &str) -> Result<(bool, StdDuration), BoxDynError> {
+    let input_err_fn = || format!("could not parse {input} as INTERVAL DAY TO SECOND");
+
+    let (negative, rest) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    let (days, rest) = rest.split_once(' ').ok_or_else(input_err_fn)?;
+    let (hours, rest) = rest.split_once(':').ok_or_else(input_err_fn)?;
+    let (minutes, rest) = rest.split_once(':').ok_or_else(input_err_fn)?;
+    let (seconds, fraction) = rest.split_once('.').unwrap_or((rest, ""));
+
+    if fraction.len() > 9 {
+        return Err(input_err_fn().into());
+    }
+
+    let days: u64 = days.parse().map_err(Box::new)?;
+    let hours: u64 = hours.parse().map_err(Box::new)?;
+    let minutes: u64 = minutes.parse().map_err(Box::new)?;
+    let seconds: u64 = seconds.parse().map_err(Box::new)?;
+    let nanos: u32 = format!("{fraction:0<9}").parse().map_err(Box::new)?;
+
+    let seconds = days * SECONDS_PER_DAY + hours * 3600 + minutes * 60 + seconds;
+    Ok((negative, StdDuration::new(seconds, nanos)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_interval_dts() {
+        let (negative, duration) = parse_interval_dts("+1 02:03:04.500").unwrap();
+        assert!(!negative);
+        assert_eq!(duration, StdDuration::new(93_784, 500_000_000));
+
+        let (negative, duration) = parse_interval_dts("-0 12:00:00.000").unwrap();
+        assert!(negative);
+        assert_eq!(duration, StdDuration::from_secs(43_200));
+
+        let (_, duration) = parse_interval_dts("3 00:00:01").unwrap();
+        assert_eq!(duration, StdDuration::from_secs(259_201));
+
+        assert!(parse_interval_dts("3 00:01").is_err());
+        assert!(parse_interval_dts("3 00:00:01.1234567891").is_err());
+    }
+}
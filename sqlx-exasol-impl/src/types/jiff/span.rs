@@ -0,0 +1,153 @@
+use std::time::Duration as StdDuration;
+
+use jiff::{SignedDuration, Span};
+use serde::Deserialize;
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+use super::{append_interval_dts, parse_interval_dts, SECONDS_PER_DAY};
+use crate::{
+    arguments::ExaBuffer,
+    database::Exasol,
+    type_info::{ExaDataType, ExaTypeInfo},
+    types::{ExaHasArrayType, ExaIntervalYearToMonth},
+    value::ExaValueRef,
+};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+impl Type<Exasol> for SignedDuration {
+    fn type_info() -> ExaTypeInfo {
+        ExaDataType::IntervalDayToSecond {
+            precision: ExaDataType::INTERVAL_DTS_MAX_PRECISION,
+            fraction: ExaDataType::INTERVAL_DTS_MAX_FRACTION,
+        }
+        .into()
+    }
+}
+
+/// [`Span`] values with calendar units, i.e. years and months, are encoded as
+/// `INTERVAL YEAR TO MONTH` and the rest as `INTERVAL DAY TO SECOND`, with days being 24 hours
+/// long.
+impl Type<Exasol> for Span {
+    fn type_info() -> ExaTypeInfo {
+        <SignedDuration as Type<Exasol>>::type_info()
+    }
+
+    fn compatible(ty: &ExaTypeInfo) -> bool {
+        matches!(
+            ty.data_type,
+            ExaDataType::IntervalDayToSecond { .. } | ExaDataType::IntervalYearToMonth { .. }
+        )
+    }
+}
+
+impl ExaHasArrayType for SignedDuration {}
+impl ExaHasArrayType for Span {}
+
+impl Encode<'_, Exasol> for SignedDuration {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        append_interval_dts(buf, self.is_negative(), self.unsigned_abs())
+    }
+
+    fn size_hint(&self) -> usize {
+        // 1 quote + 1 sign + max days precision +
+        // 1 space + 2 hours + 1 column + 2 minutes + 1 column + 2 seconds +
+        // 1 dot + max milliseconds fraction +
+        // 1 quote
+        2 + ExaDataType::INTERVAL_DTS_MAX_PRECISION as usize
+            + 10
+            + ExaDataType::INTERVAL_DTS_MAX_FRACTION as usize
+            + 1
+    }
+}
+
+impl Encode<'_, Exasol> for Span {
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        let nanos = span_nanos(self);
+
+        if has_calendar_units(self) {
+            if nanos != 0 {
+                let msg = format!("cannot encode {self} as it mixes calendar and time units");
+                return Err(msg.into());
+            }
+
+            let months = i64::from(self.get_years()) * 12 + i64::from(self.get_months());
+            buf.append(ExaIntervalYearToMonth(months))?;
+            return Ok(IsNull::No);
+        }
+
+        let abs = nanos.unsigned_abs();
+        let seconds = u64::try_from(abs / NANOS_PER_SECOND)?;
+        let subsec_nanos = u32::try_from(abs % NANOS_PER_SECOND)?;
+        append_interval_dts(
+            buf,
+            nanos.is_negative(),
+            StdDuration::new(seconds, subsec_nanos),
+        )
+    }
+
+    fn produces(&self) -> Option<ExaTypeInfo> {
+        has_calendar_units(self).then(<ExaIntervalYearToMonth as Type<Exasol>>::type_info)
+    }
+
+    fn size_hint(&self) -> usize {
+        // The `INTERVAL DAY TO SECOND` size hint is the larger one.
+        SignedDuration::ZERO.size_hint()
+    }
+}
+
+impl<'r> Decode<'r, Exasol> for SignedDuration {
+    fn decode(value: ExaValueRef<'r>) -> Result<Self, BoxDynError> {
+        let input = <&str>::deserialize(value.value).map_err(Box::new)?;
+        let (negative, duration) = parse_interval_dts(input)?;
+        let duration = SignedDuration::try_from(duration)?;
+        Ok(if negative { -duration } else { duration })
+    }
+}
+
+impl<'r> Decode<'r, Exasol> for Span {
+    fn decode(value: ExaValueRef<'r>) -> Result<Self, BoxDynError> {
+        if let ExaDataType::IntervalYearToMonth { .. } = value.type_info.data_type {
+            let ExaIntervalYearToMonth(months) = Decode::<Exasol>::decode(value)?;
+            return Ok(Span::new()
+                .try_years(months / 12)?
+                .try_months(months % 12)?);
+        }
+
+        let input = <&str>::deserialize(value.value).map_err(Box::new)?;
+        let (negative, duration) = parse_interval_dts(input)?;
+        let seconds = duration.as_secs();
+
+        let span = Span::new()
+            .try_days(i64::try_from(seconds / SECONDS_PER_DAY)?)?
+            .try_hours(i64::try_from(seconds / 3600 % 24)?)?
+            .try_minutes(i64::try_from(seconds / 60 % 60)?)?
+            .try_seconds(i64::try_from(seconds % 60)?)?
+            .try_nanoseconds(i64::from(duration.subsec_nanos()))?;
+
+        Ok(if negative { span.negate() } else { span })
+    }
+}
+
+fn has_calendar_units(span: &Span) -> bool {
+    span.get_years() != 0 || span.get_months() != 0
+}
+
+/// Returns the length of the uniform units of a [`Span`], considering days 24 hours long.
+fn span_nanos(span: &Span) -> i128 {
+    let days = i128::from(span.get_weeks()) * 7 + i128::from(span.get_days());
+    let seconds = days * i128::from(SECONDS_PER_DAY)
+        + i128::from(span.get_hours()) * 3600
+        + i128::from(span.get_minutes()) * 60
+        + i128::from(span.get_seconds());
+
+    seconds * 1_000_000_000
+        + i128::from(span.get_milliseconds()) * 1_000_000
+        + i128::from(span.get_microseconds()) * 1_000
+        + i128::from(span.get_nanoseconds())
+}
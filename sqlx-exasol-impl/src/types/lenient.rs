@@ -0,0 +1,253 @@
+use std::borrow::Cow;
+
+use serde_json::Value;
+use sqlx_core::{
+    decode::Decode,
+    encode::{Encode, IsNull},
+    error::BoxDynError,
+    types::Type,
+};
+
+use crate::{
+    arguments::ExaBuffer,
+    database::Exasol,
+    type_info::{ExaDataType, ExaTypeInfo},
+    types::ExaHasArrayType,
+    value::ExaValueRef,
+};
+
+/// Newtype that opts into decoding numeric types from any `DECIMAL` or `DOUBLE` column.
+///
+/// Decoding numbers is strict by default: a column is only accepted if its data type fits the
+/// Rust type, regardless of the actual values. [`Lenient`] instead checks the values themselves:
+/// - integers can be decoded from any `DECIMAL` or `DOUBLE` value that is integral and in range,
+///   such as `DECIMAL(10,2)` values with zero fractional digits, and fail otherwise;
+/// - [`f32`] and [`f64`] can be decoded from any `DECIMAL` or `DOUBLE` value, which gets rounded to
+///   the nearest representable float.
+///
+/// Encoding is the same as for the wrapped type.
+///
+/// ```rust,no_run
+/// # use sqlx_exasol_impl as sqlx_exasol;
+/// use sqlx_core::{error::BoxDynError, query_scalar::query_scalar};
+/// use sqlx_exasol::{types::Lenient, ExaConnection};
+///
+/// # async fn example(conn: &mut ExaConnection) -> Result<(), BoxDynError> {
+/// // `price` is a `DECIMAL(18,2)` column.
+/// let Lenient(price): Lenient<f64> = query_scalar("SELECT price FROM products")
+///     .fetch_one(conn)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lenient<T>(pub T);
+
+/// Numeric types that can be decoded through [`Lenient`].
+pub trait LenientNumber: Sized {
+    /// Converts a numeric value, as formatted by Exasol, to this type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value cannot be converted.
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError>;
+}
+
+impl<T> Type<Exasol> for Lenient<T>
+where
+    T: Type<Exasol>,
+{
+    fn type_info() -> ExaTypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &ExaTypeInfo) -> bool {
+        matches!(ty.data_type, ExaDataType::Decimal(_) | ExaDataType::Double)
+    }
+}
+
+impl<T> ExaHasArrayType for Lenient<T> where T: ExaHasArrayType {}
+
+impl<'q, T> Encode<'q, Exasol> for Lenient<T>
+where
+    T: Encode<'q, Exasol>,
+{
+    fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
+        self.0.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
+
+impl<T> Decode<'_, Exasol> for Lenient<T>
+where
+    T: LenientNumber,
+{
+    fn decode(value: ExaValueRef<'_>) -> Result<Self, BoxDynError> {
+        // Large decimals are sent as strings.
+        let numeric = match value.value {
+            Value::Number(n) => Cow::Owned(n.to_string()),
+            Value::String(s) => Cow::Borrowed(s.as_str()),
+            v => return Err(format!("invalid numeric value: {v}").into()),
+        };
+
+        T::from_numeric(&numeric).map(Lenient)
+    }
+}
+
+impl LenientNumber for i8 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        integer(numeric)
+    }
+}
+
+impl LenientNumber for i16 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        integer(numeric)
+    }
+}
+
+impl LenientNumber for i32 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        integer(numeric)
+    }
+}
+
+impl LenientNumber for i64 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        integer(numeric)
+    }
+}
+
+impl LenientNumber for i128 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        integer(numeric)
+    }
+}
+
+impl LenientNumber for u8 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        integer(numeric)
+    }
+}
+
+impl LenientNumber for u16 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        integer(numeric)
+    }
+}
+
+impl LenientNumber for u32 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        integer(numeric)
+    }
+}
+
+impl LenientNumber for u64 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        integer(numeric)
+    }
+}
+
+impl LenientNumber for u128 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        integer(numeric)
+    }
+}
+
+impl LenientNumber for f32 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        let float = numeric.parse::<Self>()?;
+
+        if float.is_finite() {
+            Ok(float)
+        } else {
+            Err(format!("{numeric} is out of range for f32").into())
+        }
+    }
+}
+
+impl LenientNumber for f64 {
+    fn from_numeric(numeric: &str) -> Result<Self, BoxDynError> {
+        Ok(numeric.parse::<Self>()?)
+    }
+}
+
+/// Converts an integral numeric value to an integer, failing if it has a fractional part or if it
+/// is out of range.
+fn integer<T>(numeric: &str) -> Result<T, BoxDynError>
+where
+    T: TryFrom<i128>,
+{
+    let out_of_range = || {
+        format!(
+            "{numeric} is out of range for {}",
+            std::any::type_name::<T>()
+        )
+    };
+
+    // Doubles can be formatted in scientific notation.
+    let integer = if numeric.contains(['e', 'E']) {
+        let float = numeric.parse::<f64>()?;
+
+        // The bounds are powers of two, so they are exact.
+        #[allow(clippy::cast_precision_loss)]
+        let in_range = (i128::MIN as f64..i128::MAX as f64).contains(&float);
+
+        if float.fract() != 0.0 {
+            return Err(format!("{numeric} is not an integer").into());
+        } else if !in_range {
+            return Err(out_of_range().into());
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let integer = float as i128;
+        integer
+    } else {
+        let (integral, fractional) = numeric.split_once('.').unwrap_or((numeric, ""));
+
+        if fractional.bytes().any(|b| b != b'0') {
+            return Err(format!("{numeric} is not an integer").into());
+        }
+
+        integral.parse::<i128>().map_err(|_| out_of_range())?
+    };
+
+    T::try_from(integer).map_err(|_| out_of_range().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(integer::<i64>("42").unwrap(), 42);
+        assert_eq!(integer::<i64>("-42.00").unwrap(), -42);
+        assert_eq!(integer::<u32>("1e3").unwrap(), 1000);
+        assert_eq!(
+            integer::<i128>("-123456789012345678901234567890.0").unwrap(),
+            -123_456_789_012_345_678_901_234_567_890
+        );
+
+        assert!(integer::<i64>("4.2").is_err());
+        assert!(integer::<i64>("4.2e-1").is_err());
+        assert!(integer::<u8>("256").is_err());
+        assert!(integer::<u64>("-1").is_err());
+        assert!(integer::<i64>("1e30").is_err());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_float() {
+        assert_eq!(f64::from_numeric("12.34").unwrap(), 12.34);
+        assert_eq!(
+            f64::from_numeric("123456789012345678901234567890").unwrap(),
+            1.234_567_890_123_456_8e29
+        );
+        assert_eq!(f32::from_numeric("1.5e-7").unwrap(), 1.5e-7);
+        assert!(f32::from_numeric("1e39").is_err());
+    }
+}
@@ -1,14 +1,29 @@
 //! # Supported types
 //!
-//! | Rust type                 | Exasol type                                   |
-//! | :------------------------ | :-------------------------------------------- |
-//! | `bool`                    | `BOOLEAN`                                     |
-//! | `i8`, `i16`, `i32`, `i64` | `DECIMAL`                                     |
-//! | `f64`                     | `DOUBLE`                                      |
-//! | `String`, `&str`          | `CHAR(n) ASCII/UTF8`, `VARCHAR(n) ASCII/UTF8` |
-//! | `ExaIntervalYearToMonth`  | `INTERVAL YEAR TO MONTH`                      |
-//! | `HashType`                | `HASHTYPE`                                    |
-//! | `Option<T>`               | `T` (for any `T` that implements `Type`)      |
+//! | Rust type                         | Exasol type                                   |
+//! | :-------------------------------- | :-------------------------------------------- |
+//! | `bool`                            | `BOOLEAN`                                     |
+//! | `i8`, `i16`, `i32`, `i64`, `i128` | `DECIMAL`                                     |
+//! | `u8`, `u16`, `u32`, `u64`, `u128` | `DECIMAL`                                     |
+//! | `f32`, `f64`                      | `DOUBLE`                                      |
+//! | `String`, `&str`                  | `CHAR(n) ASCII/UTF8`, `VARCHAR(n) ASCII/UTF8` |
+//! | `ExaIntervalDayToSecond`          | `INTERVAL DAY TO SECOND`                      |
+//! | `ExaIntervalYearToMonth`          | `INTERVAL YEAR TO MONTH`                      |
+//! | `ExaDecimal`                      | `DECIMAL(p,s)`                                |
+//! | `HashType`                        | `HASHTYPE`                                    |
+//! | `Option<T>`                       | `T` (for any `T` that implements `Type`)      |
+//! | `Lenient<T>`                      | `DECIMAL`, `DOUBLE` (for numeric `T`)         |
+//!
+//! `i128` and `u128` map to `DECIMAL(36,0)`, the widest integer type Exasol offers, so encoding a
+//! value with more than 36 digits fails with an out of range error.
+//! 
+//! Numeric types are decoded strictly, meaning the column data type must fit the Rust type. Wrap
+//! them in [`Lenient`] to decode any `DECIMAL` or `DOUBLE` value that can be converted, such as
+//! an integral `DECIMAL(10,2)` value into an `i64` or a `DECIMAL(18,2)` value into an `f64`.
+//!
+//! [`ExaIntervalDayToSecond`] and [`ExaDecimal`] need no optional dependencies. The latter keeps
+//! the exact textual value along with its precision and scale, and converts to and from the
+//! decimal types of the `bigdecimal` and `rust_decimal` features.
 //!
 //! ## `chrono` feature
 //!
@@ -26,6 +41,41 @@
 //! | `time::PrimitiveDateTime` | `TIMESTAMP`              |
 //! | `time::Duration`          | `INTERVAL DAY TO SECOND` |
 //!
+//! ## `jiff` feature
+//!
+//! | Rust type               | Exasol type                                        |
+//! | :---------------------- | :------------------------------------------------- |
+//! | `jiff::civil::Date`     | `DATE`                                             |
+//! | `jiff::civil::DateTime` | `TIMESTAMP`                                        |
+//! | `jiff::Timestamp`       | `TIMESTAMP WITH LOCAL TIME ZONE`                   |
+//! | `jiff::Zoned`           | `TIMESTAMP WITH LOCAL TIME ZONE`                   |
+//! | `jiff::SignedDuration`  | `INTERVAL DAY TO SECOND`                           |
+//! | `jiff::Span`            | `INTERVAL DAY TO SECOND`, `INTERVAL YEAR TO MONTH` |
+//!
+//! `jiff::Timestamp` and `jiff::Zoned` values are decoded using the session time zone, which is
+//! looked up in the time zone database of the system, and `jiff::Zoned` values are also returned in
+//! it. A `jiff::Span` is encoded as `INTERVAL YEAR TO MONTH` if it only has
+//! calendar units, i.e. years and months, and as `INTERVAL DAY TO SECOND` otherwise, with days
+//! being 24 hours long. Spans mixing both are rejected.
+//!
+//! Timestamps of any precision, i.e. `TIMESTAMP(p)`, are supported. Values are sent with as many
+//! fractional digits as they need and Exasol rounds them to the column precision. However, Exasol
+//! renders timestamps according to the `NLS_TIMESTAMP_FORMAT` session parameter, which only has
+//! microseconds by default. The `nanosecond-timestamps` connection option makes the session
+//! render nanoseconds instead, which also affects how timestamps get converted to strings in
+//! SQL, such as by `TO_CHAR`.
+//!
+//! Timezone aware types, such as `chrono::DateTime<Utc>` and `time::OffsetDateTime`, are
+//! exchanged in UTC. Since Exasol renders and parses `TIMESTAMP WITH LOCAL TIME ZONE` values in the
+//! session time zone, these get converted from or to UTC using the time zone rules from the
+//! `chrono-tz` feature, or from the `jiff` one if only that is enabled. `jiff` types are always
+//! decoded with the `jiff` rules. Ambiguous local timestamps are decoded according to the
+//! `AMBIGUOUS` part of the `TIME_ZONE_BEHAVIOR` session parameter, `REJECT` being treated like
+//! `ST`. Without either feature, only sessions using UTC are supported.
+//!
+//! `chrono::DateTime<Local>` values bound to or decoded from other timestamp columns use the
+//! local time of the client instead.
+//!
 //! ## `rust_decimal` feature
 //!
 //! | Rust type               | Exasol type    |
@@ -66,14 +116,19 @@ mod bigdecimal;
 mod bool;
 #[cfg(feature = "chrono")]
 pub mod chrono;
+mod decimal;
 mod float;
 #[cfg(feature = "geo-types")]
 pub mod geo_types;
 mod hashtype;
 mod int;
+mod interval_dts;
 mod interval_ytm;
+#[cfg(feature = "jiff")]
+pub mod jiff;
 #[cfg(feature = "json")]
 mod json;
+mod lenient;
 mod option;
 #[cfg(feature = "rust_decimal")]
 mod rust_decimal;
@@ -81,9 +136,13 @@ mod str;
 mod text;
 #[cfg(feature = "time")]
 pub mod time;
+pub(crate) mod time_zone;
 #[cfg(feature = "uuid")]
 mod uuid;
 
 pub use array::{ExaHasArrayType, ExaIter};
+pub use decimal::ExaDecimal;
 pub use hashtype::HashType;
+pub use interval_dts::ExaIntervalDayToSecond;
 pub use interval_ytm::ExaIntervalYearToMonth;
+pub use lenient::{Lenient, LenientNumber};
@@ -10,7 +10,7 @@ use crate::{
     arguments::ExaBuffer,
     database::Exasol,
     type_info::{Decimal, ExaDataType, ExaTypeInfo},
-    types::ExaHasArrayType,
+    types::{ExaDecimal, ExaHasArrayType},
     value::ExaValueRef,
 };
 
@@ -46,3 +46,19 @@ impl Decode<'_, Exasol> for rust_decimal::Decimal {
         <Self as Deserialize>::deserialize(value.value).map_err(From::from)
     }
 }
+
+impl TryFrom<ExaDecimal> for rust_decimal::Decimal {
+    type Error = BoxDynError;
+
+    fn try_from(value: ExaDecimal) -> Result<Self, Self::Error> {
+        value.as_str().parse().map_err(From::from)
+    }
+}
+
+impl TryFrom<rust_decimal::Decimal> for ExaDecimal {
+    type Error = BoxDynError;
+
+    fn try_from(value: rust_decimal::Decimal) -> Result<Self, Self::Error> {
+        value.to_string().parse()
+    }
+}
@@ -1,4 +1,10 @@
-use ::serde::{Deserialize, Serialize};
+use ::serde::{
+    de::{
+        value::{Error as DeError, StrDeserializer},
+        IntoDeserializer,
+    },
+    Deserialize, Serialize,
+};
 use sqlx_core::{
     decode::Decode,
     encode::{Encode, IsNull},
@@ -11,19 +17,26 @@ use crate::{
     arguments::ExaBuffer,
     database::Exasol,
     type_info::{ExaDataType, ExaTypeInfo},
-    types::ExaHasArrayType,
+    types::{time_zone::ParamTimeZone, ExaHasArrayType},
     value::ExaValueRef,
 };
 
 impl Type<Exasol> for PrimitiveDateTime {
     fn type_info() -> ExaTypeInfo {
-        ExaDataType::TimestampWithLocalTimeZone.into()
+        ExaDataType::TimestampWithLocalTimeZone { precision: None }.into()
     }
 }
 
 impl Type<Exasol> for OffsetDateTime {
     fn type_info() -> ExaTypeInfo {
-        ExaDataType::Timestamp.into()
+        ExaDataType::Timestamp { precision: None }.into()
+    }
+
+    fn compatible(ty: &ExaTypeInfo) -> bool {
+        matches!(
+            ty.data_type,
+            ExaDataType::Timestamp { .. } | ExaDataType::TimestampWithLocalTimeZone { .. }
+        )
     }
 }
 
@@ -49,6 +62,7 @@ impl Encode<'_, Exasol> for OffsetDateTime {
     fn encode_by_ref(&self, buf: &mut ExaBuffer) -> Result<IsNull, BoxDynError> {
         let utc_dt = self.to_offset(UtcOffset::UTC);
         let primitive = PrimitiveDateTime::new(utc_dt.date(), utc_dt.time());
+        buf.mark_utc_timestamps(ParamTimeZone::Utc);
         primitive.encode(buf)
     }
 
@@ -71,7 +85,10 @@ impl Decode<'_, Exasol> for PrimitiveDateTime {
 
 impl<'r> Decode<'r, Exasol> for OffsetDateTime {
     fn decode(value: ExaValueRef<'r>) -> Result<Self, BoxDynError> {
-        PrimitiveDateTime::decode(value).map(PrimitiveDateTime::assume_utc)
+        let input = value.utc_timestamp()?;
+        let deserializer: StrDeserializer<'_, DeError> = input.as_ref().into_deserializer();
+        let primitive = PrimitiveDateTimeDe::deserialize(deserializer)?.0;
+        Ok(primitive.assume_utc())
     }
 }
 
@@ -83,6 +100,8 @@ struct PrimitiveDateTimeSer<'a>(
 #[derive(Deserialize)]
 struct PrimitiveDateTimeDe(#[serde(deserialize_with = "timestamp::deserialize")] PrimitiveDateTime);
 
+// Formats as many fractional second digits as the value needs and parses any number of them, so
+// it works regardless of the column precision.
 serde::format_description!(
     timestamp,
     PrimitiveDateTime,
@@ -0,0 +1,390 @@
+//! Conversion of `TIMESTAMP WITH LOCAL TIME ZONE` values between UTC and the session time zone.
+//!
+//! Exasol stores these values as instants but renders and parses them in the session time zone,
+//! so timezone aware types have to convert them to or from UTC. The conversion is trivial if the
+//! session uses UTC, otherwise the time zone rules are looked up in the `chrono-tz` database, or in
+//! the `jiff` one if only the `jiff` feature is enabled. `jiff` types always use the latter.
+
+use std::borrow::Cow;
+
+use sqlx_core::error::BoxDynError;
+
+use crate::responses::ExaAttributes;
+
+/// The session time zone, along with how Exasol resolves ambiguous local timestamps in it.
+#[derive(Clone, Debug)]
+pub(crate) struct SessionTimeZone {
+    name: Cow<'static, str>,
+    /// Whether the `AMBIGUOUS` part of the `TIME_ZONE_BEHAVIOR` session parameter is `DST`.
+    ///
+    /// Exasol renders both instants of the hour repeated when the clocks are turned back the same
+    /// way. They are decoded as standard time, unless the session interprets such timestamps as
+    /// daylight saving time. `AMBIGUOUS REJECT` only makes Exasol refuse ambiguous input, so values
+    /// already stored still get decoded as standard time.
+    #[cfg_attr(not(any(feature = "chrono-tz", feature = "jiff")), allow(dead_code))]
+    ambiguous_dst: bool,
+}
+
+impl SessionTimeZone {
+    /// Used when no session information is available.
+    pub(crate) const UTC: Self = Self {
+        name: Cow::Borrowed("UTC"),
+        ambiguous_dst: false,
+    };
+
+    /// Names Exasol uses for time zones that are always UTC.
+    const UTC_NAMES: [&str; 8] = [
+        "UTC",
+        "UNIVERSAL",
+        "ZULU",
+        "GMT",
+        "ETC/UTC",
+        "ETC/UNIVERSAL",
+        "ETC/ZULU",
+        "ETC/GMT",
+    ];
+
+    /// Format in which timestamps are exchanged with Exasol.
+    #[cfg(feature = "chrono")]
+    const TIMESTAMP_FMT: &str = "%Y-%m-%d %H:%M:%S%.f";
+
+    pub(crate) fn new(attributes: &ExaAttributes) -> Self {
+        // Timestamps are exchanged in UTC regardless of the session time zone in this case.
+        if attributes.timestamp_utc_enabled() {
+            return Self::UTC;
+        }
+
+        Self::with_behavior(attributes.timezone(), attributes.timezone_behavior())
+    }
+
+    pub(crate) fn with_behavior(name: &str, behavior: &str) -> Self {
+        let ambiguous_dst = behavior
+            .split_whitespace()
+            .skip_while(|w| !w.eq_ignore_ascii_case("AMBIGUOUS"))
+            .nth(1)
+            .is_some_and(|w| w.eq_ignore_ascii_case("DST"));
+
+        Self {
+            name: Cow::Owned(name.to_owned()),
+            ambiguous_dst,
+        }
+    }
+
+    pub(crate) fn is_utc(&self) -> bool {
+        Self::UTC_NAMES
+            .iter()
+            .any(|n| n.eq_ignore_ascii_case(&self.name))
+    }
+
+    /// Converts a timestamp rendered in the session time zone to UTC.
+    ///
+    /// Timestamps repeated when the clocks are turned back are resolved according to the
+    /// `TIME_ZONE_BEHAVIOR` session parameter, as described for the `ambiguous_dst` field.
+    #[cfg_attr(not(any(feature = "chrono", feature = "time")), allow(dead_code))]
+    pub(crate) fn local_to_utc<'a>(&self, timestamp: &'a str) -> Result<Cow<'a, str>, BoxDynError> {
+        if self.is_utc() {
+            return Ok(Cow::Borrowed(timestamp));
+        }
+
+        self.convert_local_to_utc(timestamp).map(Cow::Owned)
+    }
+
+    /// Converts a UTC timestamp to the session time zone.
+    pub(crate) fn utc_to_local<'a>(&self, timestamp: &'a str) -> Result<Cow<'a, str>, BoxDynError> {
+        if self.is_utc() {
+            return Ok(Cow::Borrowed(timestamp));
+        }
+
+        self.convert_utc_to_local(timestamp).map(Cow::Owned)
+    }
+
+    #[cfg(feature = "jiff")]
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[cfg(feature = "jiff")]
+    pub(crate) fn ambiguous_dst(&self) -> bool {
+        self.ambiguous_dst
+    }
+
+    /// Returns the session time zone as looked up in the system time zone database.
+    #[cfg(feature = "jiff")]
+    pub(crate) fn jiff_tz(&self) -> Result<jiff::tz::TimeZone, BoxDynError> {
+        if self.is_utc() {
+            return Ok(jiff::tz::TimeZone::UTC);
+        }
+
+        // Time zone names are looked up case insensitively, so `EUROPE/BERLIN` is found as well.
+        jiff::tz::TimeZone::get(&self.name)
+            .map_err(|e| format!("unknown session time zone {}: {e}", self.name).into())
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    fn tz(&self) -> Result<chrono_tz::Tz, BoxDynError> {
+        // Exasol uses upper case time zone names, such as `EUROPE/BERLIN`.
+        chrono_tz::Tz::from_str_insensitive(&self.name)
+            .map_err(|_| format!("unknown session time zone {}", self.name).into())
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    fn convert_local_to_utc(&self, timestamp: &str) -> Result<String, BoxDynError> {
+        use chrono::{LocalResult, NaiveDateTime, TimeZone};
+
+        let naive = NaiveDateTime::parse_from_str(timestamp, Self::TIMESTAMP_FMT)?;
+
+        let datetime = match self.tz()?.from_local_datetime(&naive) {
+            LocalResult::Single(datetime) => datetime,
+            // Clocks get turned back when daylight saving time ends, so the earliest instant is the
+            // one in daylight saving time.
+            LocalResult::Ambiguous(dst, st) => {
+                if self.ambiguous_dst {
+                    dst
+                } else {
+                    st
+                }
+            }
+            LocalResult::None => {
+                let msg = format!(
+                    "{timestamp} does not exist in session time zone {}",
+                    self.name
+                );
+                return Err(msg.into());
+            }
+        };
+
+        let utc = datetime.naive_utc().format(Self::TIMESTAMP_FMT);
+        Ok(utc.to_string())
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    fn convert_utc_to_local(&self, timestamp: &str) -> Result<String, BoxDynError> {
+        use chrono::{NaiveDateTime, TimeZone};
+
+        let naive = NaiveDateTime::parse_from_str(timestamp, Self::TIMESTAMP_FMT)?;
+        let local = self.tz()?.from_utc_datetime(&naive).naive_local();
+        Ok(local.format(Self::TIMESTAMP_FMT).to_string())
+    }
+
+    #[cfg(all(feature = "jiff", not(feature = "chrono-tz")))]
+    fn convert_local_to_utc(&self, timestamp: &str) -> Result<String, BoxDynError> {
+        let datetime = timestamp.parse()?;
+        let utc = crate::types::jiff::local_to_timestamp(self, datetime)?;
+        let utc = utc.to_zoned(jiff::tz::TimeZone::UTC).datetime();
+        Ok(utc.strftime("%Y-%m-%d %H:%M:%S%.f").to_string())
+    }
+
+    #[cfg(all(feature = "jiff", not(feature = "chrono-tz")))]
+    fn convert_utc_to_local(&self, timestamp: &str) -> Result<String, BoxDynError> {
+        let utc = timestamp
+            .parse::<jiff::civil::DateTime>()?
+            .to_zoned(jiff::tz::TimeZone::UTC)?;
+        let local = utc.timestamp().to_zoned(self.jiff_tz()?).datetime();
+        Ok(local.strftime("%Y-%m-%d %H:%M:%S%.f").to_string())
+    }
+
+    #[cfg(not(any(feature = "chrono-tz", feature = "jiff")))]
+    #[cfg_attr(
+        not(any(feature = "chrono", feature = "jiff", feature = "time")),
+        allow(dead_code)
+    )]
+    fn convert_local_to_utc(&self, _: &str) -> Result<String, BoxDynError> {
+        Err(self.missing_tz_database())
+    }
+
+    #[cfg(not(any(feature = "chrono-tz", feature = "jiff")))]
+    fn convert_utc_to_local(&self, _: &str) -> Result<String, BoxDynError> {
+        Err(self.missing_tz_database())
+    }
+
+    #[cfg(not(any(feature = "chrono-tz", feature = "jiff")))]
+    fn missing_tz_database(&self) -> BoxDynError {
+        format!(
+            "converting TIMESTAMP WITH LOCAL TIME ZONE values in session time zone {} requires \
+             the `chrono-tz` or `jiff` feature",
+            self.name
+        )
+        .into()
+    }
+}
+
+/// The time zone UTC timestamp parameters are sent in when they are not bound to a
+/// `TIMESTAMP WITH LOCAL TIME ZONE`, which always gets them in the session time zone.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    not(any(feature = "chrono", feature = "jiff", feature = "time")),
+    allow(dead_code)
+)]
+pub(crate) enum ParamTimeZone {
+    /// Sent as is, e.g. for `chrono::DateTime<Utc>`.
+    Utc,
+    /// Sent in the local time zone of the client, e.g. for `chrono::DateTime<Local>`.
+    #[cfg_attr(not(feature = "chrono"), allow(dead_code))]
+    Client,
+}
+
+/// The time zone UTC timestamp parameters get converted to before being sent.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum LocalTimeZone<'a> {
+    Session(&'a SessionTimeZone),
+    Client,
+}
+
+impl LocalTimeZone<'_> {
+    pub(crate) fn utc_to_local(self, timestamp: &str) -> Result<Cow<'_, str>, BoxDynError> {
+        match self {
+            Self::Session(time_zone) => time_zone.utc_to_local(timestamp),
+            Self::Client => utc_to_client_local(timestamp).map(Cow::Owned),
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn utc_to_client_local(timestamp: &str) -> Result<String, BoxDynError> {
+    use chrono::{Local, NaiveDateTime, TimeZone};
+
+    let naive = NaiveDateTime::parse_from_str(timestamp, SessionTimeZone::TIMESTAMP_FMT)?;
+    let local = Local.from_utc_datetime(&naive).naive_local();
+    Ok(local.format(SessionTimeZone::TIMESTAMP_FMT).to_string())
+}
+
+#[cfg(not(feature = "chrono"))]
+fn utc_to_client_local(_: &str) -> Result<String, BoxDynError> {
+    Err("converting timestamps to the client time zone requires the `chrono` feature".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time_zone(name: &str, behavior: &str) -> SessionTimeZone {
+        SessionTimeZone::with_behavior(name, behavior)
+    }
+
+    #[test]
+    fn test_utc() {
+        let tz = time_zone("UNIVERSAL", "INVALID SHIFT AMBIGUOUS ST");
+        assert!(tz.is_utc());
+        assert_eq!(
+            tz.local_to_utc("2023-08-12 19:22:36").unwrap(),
+            "2023-08-12 19:22:36"
+        );
+        assert_eq!(
+            tz.utc_to_local("2023-08-12 19:22:36").unwrap(),
+            "2023-08-12 19:22:36"
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_behavior() {
+        let tz = time_zone("EUROPE/BERLIN", "INVALID SHIFT AMBIGUOUS DST");
+        assert!(!tz.is_utc());
+        assert!(tz.ambiguous_dst);
+
+        let tz = time_zone("EUROPE/BERLIN", "INVALID REJECT AMBIGUOUS REJECT");
+        assert!(!tz.ambiguous_dst);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_utc_to_client_local() {
+        use chrono::{Local, NaiveDateTime, TimeZone};
+
+        let utc =
+            NaiveDateTime::parse_from_str("2023-08-12 19:22:36.5", "%Y-%m-%d %H:%M:%S%.f").unwrap();
+        let expected = Local
+            .from_utc_datetime(&utc)
+            .naive_local()
+            .format("%Y-%m-%d %H:%M:%S%.f")
+            .to_string();
+
+        assert_eq!(
+            LocalTimeZone::Client
+                .utc_to_local("2023-08-12 19:22:36.5")
+                .unwrap(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn test_jiff_tz() {
+        let tz = time_zone("UNIVERSAL", "INVALID SHIFT AMBIGUOUS ST");
+        assert_eq!(tz.jiff_tz().unwrap(), jiff::tz::TimeZone::UTC);
+
+        let tz = time_zone("UNKNOWN/ZONE", "INVALID SHIFT AMBIGUOUS ST");
+        assert!(tz.jiff_tz().is_err());
+    }
+
+    #[cfg(not(any(feature = "chrono-tz", feature = "jiff")))]
+    #[test]
+    fn test_missing_tz_database() {
+        let tz = time_zone("EUROPE/BERLIN", "INVALID SHIFT AMBIGUOUS ST");
+        let err = tz
+            .local_to_utc("2023-08-12 19:22:36")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("chrono-tz"));
+    }
+
+    #[cfg(all(feature = "jiff", not(feature = "chrono-tz")))]
+    #[test]
+    fn test_convert_jiff() {
+        let tz = time_zone("EUROPE/BERLIN", "INVALID SHIFT AMBIGUOUS ST");
+        assert_eq!(
+            tz.local_to_utc("2023-08-12 19:22:36").unwrap(),
+            "2023-08-12 17:22:36"
+        );
+        assert_eq!(
+            tz.utc_to_local("2023-01-12 19:22:36.5").unwrap(),
+            "2023-01-12 20:22:36.5"
+        );
+
+        let tz = time_zone("EUROPE/BERLIN", "INVALID SHIFT AMBIGUOUS DST");
+        assert_eq!(
+            tz.local_to_utc("2023-10-29 02:30:00").unwrap(),
+            "2023-10-29 00:30:00"
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_convert() {
+        let tz = time_zone("EUROPE/BERLIN", "INVALID SHIFT AMBIGUOUS ST");
+        assert_eq!(
+            tz.local_to_utc("2023-08-12 19:22:36").unwrap(),
+            "2023-08-12 17:22:36"
+        );
+        assert_eq!(
+            tz.utc_to_local("2023-01-12 19:22:36.5").unwrap(),
+            "2023-01-12 20:22:36.500"
+        );
+
+        // Clocks were turned back from 03:00 to 02:00.
+        assert_eq!(
+            tz.local_to_utc("2023-10-29 02:30:00").unwrap(),
+            "2023-10-29 01:30:00"
+        );
+        let tz = time_zone("EUROPE/BERLIN", "INVALID SHIFT AMBIGUOUS DST");
+        assert_eq!(
+            tz.local_to_utc("2023-10-29 02:30:00").unwrap(),
+            "2023-10-29 00:30:00"
+        );
+        // Values stored in the repeated hour are still readable.
+        let tz = time_zone("EUROPE/BERLIN", "INVALID REJECT AMBIGUOUS REJECT");
+        assert_eq!(
+            tz.local_to_utc("2023-10-29 02:30:00").unwrap(),
+            "2023-10-29 01:30:00"
+        );
+
+        // Clocks were turned forward from 02:00 to 03:00.
+        assert!(tz.local_to_utc("2023-03-26 02:30:00").is_err());
+
+        let tz = time_zone("MARS/OLYMPUS_MONS", "INVALID SHIFT AMBIGUOUS ST");
+        let err = tz
+            .local_to_utc("2023-08-12 19:22:36")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unknown session time zone"));
+    }
+}
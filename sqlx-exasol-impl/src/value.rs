@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
 
 use serde_json::Value as JsonValue;
 use sqlx_core::{
@@ -6,13 +6,14 @@ use sqlx_core::{
     value::{Value, ValueRef},
 };
 
-use crate::{database::Exasol, type_info::ExaTypeInfo};
+use crate::{database::Exasol, type_info::ExaTypeInfo, types::time_zone::SessionTimeZone};
 
 /// Implementor of [`Value`].
 #[derive(Clone, Debug)]
 pub struct ExaValue {
     pub(crate) value: JsonValue,
     type_info: ExaTypeInfo,
+    time_zone: Option<Arc<SessionTimeZone>>,
 }
 
 /// Implementor of [`ValueRef`].
@@ -20,6 +21,41 @@ pub struct ExaValue {
 pub struct ExaValueRef<'r> {
     pub(crate) value: &'r JsonValue,
     pub(crate) type_info: &'r ExaTypeInfo,
+    /// The session time zone, if the value comes from a connection.
+    pub(crate) time_zone: Option<&'r Arc<SessionTimeZone>>,
+}
+
+impl<'r> ExaValueRef<'r> {
+    /// Returns the value as received from Exasol, useful for generically displaying values.
+    #[must_use]
+    pub fn as_json(&self) -> &'r JsonValue {
+        self.value
+    }
+
+    /// Returns the session time zone the value was rendered in, which is UTC for values not
+    /// coming from a connection.
+    #[cfg(any(feature = "chrono", feature = "jiff", feature = "time"))]
+    pub(crate) fn time_zone(&self) -> &'r SessionTimeZone {
+        self.time_zone.map_or(&SessionTimeZone::UTC, |tz| tz)
+    }
+
+    /// Returns the timestamp value in UTC, converting it from the session time zone in the case
+    /// of `TIMESTAMP WITH LOCAL TIME ZONE` columns.
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    pub(crate) fn utc_timestamp(&self) -> Result<Cow<'r, str>, sqlx_core::error::BoxDynError> {
+        use serde::Deserialize;
+
+        use crate::type_info::ExaDataType;
+
+        let timestamp = <&str>::deserialize(self.value).map_err(Box::new)?;
+
+        match self.type_info.data_type {
+            ExaDataType::TimestampWithLocalTimeZone { .. } => {
+                self.time_zone().local_to_utc(timestamp)
+            }
+            _ => Ok(Cow::Borrowed(timestamp)),
+        }
+    }
 }
 
 impl Value for ExaValue {
@@ -29,6 +65,7 @@ impl Value for ExaValue {
         ExaValueRef {
             value: &self.value,
             type_info: &self.type_info,
+            time_zone: self.time_zone.as_ref(),
         }
     }
 
@@ -48,6 +85,7 @@ impl<'r> ValueRef<'r> for ExaValueRef<'r> {
         ExaValue {
             value: self.value.clone(),
             type_info: *self.type_info,
+            time_zone: self.time_zone.cloned(),
         }
     }
 
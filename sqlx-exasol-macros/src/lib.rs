@@ -1,4 +1,6 @@
 mod parse;
+#[cfg(feature = "macros")]
+mod test_attr;
 
 #[allow(unused_imports, reason = "built-in; conditionally compiled")]
 use proc_macro::TokenStream;
@@ -86,9 +88,14 @@ pub fn migrate(input: TokenStream) -> TokenStream {
 #[cfg(feature = "macros")]
 #[proc_macro_attribute]
 pub fn test(args: TokenStream, input: TokenStream) -> TokenStream {
-    let input = syn::parse_macro_input!(input as syn::ItemFn);
+    let mut input = syn::parse_macro_input!(input as syn::ItemFn);
+
+    let args = match test_attr::expand_csv_fixtures(args.into(), &mut input) {
+        Ok(args) => args,
+        Err(e) => return e.to_compile_error().into(),
+    };
 
-    match sqlx_macros_core::test_attr::expand(args.into(), input) {
+    match sqlx_macros_core::test_attr::expand(args, input) {
         Ok(ts) => parse::rewrite(ts).into(),
         Err(e) => {
             if let Some(parse_err) = e.downcast_ref::<syn::Error>() {
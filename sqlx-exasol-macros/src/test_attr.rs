@@ -0,0 +1,104 @@
+use std::path::Path;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse::Parser, punctuated::Punctuated, FnArg, ItemFn, LitStr, Meta, Pat, Token};
+
+type AttributeArgs = Punctuated<Meta, Token![,]>;
+
+/// Extracts the `csv_fixtures(...)` arguments of the test attribute, making the test function load
+/// them before running its body. The remaining arguments are returned to be handled by `sqlx`.
+///
+/// CSV fixtures are resolved the same way as regular fixtures, relative to a `fixtures`
+/// directory next to the test file unless an explicit path is given. The table each fixture is
+/// imported in is the file name up to the first `.`, uppercased like unquoted identifiers are.
+pub fn expand_csv_fixtures(args: TokenStream, input: &mut ItemFn) -> syn::Result<TokenStream> {
+    let args = AttributeArgs::parse_terminated.parse2(args)?;
+
+    let mut remaining = AttributeArgs::new();
+    let mut fixtures = Vec::new();
+
+    for arg in args {
+        match arg {
+            Meta::List(list) if list.path.is_ident("csv_fixtures") => {
+                let paths =
+                    list.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?;
+                for path in paths {
+                    fixtures.push(csv_fixture(&path)?);
+                }
+            }
+            arg => remaining.push(arg),
+        }
+    }
+
+    if fixtures.is_empty() {
+        return Ok(quote!(#remaining));
+    }
+
+    let Some(FnArg::Typed(arg)) = input.sig.inputs.last() else {
+        return Err(syn::Error::new_spanned(
+            &input.sig,
+            "CSV fixtures require automatic test DB management; see docs",
+        ));
+    };
+
+    let Pat::Ident(pat) = &*arg.pat else {
+        return Err(syn::Error::new_spanned(
+            &arg.pat,
+            "CSV fixtures require the test function argument to be an identifier",
+        ));
+    };
+
+    let ident = &pat.ident;
+    let body = &input.block;
+
+    input.block = syn::parse_quote!({
+        #[allow(unused_mut)]
+        let mut #ident = #ident;
+
+        ::sqlx_exasol::ExaCsvFixtureTarget::load_csv_fixtures(&mut #ident, &[#(#fixtures),*])
+            .await
+            .expect("failed to load CSV fixtures");
+
+        #body
+    });
+
+    Ok(quote!(#remaining))
+}
+
+fn csv_fixture(lit: &LitStr) -> syn::Result<TokenStream> {
+    let value = lit.value();
+    let path = Path::new(&value);
+
+    // Same as `sqlx`, anything with a path separator is an explicit path.
+    let path = if path.components().count() > 1 {
+        value.clone()
+    } else {
+        format!("fixtures/{value}")
+    };
+
+    let file_name = Path::new(&path)
+        .file_name()
+        .and_then(|f| f.to_str())
+        .unwrap_or_default();
+
+    let (table, extension) = file_name.split_once('.').unwrap_or((file_name, ""));
+    let is_csv = extension.eq_ignore_ascii_case("csv") || extension.eq_ignore_ascii_case("csv.gz");
+
+    if !is_csv || table.is_empty() {
+        return Err(syn::Error::new_spanned(
+            lit,
+            "expected a `<table>.csv` or `<table>.csv.gz` fixture",
+        ));
+    }
+
+    let table = table.to_uppercase();
+
+    Ok(quote! {
+        ::sqlx_exasol::ExaCsvFixture {
+            path: #path,
+            table: #table,
+            contents: include_bytes!(#path),
+        }
+    })
+}
@@ -119,6 +119,11 @@ pub mod types {
         pub use sqlx_exasol_impl::types::time::*;
     }
 
+    #[cfg(feature = "jiff")]
+    pub mod jiff {
+        pub use sqlx_exasol_impl::types::jiff::*;
+    }
+
     #[cfg(feature = "derive")]
     #[doc(hidden)]
     pub use sqlx_exasol_macros::Type;
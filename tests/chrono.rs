@@ -11,6 +11,9 @@ test_type_valid!(naive_datetime<NaiveDateTime>::"TIMESTAMP"::("'2023-08-12 19:22
 test_type_valid!(naive_datetime_optional<Option<NaiveDateTime>>::"TIMESTAMP"::("NULL" => None::<NaiveDateTime>, "''" => None::<NaiveDateTime>, "'2023-08-12 19:22:36.591000'" => Some(NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap())));
 test_type_array!(naive_datetime_array<NaiveDateTime>::"TIMESTAMP"::(vec![NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap(), NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap(), NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap()]));
 
+test_type_valid!(naive_datetime_precision<NaiveDateTime>::"TIMESTAMP(6)"::("'2023-08-12 19:22:36.591123'" => NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591123", TIMESTAMP_FMT).unwrap()));
+test_type_valid!(naive_datetime_rounded<NaiveDateTime>::"TIMESTAMP(3)"::("'2023-08-12 19:22:36.591'" => NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591", TIMESTAMP_FMT).unwrap()));
+
 test_type_valid!(naive_date<NaiveDate>::"DATE"::("'2023-08-12'" => NaiveDate::parse_from_str("2023-08-12", DATE_FMT).unwrap()));
 test_type_valid!(naive_date_option<Option<NaiveDate>>::"DATE"::("NULL" => None::<NaiveDate>, "''" => None::<NaiveDate>, "'2023-08-12'" => Some(NaiveDate::parse_from_str("2023-08-12", DATE_FMT).unwrap())));
 test_type_array!(naive_date_array<NaiveDate>::"DATE"::(vec![NaiveDate::parse_from_str("2023-08-12", DATE_FMT).unwrap(), NaiveDate::parse_from_str("2023-08-12", DATE_FMT).unwrap(), NaiveDate::parse_from_str("2023-08-12", DATE_FMT).unwrap()]));
@@ -23,7 +26,172 @@ test_type_valid!(datetime_local<DateTime<Local>>::"TIMESTAMP WITH LOCAL TIME ZON
 test_type_valid!(datetime_local_option<Option<DateTime<Local>>>::"TIMESTAMP WITH LOCAL TIME ZONE"::("NULL" => None::<DateTime<Local>>, "''" => None::<DateTime<Local>>, "'2023-08-12 19:22:36.591000'" => Some(NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap().and_local_timezone(Local).unwrap())));
 test_type_array!(datetime_local_array<DateTime<Local>>::"TIMESTAMP WITH LOCAL TIME ZONE"::(vec![NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap().and_local_timezone(Local).unwrap(), NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap().and_local_timezone(Local).unwrap(), NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap().and_local_timezone(Local).unwrap()]));
 
+test_type_valid!(datetime_local_precision<DateTime<Local>>::"TIMESTAMP(6) WITH LOCAL TIME ZONE"::("'2023-08-12 19:22:36.591123'" => NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591123", TIMESTAMP_FMT).unwrap().and_local_timezone(Local).unwrap()));
+
 test_type_valid!(duration<TimeDelta>::"INTERVAL DAY TO SECOND"::("'10 20:45:50.123'" => TimeDelta::try_milliseconds(938_750_123).unwrap(), "'-10 20:45:50.123'" => TimeDelta::try_milliseconds(-938_750_123).unwrap()));
 test_type_valid!(duration_with_prec<TimeDelta>::"INTERVAL DAY(4) TO SECOND"::("'10 20:45:50.123'" => TimeDelta::try_milliseconds(938_750_123).unwrap(), "'-10 20:45:50.123'" => TimeDelta::try_milliseconds(-938_750_123).unwrap()));
 test_type_valid!(duration_option<Option<TimeDelta>>::"INTERVAL DAY TO SECOND"::("NULL" => None::<TimeDelta>, "''" => None::<TimeDelta>, "'10 20:45:50.123'" => Some(TimeDelta::try_milliseconds(938_750_123).unwrap())));
 test_type_array!(duration_array<TimeDelta>::"INTERVAL DAY TO SECOND"::(vec!["10 20:45:50.123", "10 20:45:50.123", "10 20:45:50.123"]));
+
+#[cfg(feature = "chrono-tz")]
+#[sqlx_exasol::test]
+async fn test_datetime_session_time_zone(
+    mut con: sqlx_exasol::pool::PoolConnection<sqlx_exasol::Exasol>,
+) -> Result<(), sqlx_exasol::error::BoxDynError> {
+    use sqlx_exasol::Executor;
+
+    con.execute("ALTER SESSION SET TIME_ZONE = 'EUROPE/BERLIN';")
+        .await?;
+    con.execute("CREATE TABLE sqlx_test_type ( col TIMESTAMP WITH LOCAL TIME ZONE );")
+        .await?;
+
+    let expected =
+        NaiveDateTime::parse_from_str("2023-08-12 17:22:36.591", TIMESTAMP_FMT)?.and_utc();
+
+    sqlx_exasol::query("INSERT INTO sqlx_test_type VALUES (?)")
+        .bind(expected)
+        .execute(&mut *con)
+        .await?;
+    sqlx_exasol::query("INSERT INTO sqlx_test_type VALUES (?)")
+        .bind(expected.with_timezone(&Local))
+        .execute(&mut *con)
+        .await?;
+    con.execute("INSERT INTO sqlx_test_type VALUES ('2023-08-12 19:22:36.591');")
+        .await?;
+
+    let values: Vec<DateTime<Utc>> = sqlx_exasol::query_scalar("SELECT * FROM sqlx_test_type;")
+        .fetch_all(&mut *con)
+        .await?;
+    assert_eq!(values, [expected; 3]);
+
+    let values: Vec<DateTime<Local>> = sqlx_exasol::query_scalar("SELECT * FROM sqlx_test_type;")
+        .fetch_all(&mut *con)
+        .await?;
+    assert_eq!(values, [expected.with_timezone(&Local); 3]);
+
+    let rendered: String = sqlx_exasol::query_scalar("SELECT TO_CHAR(col) FROM sqlx_test_type;")
+        .fetch_one(&mut *con)
+        .await?;
+    assert_eq!(rendered, "2023-08-12 19:22:36.591000");
+
+    Ok(())
+}
+
+#[cfg(feature = "chrono-tz")]
+#[sqlx_exasol::test]
+async fn test_datetime_session_time_zone_dst(
+    mut con: sqlx_exasol::pool::PoolConnection<sqlx_exasol::Exasol>,
+) -> Result<(), sqlx_exasol::error::BoxDynError> {
+    use sqlx_exasol::Executor;
+
+    con.execute("ALTER SESSION SET TIME_ZONE = 'EUROPE/BERLIN';")
+        .await?;
+    con.execute("CREATE TABLE sqlx_test_type ( col TIMESTAMP WITH LOCAL TIME ZONE );")
+        .await?;
+
+    // Right before and after the clocks were turned forward, then twice the same local time
+    // when they were turned back.
+    let values = [
+        "2023-03-26 00:59:59",
+        "2023-03-26 01:00:00",
+        "2023-10-29 00:30:00",
+        "2023-10-29 01:30:00",
+    ]
+    .map(|v| {
+        NaiveDateTime::parse_from_str(v, TIMESTAMP_FMT)
+            .unwrap()
+            .and_utc()
+    });
+
+    sqlx_exasol::query("INSERT INTO sqlx_test_type VALUES (?)")
+        .bind(values.to_vec())
+        .execute(&mut *con)
+        .await?;
+
+    let rendered: Vec<String> = sqlx_exasol::query_scalar(
+        "SELECT TO_CHAR(col, 'YYYY-MM-DD HH24:MI:SS') FROM sqlx_test_type ORDER BY col;",
+    )
+    .fetch_all(&mut *con)
+    .await?;
+    assert_eq!(
+        rendered,
+        [
+            "2023-03-26 01:59:59",
+            "2023-03-26 03:00:00",
+            "2023-10-29 02:30:00",
+            "2023-10-29 02:30:00"
+        ]
+    );
+
+    // Ambiguous timestamps are interpreted in standard time by default, so only the first
+    // occurrence does not round trip.
+    let decoded: Vec<DateTime<Utc>> =
+        sqlx_exasol::query_scalar("SELECT col FROM sqlx_test_type ORDER BY col;")
+            .fetch_all(&mut *con)
+            .await?;
+    assert_eq!(decoded[..2], values[..2]);
+    assert_eq!(decoded[2], values[3]);
+    assert_eq!(decoded[3], values[3]);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "chrono-tz"))]
+#[sqlx_exasol::test]
+async fn test_datetime_session_time_zone_unsupported(
+    mut con: sqlx_exasol::pool::PoolConnection<sqlx_exasol::Exasol>,
+) -> Result<(), sqlx_exasol::error::BoxDynError> {
+    use sqlx_exasol::Executor;
+
+    con.execute("ALTER SESSION SET TIME_ZONE = 'EUROPE/BERLIN';")
+        .await?;
+    con.execute("CREATE TABLE sqlx_test_type ( col TIMESTAMP WITH LOCAL TIME ZONE );")
+        .await?;
+    con.execute("INSERT INTO sqlx_test_type VALUES ('2023-08-12 19:22:36.591');")
+        .await?;
+
+    let error = sqlx_exasol::query_scalar::<_, DateTime<Utc>>("SELECT * FROM sqlx_test_type;")
+        .fetch_one(&mut *con)
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("chrono-tz"));
+
+    Ok(())
+}
+
+#[sqlx_exasol::test]
+async fn test_nanosecond_timestamps(
+    _pool_opts: sqlx_exasol::pool::PoolOptions<sqlx_exasol::Exasol>,
+    exa_opts: sqlx_exasol::ExaConnectOptions,
+) -> Result<(), sqlx_exasol::error::BoxDynError> {
+    use sqlx_exasol::{ConnectOptions, Executor};
+
+    let mut url = exa_opts.to_url_lossy();
+    url.query_pairs_mut()
+        .append_pair("nanosecond-timestamps", "true");
+    let mut con = sqlx_exasol::ExaConnectOptions::from_url(&url)?
+        .connect()
+        .await?;
+
+    con.execute("CREATE TABLE sqlx_test_type ( col TIMESTAMP(9) );")
+        .await?;
+
+    let expected = NaiveDateTime::parse_from_str("2023-08-12 19:22:36.591123456", TIMESTAMP_FMT)?;
+
+    sqlx_exasol::query("INSERT INTO sqlx_test_type VALUES (?)")
+        .bind(expected)
+        .execute(&mut con)
+        .await?;
+
+    let value: NaiveDateTime = sqlx_exasol::query_scalar("SELECT col FROM sqlx_test_type;")
+        .fetch_one(&mut con)
+        .await?;
+    assert_eq!(value, expected);
+
+    let rendered: String = sqlx_exasol::query_scalar("SELECT TO_CHAR(col) FROM sqlx_test_type;")
+        .fetch_one(&mut con)
+        .await?;
+    assert_eq!(rendered, "2023-08-12 19:22:36.591123456");
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ use sqlx_exasol::{
     error::BoxDynError,
     pool::{PoolConnection, PoolOptions},
     AssertSqlSafe, Column, Connection, ExaConnectOptions, ExaConnection, ExaPool, ExaPoolOptions,
-    ExaQueryResult, ExaRow, Exasol, Executor, Row, SqlStr, Statement, TypeInfo,
+    ExaQueryResult, ExaRow, ExaTxOptions, Exasol, Executor, Row, SqlStr, Statement, TypeInfo,
 };
 
 #[sqlx_exasol::test]
@@ -369,6 +369,87 @@ async fn it_cannot_nest_transactions(mut conn: PoolConnection<Exasol>) -> Result
     Ok(())
 }
 
+#[sqlx_exasol::test]
+async fn it_restores_autocommit_on_failed_begin(
+    mut conn: PoolConnection<Exasol>,
+) -> Result<(), BoxDynError> {
+    assert!(conn
+        .begin_with("SELECT * FROM NO_SUCH_TABLE")
+        .await
+        .is_err());
+    assert!(conn.attributes().autocommit());
+    assert!(!conn.attributes().open_transaction());
+
+    // A new transaction can be started right away.
+    let tx = conn.begin().await?;
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[sqlx_exasol::test]
+async fn it_restores_snapshot_mode_after_commit(
+    mut conn: PoolConnection<Exasol>,
+) -> Result<(), BoxDynError> {
+    conn.execute("CREATE TABLE snapshot_tx (id INTEGER);")
+        .await?;
+    assert!(!conn.attributes().snapshot_transactions_enabled());
+
+    let options = ExaTxOptions { snapshot: true };
+
+    let mut tx = conn.begin_with_options(options).await?;
+    assert!(tx.attributes().snapshot_transactions_enabled());
+
+    // The attribute is sent along with the statement and the response does not override it.
+    tx.execute("INSERT INTO snapshot_tx VALUES (1);").await?;
+    assert!(tx.attributes().snapshot_transactions_enabled());
+    tx.commit().await?;
+
+    assert!(!conn.attributes().snapshot_transactions_enabled());
+    assert!(conn.attributes().autocommit());
+
+    // The restored attribute reaches the database with the next request.
+    let count: i64 = sqlx_exasol::query_scalar("SELECT COUNT(*) FROM snapshot_tx")
+        .fetch_one(&mut *conn)
+        .await?;
+    assert_eq!(count, 1);
+    assert!(!conn.attributes().snapshot_transactions_enabled());
+
+    Ok(())
+}
+
+#[sqlx_exasol::test]
+async fn it_restores_snapshot_mode_after_rollback(
+    mut conn: PoolConnection<Exasol>,
+) -> Result<(), BoxDynError> {
+    conn.execute("CREATE TABLE snapshot_tx (id INTEGER);")
+        .await?;
+
+    let options = ExaTxOptions { snapshot: true };
+
+    let mut tx = conn.begin_with_options(options).await?;
+    tx.execute("INSERT INTO snapshot_tx VALUES (1);").await?;
+    assert!(tx.attributes().snapshot_transactions_enabled());
+    tx.rollback().await?;
+
+    assert!(!conn.attributes().snapshot_transactions_enabled());
+    assert!(conn.attributes().autocommit());
+
+    // Dropping the transaction rolls it back as well.
+    let mut tx = conn.begin_with_options(options).await?;
+    tx.execute("INSERT INTO snapshot_tx VALUES (2);").await?;
+    drop(tx);
+
+    let count: i64 = sqlx_exasol::query_scalar("SELECT COUNT(*) FROM snapshot_tx")
+        .fetch_one(&mut *conn)
+        .await?;
+    assert_eq!(count, 0);
+    assert!(!conn.attributes().snapshot_transactions_enabled());
+    assert!(conn.attributes().autocommit());
+
+    Ok(())
+}
+
 // This cannot unfortunately be achieved without some async drop.
 //
 // We can either schedule the rollback to be sent on the next async database interaction or
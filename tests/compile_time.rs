@@ -140,6 +140,17 @@ test_compile_time_type!(
     "SELECT column_decimal FROM compile_time_tests;"
 );
 
+#[cfg(not(any(feature = "bigdecimal", feature = "rust_decimal")))]
+test_compile_time_type!(
+    exa_decimal,
+    sqlx_exasol::types::ExaDecimal,
+    "0.0000002"
+        .parse::<sqlx_exasol::types::ExaDecimal>()
+        .unwrap(),
+    "INSERT INTO compile_time_tests (column_decimal) VALUES(?);",
+    "SELECT column_decimal FROM compile_time_tests;"
+);
+
 #[cfg(feature = "uuid")]
 test_compile_time_type!(
     uuid,
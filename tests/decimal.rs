@@ -0,0 +1,15 @@
+#![cfg(feature = "migrate")]
+
+mod macros;
+
+use sqlx_exasol::types::ExaDecimal;
+
+fn decimal(s: &str) -> ExaDecimal {
+    s.parse().unwrap()
+}
+
+test_type_valid!(decimal<ExaDecimal>::"DECIMAL(36, 16)"::(decimal("-12345678901234567890.1234567890123456"), decimal("0.0000000000000001"), decimal("1.5"), decimal("0")));
+test_type_valid!(decimal_literal<ExaDecimal>::"DECIMAL(10, 2)"::("12345678.90" => decimal("12345678.9"), "-0.05" => decimal("-0.05"), "100" => decimal("100")));
+test_type_valid!(decimal_no_scale<ExaDecimal>::"DECIMAL(36, 0)"::(decimal(&"9".repeat(36)), decimal(&format!("-{}", "9".repeat(36)))));
+test_type_valid!(decimal_option<Option<ExaDecimal>>::"DECIMAL(36, 16)"::("NULL" => None::<ExaDecimal>, decimal("1.5") => Some(decimal("1.5"))));
+test_type_array!(decimal_array<ExaDecimal>::"DECIMAL(36, 16)"::(vec![decimal("-12345678901234567890.1234567890123456"), decimal("1.5"), decimal("0")]));
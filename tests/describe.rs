@@ -15,10 +15,10 @@ async fn it_describes_columns(mut conn: PoolConnection<Exasol>) -> Result<(), Bo
     assert_eq!(d.columns()[2].name(), "text");
     assert_eq!(d.columns()[3].name(), "owner_id");
 
-    assert_eq!(d.nullable(0), None);
-    assert_eq!(d.nullable(1), None);
-    assert_eq!(d.nullable(2), None);
-    assert_eq!(d.nullable(3), None);
+    assert_eq!(d.nullable(0), Some(false));
+    assert_eq!(d.nullable(1), Some(true));
+    assert_eq!(d.nullable(2), Some(false));
+    assert_eq!(d.nullable(3), Some(true));
 
     assert_eq!(d.columns()[0].type_info().name(), "DECIMAL(20, 0)");
     assert_eq!(d.columns()[1].type_info().name(), "TIMESTAMP");
@@ -28,6 +28,33 @@ async fn it_describes_columns(mut conn: PoolConnection<Exasol>) -> Result<(), Bo
     Ok(())
 }
 
+#[sqlx_exasol::test(migrations = "tests/setup")]
+async fn it_describes_join_nullability(
+    mut conn: PoolConnection<Exasol>,
+) -> Result<(), BoxDynError> {
+    let d = conn
+        .describe(SqlStr::from_static(
+            "SELECT t.id, r.text, 1 AS one, r.owner_id FROM tweet t LEFT JOIN tweet_reply r ON \
+             r.tweet_id = t.id",
+        ))
+        .await?;
+
+    assert_eq!(d.nullable(0), Some(false));
+    assert_eq!(d.nullable(1), Some(true));
+    assert_eq!(d.nullable(2), Some(false));
+    assert_eq!(d.nullable(3), Some(true));
+
+    let d = conn
+        .describe(SqlStr::from_static(
+            "SELECT text FROM tweet UNION ALL SELECT text FROM tweet_reply",
+        ))
+        .await?;
+
+    assert_eq!(d.nullable(0), None);
+
+    Ok(())
+}
+
 #[sqlx_exasol::test(migrations = "tests/setup")]
 async fn it_describes_params(mut conn: PoolConnection<Exasol>) -> Result<(), BoxDynError> {
     conn.execute(
@@ -6,3 +6,7 @@ mod macros;
 test_type_valid!(f64::"DOUBLE PRECISION"::(-3.402_823_466_385_29e38_f64, 3.402_823_466_385_29e38_f64));
 test_type_valid!(f64_option<Option<f64>>::"DOUBLE PRECISION"::("NULL" => None::<f64>, -1_005_213.045_654_3 => Some(-1_005_213.045_654_3)));
 test_type_array!(f64_array<f64>::"DOUBLE PRECISION"::(vec![-1_005_213.045_654_3, 1_005_213.045_654_3, -1005.0456, 1005.0456, -7462.0, 7462.0]));
+
+test_type_valid!(f32::"DOUBLE PRECISION"::(-1_005_213.0_f32, 1_005_213.0_f32, -1005.0456_f32, 1005.0456_f32));
+test_type_valid!(f32_option<Option<f32>>::"DOUBLE PRECISION"::("NULL" => None::<f32>, -7462.5 => Some(-7462.5_f32)));
+test_type_array!(f32_array<f32>::"DOUBLE PRECISION"::(vec![-1005.0456_f32, 1005.0456, -7462.0, 7462.0]));
@@ -4,6 +4,8 @@ mod macros;
 
 const MIN_I64_NUMERIC: i64 = -999_999_999_999_999_999;
 const MAX_I64_NUMERIC: i64 = 1_000_000_000_000_000_000;
+const MIN_I128_NUMERIC: i128 = -999_999_999_999_999_999_999_999_999_999_999_999;
+const MAX_I128_NUMERIC: i128 = 999_999_999_999_999_999_999_999_999_999_999_999;
 
 test_type_valid!(i8::"DECIMAL(3, 0)"::(i8::MIN, i8::MAX));
 
@@ -21,3 +23,17 @@ test_type_valid!(i32_in_i64<i64>::"DECIMAL(20, 0)"::(i32::MIN => i64::from(i32::
 
 test_type_valid!(i64_option<Option<i64>>::"DECIMAL(20, 0)"::("NULL" => None::<i64>, i64::MAX => Some(i64::MAX)));
 test_type_array!(i64_array<i64>::"DECIMAL(20, 0)"::(vec![i64::MIN, i64::MAX, 1_234_567]));
+
+test_type_valid!(u8::"DECIMAL(3, 0)"::(u8::MIN, u8::MAX));
+test_type_valid!(u16::"DECIMAL(5, 0)"::(u16::MIN, u16::MAX, u16::from(u8::MAX)));
+test_type_valid!(u32::"DECIMAL(10, 0)"::(u32::MIN, u32::MAX, u32::from(u16::MAX)));
+test_type_valid!(u64::"DECIMAL(20, 0)"::(u64::MIN, u64::MAX, u64::from(u32::MAX), MAX_I64_NUMERIC.unsigned_abs(), MAX_I64_NUMERIC.unsigned_abs() - 1));
+test_type_valid!(u32_in_u64<u64>::"DECIMAL(20, 0)"::(u32::MAX => u64::from(u32::MAX)));
+
+test_type_valid!(i128::"DECIMAL(36, 0)"::(i128::from(i64::MIN), i128::from(i64::MAX), i128::from(MIN_I64_NUMERIC) - 1, i128::from(MAX_I64_NUMERIC), MIN_I128_NUMERIC, MAX_I128_NUMERIC));
+test_type_valid!(i64_in_i128<i128>::"DECIMAL(36, 0)"::(i64::MIN => i128::from(i64::MIN), i64::MAX => i128::from(i64::MAX)));
+test_type_valid!(u128::"DECIMAL(36, 0)"::(u128::MIN, u128::from(u64::MAX), MAX_I128_NUMERIC.unsigned_abs()));
+
+test_type_valid!(u64_option<Option<u64>>::"DECIMAL(20, 0)"::("NULL" => None::<u64>, u64::MAX => Some(u64::MAX)));
+test_type_array!(u64_array<u64>::"DECIMAL(20, 0)"::(vec![u64::MIN, u64::MAX, 1_234_567]));
+test_type_array!(i128_array<i128>::"DECIMAL(36, 0)"::(vec![MIN_I128_NUMERIC, MAX_I128_NUMERIC, 1_234_567]));
@@ -0,0 +1,10 @@
+#![cfg(feature = "migrate")]
+
+mod macros;
+
+use sqlx_exasol::types::ExaIntervalDayToSecond;
+
+test_type_valid!(interval_dts<ExaIntervalDayToSecond>::"INTERVAL DAY TO SECOND"::("'10 20:45:50.123'" => ExaIntervalDayToSecond::new(10, 20, 45, 50, 123), "'-10 20:45:50.123'" => ExaIntervalDayToSecond::new(-10, -20, -45, -50, -123)));
+test_type_valid!(interval_dts_with_prec<ExaIntervalDayToSecond>::"INTERVAL DAY(4) TO SECOND"::("'1000 20:45:50.123'" => ExaIntervalDayToSecond::new(1000, 20, 45, 50, 123), "'-0 05:00:00.000'" => ExaIntervalDayToSecond::new(0, -5, 0, 0, 0)));
+test_type_valid!(interval_dts_option<Option<ExaIntervalDayToSecond>>::"INTERVAL DAY TO SECOND"::("NULL" => None::<ExaIntervalDayToSecond>, "''" => None::<ExaIntervalDayToSecond>, "'10 20:45:50.123'" => Some(ExaIntervalDayToSecond::new(10, 20, 45, 50, 123))));
+test_type_array!(interval_dts_array<ExaIntervalDayToSecond>::"INTERVAL DAY TO SECOND"::(vec![ExaIntervalDayToSecond::new(10, 20, 45, 50, 123), ExaIntervalDayToSecond(-1), ExaIntervalDayToSecond(0)]));
@@ -7,6 +7,7 @@ test_type_invalid!(i8_into_smaller<i8>::"DECIMAL(1, 0)"::(-15i8, 15i8));
 test_type_invalid!(i16_into_smaller<i16>::"DECIMAL(3, 0)"::(-1_234i16, 1_234i16));
 test_type_invalid!(i32_into_smaller<i32>::"DECIMAL(7, 0)"::(-12_345_678i32, 12_345_678i32));
 test_type_invalid!(i64_into_smaller<i64>::"DECIMAL(15, 0)"::(-1_234_567_890_123_456i64, 1_234_567_890_123_456i64));
+test_type_invalid!(i128_into_smaller<i128>::"DECIMAL(20, 0)"::(i128::from(i64::MIN) * 100, i128::from(i64::MAX) * 100));
 
 // Test incompatible types
 test_type_invalid!(i16_into_i8<i16>::"DECIMAL(3,0)"::(i16::MAX));
@@ -16,6 +17,12 @@ test_type_invalid!(i64_into_i8<i64>::"DECIMAL(3,0)"::(i64::MAX));
 test_type_invalid!(i64_into_i16<i64>::"DECIMAL(5,0)"::(i64::MAX));
 test_type_invalid!(i64_into_i32<i64>::"DECIMAL(10,0)"::(i64::MAX));
 
+// Values out of the range of the decoded type
+test_type_invalid!(u8_overflow<u8>::"DECIMAL(3,0)"::(999u16));
+test_type_invalid!(u32_negative<u32>::"DECIMAL(10,0)"::(-1i32));
+test_type_invalid!(u64_negative<u64>::"DECIMAL(20,0)"::(i64::MIN));
+test_type_invalid!(u128_negative<u128>::"DECIMAL(36,0)"::(-1i128));
+
 // Not enough room due to scale eating up space
 test_type_invalid!(i16_no_room<i16>::"DECIMAL(5,2)"::(i16::MAX));
 test_type_invalid!(i32_no_room<i32>::"DECIMAL(10,2)"::(i32::MAX));
@@ -0,0 +1,97 @@
+#![cfg(all(feature = "migrate", feature = "jiff"))]
+
+mod macros;
+
+use jiff::{civil::date, tz::TimeZone};
+use sqlx_exasol::types::jiff::{
+    civil::{Date, DateTime},
+    SignedDuration, Span, Timestamp, Zoned,
+};
+
+fn timestamp() -> Timestamp {
+    "2023-08-12T19:22:36.591Z".parse().unwrap()
+}
+
+test_type_valid!(date<Date>::"DATE"::("'2023-08-12'" => date(2023, 8, 12)));
+test_type_valid!(date_option<Option<Date>>::"DATE"::("NULL" => None::<Date>, "''" => None::<Date>, "'2023-08-12'" => Some(date(2023, 8, 12))));
+test_type_array!(date_array<Date>::"DATE"::(vec![date(2023, 8, 12), date(2023, 8, 12), date(2023, 8, 12)]));
+
+test_type_valid!(datetime<DateTime>::"TIMESTAMP"::("'2023-08-12 19:22:36.591000'" => date(2023, 8, 12).at(19, 22, 36, 591_000_000)));
+test_type_valid!(datetime_option<Option<DateTime>>::"TIMESTAMP"::("NULL" => None::<DateTime>, "''" => None::<DateTime>, "'2023-08-12 19:22:36.591000'" => Some(date(2023, 8, 12).at(19, 22, 36, 591_000_000))));
+test_type_array!(datetime_array<DateTime>::"TIMESTAMP"::(vec![date(2023, 8, 12).at(19, 22, 36, 591_000_000), date(2023, 8, 12).at(19, 22, 36, 591_000_000), date(2023, 8, 12).at(19, 22, 36, 591_000_000)]));
+test_type_valid!(datetime_precision<DateTime>::"TIMESTAMP(6)"::("'2023-08-12 19:22:36.591123'" => date(2023, 8, 12).at(19, 22, 36, 591_123_000)));
+test_type_valid!(datetime_rounded<DateTime>::"TIMESTAMP(0)"::("'2023-08-12 19:22:36'" => date(2023, 8, 12).at(19, 22, 36, 0)));
+
+test_type_valid!(timestamp<Timestamp>::"TIMESTAMP WITH LOCAL TIME ZONE"::("'2023-08-12 19:22:36.591000'" => timestamp()));
+test_type_valid!(timestamp_option<Option<Timestamp>>::"TIMESTAMP WITH LOCAL TIME ZONE"::("NULL" => None::<Timestamp>, "''" => None::<Timestamp>, "'2023-08-12 19:22:36.591000'" => Some(timestamp())));
+test_type_array!(timestamp_array<Timestamp>::"TIMESTAMP WITH LOCAL TIME ZONE"::(vec![timestamp(), timestamp(), timestamp()]));
+test_type_valid!(timestamp_utc<Timestamp>::"TIMESTAMP"::("'2023-08-12 19:22:36.591000'" => timestamp()));
+
+test_type_valid!(zoned<Zoned>::"TIMESTAMP WITH LOCAL TIME ZONE"::("'2023-08-12 19:22:36.591000'" => timestamp().to_zoned(TimeZone::UTC)));
+test_type_array!(zoned_array<Zoned>::"TIMESTAMP WITH LOCAL TIME ZONE"::(vec![timestamp().to_zoned(TimeZone::UTC), timestamp().to_zoned(TimeZone::UTC)]));
+
+test_type_valid!(signed_duration<SignedDuration>::"INTERVAL DAY TO SECOND"::("'10 20:45:50.123'" => SignedDuration::from_millis(938_750_123), "'-10 20:45:50.123'" => SignedDuration::from_millis(-938_750_123)));
+test_type_valid!(signed_duration_with_prec<SignedDuration>::"INTERVAL DAY(4) TO SECOND"::("'10 20:45:50.123'" => SignedDuration::from_millis(938_750_123), "'-10 20:45:50.123'" => SignedDuration::from_millis(-938_750_123)));
+test_type_valid!(signed_duration_option<Option<SignedDuration>>::"INTERVAL DAY TO SECOND"::("NULL" => None::<SignedDuration>, "''" => None::<SignedDuration>, "'10 20:45:50.123'" => Some(SignedDuration::from_millis(938_750_123))));
+test_type_array!(signed_duration_array<SignedDuration>::"INTERVAL DAY TO SECOND"::(vec![SignedDuration::from_millis(938_750_123), SignedDuration::from_millis(-938_750_123)]));
+
+#[sqlx_exasol::test]
+async fn test_span(
+    mut con: sqlx_exasol::pool::PoolConnection<sqlx_exasol::Exasol>,
+) -> Result<(), sqlx_exasol::error::BoxDynError> {
+    use sqlx_exasol::Executor;
+
+    con.execute(
+        "CREATE TABLE sqlx_test_type ( dts INTERVAL DAY TO SECOND, ytm INTERVAL YEAR TO MONTH );",
+    )
+    .await?;
+
+    let dts = Span::new()
+        .days(10)
+        .hours(20)
+        .minutes(45)
+        .seconds(50)
+        .milliseconds(123);
+    let ytm = Span::new().years(-1).months(-6);
+
+    sqlx_exasol::query("INSERT INTO sqlx_test_type VALUES (?, ?)")
+        .bind(dts)
+        .bind(ytm)
+        .execute(&mut *con)
+        .await?;
+    con.execute("INSERT INTO sqlx_test_type VALUES ('10 20:45:50.123', '-1-6');")
+        .await?;
+
+    let values: Vec<(Span, Span)> = sqlx_exasol::query_as("SELECT * FROM sqlx_test_type;")
+        .fetch_all(&mut *con)
+        .await?;
+
+    for (dts_value, ytm_value) in values {
+        assert_eq!(dts_value.fieldwise(), dts.fieldwise());
+        assert_eq!(ytm_value.fieldwise(), ytm.fieldwise());
+    }
+
+    // Unbalanced units get balanced, with days being 24 hours long.
+    sqlx_exasol::query("INSERT INTO sqlx_test_type (dts) VALUES (?)")
+        .bind(Span::new().hours(-36))
+        .execute(&mut *con)
+        .await?;
+
+    let value: Span =
+        sqlx_exasol::query_scalar("SELECT dts FROM sqlx_test_type WHERE ytm IS NULL;")
+            .fetch_one(&mut *con)
+            .await?;
+    assert_eq!(
+        value.fieldwise(),
+        Span::new().days(-1).hours(-12).fieldwise()
+    );
+
+    // Calendar and time units cannot be mixed.
+    let result = sqlx_exasol::query("INSERT INTO sqlx_test_type (dts) VALUES (?)")
+        .bind(Span::new().months(1).days(1))
+        .execute(&mut *con)
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
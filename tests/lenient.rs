@@ -0,0 +1,21 @@
+#![allow(clippy::float_cmp)]
+#![cfg(feature = "migrate")]
+
+mod macros;
+
+use sqlx_exasol::types::Lenient;
+
+test_type_valid!(decimal_f64<Lenient<f64>>::"DECIMAL(18, 2)"::(1234 => 1234 => Lenient(1234.0), "12.34" => Lenient(12.34) => Lenient(12.34)));
+test_type_valid!(decimal_f32<Lenient<f32>>::"DECIMAL(36, 0)"::(i64::MAX => i64::MAX => Lenient(9.223_372e18_f32)));
+test_type_valid!(decimal_i64<Lenient<i64>>::"DECIMAL(10, 2)"::(-5 => -5 => Lenient(-5), 1_000 => 1_000 => Lenient(1_000)));
+test_type_valid!(decimal_u8<Lenient<u8>>::"DECIMAL(18, 0)"::(255 => 255 => Lenient(255)));
+test_type_valid!(double_i64<Lenient<i64>>::"DOUBLE PRECISION"::(7462 => 7462.0 => Lenient(7462), "1e15" => 1e15 => Lenient(1_000_000_000_000_000)));
+test_type_valid!(double_f32<Lenient<f32>>::"DOUBLE PRECISION"::("1005.0456" => 1005.0456 => Lenient(1005.0456)));
+test_type_valid!(lenient_option<Option<Lenient<i32>>>::"DECIMAL(10, 2)"::("NULL" => None::<i32> => None, 42 => 42 => Some(Lenient(42))));
+
+// Values that cannot be represented by the decoded type
+test_type_invalid!(decimal_fraction_i64<Lenient<i64>>::"DECIMAL(10, 2)"::(Lenient(12.34)));
+test_type_invalid!(double_fraction_i32<Lenient<i32>>::"DOUBLE PRECISION"::(Lenient(7462.5)));
+test_type_invalid!(decimal_out_of_range_u8<Lenient<u8>>::"DECIMAL(18, 0)"::(Lenient(-1)));
+test_type_invalid!(double_out_of_range_i16<Lenient<i16>>::"DOUBLE PRECISION"::(Lenient(1e10)));
+test_type_invalid!(varchar_i64<Lenient<i64>>::"VARCHAR(100)"::(Lenient("12")));
@@ -3,8 +3,10 @@
 use std::path::Path;
 
 use sqlx_exasol::{
-    error::BoxDynError, migrate::Migrator, pool::PoolConnection, ExaConnection, Exasol, Executor,
-    Row,
+    error::BoxDynError,
+    migrate::{Migrate, MigrateError, Migrator},
+    pool::PoolConnection,
+    ExaConnection, Exasol, Executor, Row,
 };
 
 #[sqlx_exasol::test(migrations = false)]
@@ -29,6 +31,35 @@ async fn simple(mut conn: PoolConnection<Exasol>) -> Result<(), BoxDynError> {
     Ok(())
 }
 
+#[sqlx_exasol::test(migrations = false)]
+async fn directives(mut conn: PoolConnection<Exasol>) -> Result<(), BoxDynError> {
+    clean_up(&mut conn).await?;
+
+    let migrator = Migrator::new(Path::new("tests/migrations_directives")).await?;
+
+    // run migration
+    migrator.run(&mut conn).await?;
+
+    // check outcome
+    let success: bool = conn
+        .fetch_one("SELECT success FROM _sqlx_migrations WHERE version = 1")
+        .await?
+        .get(0);
+    assert!(success);
+
+    // the script body must have been created in full
+    conn.execute("EXECUTE SCRIPT migrations_directives_script()")
+        .await?;
+
+    let res: i64 = conn
+        .fetch_one("SELECT some_payload FROM migrations_directives_test")
+        .await?
+        .get(0);
+    assert_eq!(res, 42);
+
+    Ok(())
+}
+
 #[sqlx_exasol::test(migrations = false)]
 async fn reversible(mut conn: PoolConnection<Exasol>) -> Result<(), BoxDynError> {
     clean_up(&mut conn).await?;
@@ -68,12 +99,139 @@ async fn reversible(mut conn: PoolConnection<Exasol>) -> Result<(), BoxDynError>
     Ok(())
 }
 
+#[sqlx_exasol::test(migrations = false)]
+async fn lock_lost(mut conn: PoolConnection<Exasol>) -> Result<(), BoxDynError> {
+    clean_up(&mut conn).await?;
+
+    let mut migrator = Migrator::new(Path::new("tests/migrations_simple")).await?;
+    migrator.set_locking(false);
+
+    conn.lock().await?;
+
+    // Simulate the lock expiring and getting taken over by another session.
+    conn.execute(r#"UPDATE "_sqlx_migrations_lock" SET session_id = 0"#)
+        .await?;
+
+    // The lock is refreshed before applying a migration, which must fail.
+    let err = migrator.run_direct(None, &mut *conn).await.unwrap_err();
+    assert!(err.to_string().contains("migration lock"));
+
+    let applied: i64 = conn
+        .fetch_one("SELECT COUNT(*) FROM _sqlx_migrations")
+        .await?
+        .get(0);
+    assert_eq!(applied, 0);
+
+    Ok(())
+}
+
+#[sqlx_exasol::test(migrations = false)]
+async fn unlock_not_held(mut conn: PoolConnection<Exasol>) -> Result<(), BoxDynError> {
+    conn.lock().await?;
+    conn.unlock().await?;
+
+    // Releasing a lock that is no longer held fails.
+    conn.lock().await?;
+    conn.execute(r#"UPDATE "_sqlx_migrations_lock" SET session_id = 0"#)
+        .await?;
+
+    let err = conn.unlock().await.unwrap_err();
+    let MigrateError::Execute(sqlx_exasol::Error::Protocol(msg)) = err else {
+        panic!("unexpected error: {err}");
+    };
+    assert!(msg.starts_with("migration lock is not held"));
+
+    // Clean up the lock taken over by the fake session.
+    conn.execute(r#"DELETE FROM "_sqlx_migrations_lock""#)
+        .await?;
+
+    Ok(())
+}
+
+#[sqlx_exasol::test(migrations = false)]
+async fn baseline(mut conn: PoolConnection<Exasol>) -> Result<(), BoxDynError> {
+    clean_up(&mut conn).await?;
+
+    let migrator = Migrator::new(Path::new("tests/migrations_simple")).await?;
+
+    // create the objects of the first migration by hand
+    conn.execute(
+        "CREATE TABLE migrations_simple_test (some_id INTEGER PRIMARY KEY, some_payload INTEGER \
+         NOT NULL)",
+    )
+    .await?;
+    conn.execute("INSERT INTO migrations_simple_test VALUES (1, 100)")
+        .await?;
+
+    conn.baseline_migrations(&migrator, 1).await?;
+
+    let applied = conn.list_applied_migrations("_sqlx_migrations").await?;
+    assert_eq!(applied.len(), 1);
+    assert_eq!(applied[0].version, 1);
+
+    // only the second migration gets run
+    migrator.run(&mut conn).await?;
+
+    let res: String = conn
+        .fetch_one("SELECT some_payload FROM migrations_simple_test")
+        .await?
+        .get(0);
+    assert_eq!(res, "110_suffix");
+
+    // baselining again is a no-op
+    conn.baseline_migrations(&migrator, 2).await?;
+    assert_eq!(
+        conn.list_applied_migrations("_sqlx_migrations")
+            .await?
+            .len(),
+        2
+    );
+
+    Ok(())
+}
+
+#[sqlx_exasol::test(migrations = false)]
+async fn repair(mut conn: PoolConnection<Exasol>) -> Result<(), BoxDynError> {
+    clean_up(&mut conn).await?;
+
+    let migrator = Migrator::new(Path::new("tests/migrations_simple")).await?;
+    migrator.run(&mut conn).await?;
+
+    // nothing to repair
+    assert!(conn.repair_migrations(&migrator, None).await?.is_empty());
+
+    // simulate both migrations being changed after being applied
+    conn.execute("UPDATE _sqlx_migrations SET checksum = '00'")
+        .await?;
+
+    let err = migrator.run(&mut conn).await.unwrap_err();
+    assert!(matches!(err, MigrateError::VersionMismatch(1)));
+
+    // repairing a single version leaves the other one untouched
+    assert_eq!(conn.repair_migrations(&migrator, Some(2)).await?, [2]);
+    assert!(matches!(
+        migrator.run(&mut conn).await.unwrap_err(),
+        MigrateError::VersionMismatch(1)
+    ));
+
+    assert_eq!(conn.repair_migrations(&migrator, None).await?, [1]);
+    migrator.run(&mut conn).await?;
+
+    Ok(())
+}
+
 /// Ensure that we have a clean initial state.
 async fn clean_up(conn: &mut ExaConnection) -> Result<(), BoxDynError> {
     conn.execute("DROP TABLE migrations_simple_test").await.ok();
     conn.execute("DROP TABLE migrations_reversible_test")
         .await
         .ok();
+    conn.execute("DROP TABLE migrations_directives_test")
+        .await
+        .ok();
+    conn.execute("DROP SCRIPT migrations_directives_script")
+        .await
+        .ok();
     conn.execute("DROP TABLE _sqlx_migrations").await.ok();
 
     Ok(())
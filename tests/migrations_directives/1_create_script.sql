@@ -0,0 +1,6 @@
+-- sqlx-exasol: no-transaction, no-split
+-- The script body contains semicolons, so the migration must not be split into statements.
+CREATE OR REPLACE SCRIPT migrations_directives_script() AS
+local payload = 40 + 2;
+query([[CREATE TABLE migrations_directives_test (some_payload DECIMAL(18, 0))]]);
+query([[INSERT INTO migrations_directives_test VALUES (:payload)]], {payload = payload});
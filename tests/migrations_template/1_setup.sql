@@ -0,0 +1,15 @@
+CREATE TABLE template_parent
+(
+    parent_id INTEGER IDENTITY PRIMARY KEY,
+    name      VARCHAR(16) DEFAULT 'unnamed' NOT NULL
+);
+
+CREATE TABLE template_child
+(
+    child_id  INTEGER IDENTITY,
+    parent_id INTEGER NOT NULL CONSTRAINT TEMPLATE_CHILD_PARENT REFERENCES template_parent (parent_id),
+    CONSTRAINT TEMPLATE_CHILD_PK PRIMARY KEY (child_id)
+);
+
+INSERT INTO template_parent (name) VALUES ('first'), ('second');
+INSERT INTO template_child (parent_id) VALUES (1), (2), (2);
@@ -0,0 +1,139 @@
+#![cfg(feature = "migrate")]
+
+use futures_util::TryStreamExt;
+use sqlx_exasol::{
+    error::BoxDynError, pool::PoolConnection, ExaConnection, ExaQueryOptions, Exasol, Executor,
+};
+
+const ROWS_QUERY: &str = "SELECT * FROM VALUES (1), (2), (3), (4)";
+const TIMEOUT_QUERY: &str =
+    "SELECT SESSION_VALUE FROM EXA_PARAMETERS WHERE PARAMETER_NAME = 'QUERY_TIMEOUT'";
+
+async fn session_timeout(conn: &mut ExaConnection) -> Result<String, BoxDynError> {
+    let timeout = sqlx_exasol::query_scalar(TIMEOUT_QUERY)
+        .fetch_one(conn)
+        .await?;
+
+    Ok(timeout)
+}
+
+/// Asserts that neither the connection attributes nor the session kept the query options.
+async fn assert_restored(
+    conn: &mut ExaConnection,
+    query_timeout: u64,
+    fetch_size: usize,
+) -> Result<(), BoxDynError> {
+    assert_eq!(conn.attributes().query_timeout(), query_timeout);
+    assert_eq!(conn.attributes().fetch_size(), fetch_size);
+    assert_eq!(conn.attributes().result_set_max_rows(), 0);
+
+    assert_eq!(session_timeout(conn).await?, query_timeout.to_string());
+
+    let rows = sqlx_exasol::query(ROWS_QUERY).fetch_all(&mut *conn).await?;
+    assert_eq!(rows.len(), 4);
+
+    Ok(())
+}
+
+#[sqlx_exasol::test]
+async fn it_applies_query_options_to_the_wrapped_query(
+    mut conn: PoolConnection<Exasol>,
+) -> Result<(), BoxDynError> {
+    let query_timeout = conn.attributes().query_timeout();
+    let fetch_size = conn.attributes().fetch_size();
+
+    let options = ExaQueryOptions {
+        timeout: Some(query_timeout + 42),
+        ..Default::default()
+    };
+
+    let timeout: String = sqlx_exasol::query_scalar(TIMEOUT_QUERY)
+        .fetch_one(conn.with_options(options))
+        .await?;
+
+    assert_eq!(timeout, (query_timeout + 42).to_string());
+    assert_restored(&mut conn, query_timeout, fetch_size).await
+}
+
+#[sqlx_exasol::test]
+async fn it_limits_rows_of_the_wrapped_query(
+    mut conn: PoolConnection<Exasol>,
+) -> Result<(), BoxDynError> {
+    let query_timeout = conn.attributes().query_timeout();
+    let fetch_size = conn.attributes().fetch_size();
+
+    let options = ExaQueryOptions {
+        max_rows: Some(2),
+        fetch_size: Some(fetch_size / 2),
+        ..Default::default()
+    };
+
+    let rows = sqlx_exasol::query(ROWS_QUERY)
+        .fetch_all(conn.with_options(options))
+        .await?;
+    assert_eq!(rows.len(), 2);
+    assert_restored(&mut conn, query_timeout, fetch_size).await?;
+
+    // Prepared statements get the options as well.
+    let rows = sqlx_exasol::query("SELECT * FROM VALUES (1), (2), (3), (4) WHERE ? = 1")
+        .bind(1)
+        .fetch_all(conn.with_options(options))
+        .await?;
+    assert_eq!(rows.len(), 2);
+    assert_restored(&mut conn, query_timeout, fetch_size).await?;
+
+    let row = sqlx_exasol::query(ROWS_QUERY)
+        .fetch_optional(conn.with_options(options))
+        .await?;
+    assert!(row.is_some());
+    assert_restored(&mut conn, query_timeout, fetch_size).await
+}
+
+#[sqlx_exasol::test]
+async fn it_restores_query_options_on_error(
+    mut conn: PoolConnection<Exasol>,
+) -> Result<(), BoxDynError> {
+    let query_timeout = conn.attributes().query_timeout();
+    let fetch_size = conn.attributes().fetch_size();
+
+    let options = ExaQueryOptions {
+        timeout: Some(query_timeout + 42),
+        fetch_size: Some(fetch_size / 2),
+        max_rows: Some(2),
+    };
+
+    let res = sqlx_exasol::query("SELECT * FROM QUERY_OPTIONS_MISSING_TABLE")
+        .fetch_all(conn.with_options(options))
+        .await;
+    assert!(res.is_err());
+    assert_restored(&mut conn, query_timeout, fetch_size).await?;
+
+    let res = conn
+        .with_options(options)
+        .execute("INSERT INTO QUERY_OPTIONS_MISSING_TABLE VALUES (1)")
+        .await;
+    assert!(res.is_err());
+    assert_restored(&mut conn, query_timeout, fetch_size).await
+}
+
+#[sqlx_exasol::test]
+async fn it_restores_query_options_on_early_drop(
+    mut conn: PoolConnection<Exasol>,
+) -> Result<(), BoxDynError> {
+    let query_timeout = conn.attributes().query_timeout();
+    let fetch_size = conn.attributes().fetch_size();
+
+    let options = ExaQueryOptions {
+        timeout: Some(query_timeout + 42),
+        fetch_size: Some(fetch_size / 2),
+        max_rows: Some(3),
+    };
+
+    let row = conn
+        .with_options(options)
+        .fetch(ROWS_QUERY)
+        .try_next()
+        .await?;
+    assert!(row.is_some());
+    assert_restored(&mut conn, query_timeout, fetch_size).await
+}
@@ -1,6 +1,6 @@
 #![cfg(feature = "migrate")]
 
-use sqlx_exasol::ExaPool;
+use sqlx_exasol::{AssertSqlSafe, ExaPool, Executor};
 
 const MIGRATOR: sqlx_exasol::migrate::Migrator = sqlx_exasol::migrate!("tests/migrations");
 
@@ -92,3 +92,191 @@ async fn it_gets_comments(pool: ExaPool) -> sqlx_exasol::Result<()> {
 
     Ok(())
 }
+
+// This should apply migrations and then import `fixtures/users.csv`
+#[cfg(feature = "etl")]
+#[sqlx_exasol::test(migrations = "tests/migrations", csv_fixtures("users.csv"))]
+async fn it_gets_csv_users(pool: ExaPool) -> sqlx_exasol::Result<()> {
+    let usernames: Vec<String> =
+        sqlx_exasol::query_scalar(r"SELECT username FROM users ORDER BY username")
+            .fetch_all(&pool)
+            .await?;
+
+    assert_eq!(usernames, ["alice", "bob"]);
+
+    Ok(())
+}
+
+#[cfg(all(feature = "etl", feature = "compression"))]
+#[sqlx_exasol::test(
+    migrations = "tests/migrations",
+    csv_fixtures("users.csv", "post.csv.gz")
+)]
+async fn it_gets_gzipped_csv_posts(pool: ExaPool) -> sqlx_exasol::Result<()> {
+    let post_contents: Vec<String> =
+        sqlx_exasol::query_scalar("SELECT content FROM post ORDER BY created_at")
+            .fetch_all(&pool)
+            .await?;
+
+    assert_eq!(
+        post_contents,
+        [
+            "This new computer is lightning-fast!",
+            "@alice is a haxxor :("
+        ]
+    );
+
+    Ok(())
+}
+
+// The migrated template schema gets cloned into the test schema.
+#[sqlx_exasol::test(migrations = "tests/migrations_template")]
+async fn it_clones_the_template(pool: ExaPool) -> sqlx_exasol::Result<()> {
+    let mut conn = pool.acquire().await?;
+
+    let template: String = sqlx_exasol::query_scalar(
+        "SELECT table_schema FROM exa_all_tables
+         WHERE table_schema LIKE '\\_sqlx\\_template\\_%' ESCAPE '\\'
+         AND table_name = 'TEMPLATE_PARENT'",
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    // The migrations table is copied along with the data, rather than migrations being rerun.
+    let migrations_query = |schema: &str| {
+        format!(
+            r#"SELECT version, checksum, CAST(installed_on AS VARCHAR(30))
+            FROM "{schema}"."_sqlx_migrations" ORDER BY version"#
+        )
+    };
+    let template_migrations: Vec<(i64, String, String)> =
+        sqlx_exasol::query_as(AssertSqlSafe(migrations_query(&template)))
+            .fetch_all(&mut *conn)
+            .await?;
+    let schema: String = sqlx_exasol::query_scalar("SELECT CURRENT_SCHEMA")
+        .fetch_one(&mut *conn)
+        .await?;
+    let migrations: Vec<(i64, String, String)> =
+        sqlx_exasol::query_as(AssertSqlSafe(migrations_query(&schema)))
+            .fetch_all(&mut *conn)
+            .await?;
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations, template_migrations);
+
+    // Schema
+    let constraints: Vec<(String, String, String)> = sqlx_exasol::query_as(
+        "SELECT constraint_table, constraint_type, constraint_name FROM exa_all_constraints
+         WHERE constraint_schema = CURRENT_SCHEMA AND constraint_type <> 'NOT NULL'
+         AND constraint_enabled
+         ORDER BY constraint_table, constraint_type",
+    )
+    .fetch_all(&mut *conn)
+    .await?;
+
+    let constraints = constraints
+        .iter()
+        .map(|(table, ty, name)| {
+            // System generated names differ between schemas.
+            let name = if name.starts_with("SYS_") {
+                "SYS"
+            } else {
+                name
+            };
+            (table.as_str(), ty.as_str(), name)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        constraints,
+        [
+            ("TEMPLATE_CHILD", "FOREIGN KEY", "TEMPLATE_CHILD_PARENT"),
+            ("TEMPLATE_CHILD", "PRIMARY KEY", "TEMPLATE_CHILD_PK"),
+            ("TEMPLATE_PARENT", "PRIMARY KEY", "SYS"),
+        ]
+    );
+
+    let default: String = sqlx_exasol::query_scalar(
+        "SELECT column_default FROM exa_all_columns
+         WHERE column_schema = CURRENT_SCHEMA AND column_table = 'TEMPLATE_PARENT'
+         AND column_name = 'NAME'",
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    assert_eq!(default, "'unnamed'");
+
+    // Data
+    let parents: Vec<(i64, String)> =
+        sqlx_exasol::query_as("SELECT parent_id, name FROM template_parent ORDER BY parent_id")
+            .fetch_all(&mut *conn)
+            .await?;
+
+    assert_eq!(parents, [(1, "first".to_owned()), (2, "second".to_owned())]);
+
+    let children: Vec<(i64, i64)> =
+        sqlx_exasol::query_as("SELECT child_id, parent_id FROM template_child ORDER BY child_id")
+            .fetch_all(&mut *conn)
+            .await?;
+
+    assert_eq!(children, [(1, 1), (2, 2), (3, 2)]);
+
+    // Identity columns continue after the copied rows.
+    sqlx_exasol::query("INSERT INTO template_parent (name) VALUES (DEFAULT)")
+        .execute(&mut *conn)
+        .await?;
+
+    let parent_id: i64 =
+        sqlx_exasol::query_scalar("SELECT parent_id FROM template_parent WHERE name = 'unnamed'")
+            .fetch_one(&mut *conn)
+            .await?;
+
+    assert_eq!(parent_id, 3);
+
+    Ok(())
+}
+
+// The snapshot fixtures restore the data they were taken from.
+#[sqlx_exasol::test(migrations = "tests/migrations", fixtures("users", "posts", "comments"))]
+async fn it_reapplies_snapshot_fixtures(pool: ExaPool) -> sqlx_exasol::Result<()> {
+    let mut conn = pool.acquire().await?;
+    let snapshot = conn.snapshot().await?;
+
+    let schema: String = sqlx_exasol::query_scalar("SELECT CURRENT_SCHEMA")
+        .fetch_one(&mut *conn)
+        .await?;
+    let dir = std::env::temp_dir().join(schema);
+    snapshot.write_fixtures(&dir)?;
+
+    let mut fixtures = std::fs::read_dir(&dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    fixtures.sort();
+
+    let names = fixtures
+        .iter()
+        .filter_map(|p| p.file_name()?.to_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["00_users.sql", "01_post.sql", "02_comment.sql"]);
+
+    conn.execute("DELETE FROM comment; DELETE FROM post; DELETE FROM users;")
+        .await?;
+
+    for fixture in &fixtures {
+        let sql = std::fs::read_to_string(fixture)?;
+        conn.execute(AssertSqlSafe(sql)).await?;
+    }
+
+    std::fs::remove_dir_all(&dir)?;
+
+    let usernames: Vec<String> =
+        sqlx_exasol::query_scalar("SELECT username FROM users ORDER BY username")
+            .fetch_all(&mut *conn)
+            .await?;
+    assert_eq!(usernames, ["alice", "bob"]);
+
+    let restored = conn.snapshot().await?;
+    assert_eq!(restored.to_string(), snapshot.to_string());
+
+    Ok(())
+}
@@ -17,11 +17,44 @@ test_type_valid!(offset_datetime<OffsetDateTime>::"TIMESTAMP"::("'2023-08-12 19:
 test_type_valid!(offset_datetime_option<Option<OffsetDateTime>>::"TIMESTAMP"::("NULL" => None::<OffsetDateTime>, "''" => None::<OffsetDateTime>, "'2023-08-12 19:22:36.591000'" => Some(PrimitiveDateTime::parse("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap().assume_utc())));
 test_type_array!(offset_datetime_array<OffsetDateTime>::"TIMESTAMP"::(vec![PrimitiveDateTime::parse("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap().assume_utc(), PrimitiveDateTime::parse("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap().assume_utc(), PrimitiveDateTime::parse("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap().assume_utc()]));
 
+test_type_valid!(offset_datetime_precision<OffsetDateTime>::"TIMESTAMP(6)"::("'2023-08-12 19:22:36.591123'" => PrimitiveDateTime::parse("2023-08-12 19:22:36.591123", TIMESTAMP_FMT).unwrap().assume_utc()));
+
 test_type_valid!(primitive_datetime<PrimitiveDateTime>::"TIMESTAMP WITH LOCAL TIME ZONE"::("'2023-08-12 19:22:36.591000'" => PrimitiveDateTime::parse("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap()));
 test_type_valid!(primitive_datetime_optional<Option<PrimitiveDateTime>>::"TIMESTAMP WITH LOCAL TIME ZONE"::("NULL" => None::<PrimitiveDateTime>, "''" => None::<PrimitiveDateTime>, "'2023-08-12 19:22:36.591000'" => Some(PrimitiveDateTime::parse("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap())));
 test_type_array!(primitive_datetime_array<PrimitiveDateTime>::"TIMESTAMP WITH LOCAL TIME ZONE"::(vec![PrimitiveDateTime::parse("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap(), PrimitiveDateTime::parse("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap(), PrimitiveDateTime::parse("2023-08-12 19:22:36.591000", TIMESTAMP_FMT).unwrap()]));
 
+test_type_valid!(primitive_datetime_precision<PrimitiveDateTime>::"TIMESTAMP(6) WITH LOCAL TIME ZONE"::("'2023-08-12 19:22:36.591123'" => PrimitiveDateTime::parse("2023-08-12 19:22:36.591123", TIMESTAMP_FMT).unwrap()));
+
 test_type_valid!(duration<Duration>::"INTERVAL DAY TO SECOND"::("'10 20:45:50.123'" => Duration::milliseconds(938_750_123), "'-10 20:45:50.123'" => Duration::milliseconds(-938_750_123)));
 test_type_valid!(duration_with_prec<Duration>::"INTERVAL DAY(4) TO SECOND"::("'10 20:45:50.123'" => Duration::milliseconds(938_750_123), "'-10 20:45:50.123'" => Duration::milliseconds(-938_750_123)));
 test_type_valid!(duration_option<Option<Duration>>::"INTERVAL DAY TO SECOND"::("NULL" => None::<Duration>, "''" => None::<Duration>, "'10 20:45:50.123'" => Some(Duration::milliseconds(938_750_123))));
 test_type_array!(duration_array<Duration>::"INTERVAL DAY TO SECOND"::(vec!["10 20:45:50.123", "10 20:45:50.123", "10 20:45:50.123"]));
+
+#[cfg(feature = "chrono-tz")]
+#[sqlx_exasol::test]
+async fn test_offset_datetime_session_time_zone(
+    mut con: sqlx_exasol::pool::PoolConnection<sqlx_exasol::Exasol>,
+) -> Result<(), sqlx_exasol::error::BoxDynError> {
+    use sqlx_exasol::Executor;
+
+    con.execute("ALTER SESSION SET TIME_ZONE = 'EUROPE/BERLIN';")
+        .await?;
+    con.execute("CREATE TABLE sqlx_test_type ( col TIMESTAMP WITH LOCAL TIME ZONE );")
+        .await?;
+
+    let expected = PrimitiveDateTime::parse("2023-08-12 17:22:36.591", TIMESTAMP_FMT)?.assume_utc();
+
+    sqlx_exasol::query("INSERT INTO sqlx_test_type VALUES (?)")
+        .bind(expected)
+        .execute(&mut *con)
+        .await?;
+    con.execute("INSERT INTO sqlx_test_type VALUES ('2023-08-12 19:22:36.591');")
+        .await?;
+
+    let values: Vec<OffsetDateTime> = sqlx_exasol::query_scalar("SELECT * FROM sqlx_test_type;")
+        .fetch_all(&mut *con)
+        .await?;
+    assert_eq!(values, [expected; 2]);
+
+    Ok(())
+}